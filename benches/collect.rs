@@ -0,0 +1,49 @@
+#![feature(test)]
+
+extern crate test;
+
+use chumsky::{error::Cheap, prelude::*};
+use std::cell::RefCell;
+use test::{black_box, Bencher};
+
+fn input() -> String {
+    (0..1_000).map(|i| format!("word{} ", i)).collect()
+}
+
+#[bench]
+fn collect_string(b: &mut Bencher) {
+    let word = filter::<_, _, Cheap<char>>(|c: &char| c.is_alphanumeric())
+        .repeated()
+        .at_least(1)
+        .collect::<String>()
+        .padded();
+
+    let input = input();
+    b.iter(|| {
+        let mut len = 0;
+        for chunk in input.split_inclusive(' ') {
+            len += word.parse(chunk).unwrap().len();
+        }
+        black_box(len)
+    });
+}
+
+#[bench]
+fn collect_into_string(b: &mut Bencher) {
+    let buffer = RefCell::new(String::new());
+    let word = filter::<_, _, Cheap<char>>(|c: &char| c.is_alphanumeric())
+        .repeated()
+        .at_least(1)
+        .collect_into_string(&buffer)
+        .padded();
+
+    let input = input();
+    b.iter(|| {
+        let mut len = 0;
+        for chunk in input.split_inclusive(' ') {
+            buffer.borrow_mut().clear();
+            len += word.parse(chunk).unwrap().len();
+        }
+        black_box(len)
+    });
+}
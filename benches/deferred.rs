@@ -0,0 +1,41 @@
+#![feature(test)]
+
+extern crate test;
+
+use chumsky::{error::Cheap, prelude::*};
+use test::{black_box, Bencher};
+
+// Every identifier is first tried as a call (which fails), then as a variable
+fn input() -> String {
+    (0..1_000).map(|i| format!("ident{} ", i)).collect()
+}
+
+#[derive(Debug)]
+pub enum Expr {
+    Call(String),
+    Var(String),
+}
+
+#[bench]
+fn map(b: &mut Bencher) {
+    let ident = text::ident::<_, Cheap<char>>().map(|s: String| s.to_uppercase());
+    let call = ident.then_ignore(just("()")).map(Expr::Call);
+    let var = ident.map(Expr::Var);
+    let expr = call.or(var).padded().repeated().then_ignore(end());
+
+    let input = input();
+    b.iter(|| black_box(expr.parse(input.as_str()).unwrap()));
+}
+
+#[bench]
+fn map_deferred(b: &mut Bencher) {
+    let ident = text::ident::<_, Cheap<char>>().map_deferred(|s: String| s.to_uppercase());
+    let call = ident
+        .then_ignore(just("()"))
+        .map(|name| Expr::Call(name.force()));
+    let var = ident.map(|name| Expr::Var(name.force()));
+    let expr = call.or(var).padded().repeated().then_ignore(end());
+
+    let input = input();
+    b.iter(|| black_box(expr.parse(input.as_str()).unwrap()));
+}
@@ -0,0 +1,54 @@
+#![feature(test)]
+
+extern crate test;
+
+use chumsky::{error::Cheap, prelude::*};
+use test::{black_box, Bencher};
+
+#[derive(Debug, PartialEq)]
+pub enum Expr {
+    Num(u32),
+    Pow(Box<Expr>, Box<Expr>),
+}
+
+// A deeply right-associative expression: `1^1^1^...^1`
+fn input() -> String {
+    let mut s = "1".to_string();
+    for _ in 0..2_000 {
+        s.push_str("^1");
+    }
+    s
+}
+
+fn num() -> impl Parser<char, Expr, Error = Cheap<char>> + Clone {
+    text::int(10).map(|s: String| Expr::Num(s.parse().unwrap()))
+}
+
+#[bench]
+fn foldr(b: &mut Bencher) {
+    let expr = num()
+        .then_ignore(just('^'))
+        .repeated()
+        .then(num())
+        .foldr(|a, b| Expr::Pow(Box::new(a), Box::new(b)))
+        .then_ignore(end());
+
+    let input = input();
+    b.iter(|| black_box(expr.parse(input.as_str()).unwrap()));
+}
+
+#[bench]
+fn recursive(b: &mut Bencher) {
+    let expr = chumsky::recursive::recursive(|expr| {
+        num()
+            .then(just('^').ignore_then(expr).or_not())
+            .map(|(a, b)| match b {
+                Some(b) => Expr::Pow(Box::new(a), Box::new(b)),
+                None => a,
+            })
+    })
+    .then_ignore(end());
+
+    let input = input();
+    b.iter(|| black_box(expr.parse(input.as_str()).unwrap()));
+}
@@ -0,0 +1,225 @@
+//! Parsers and utilities for working with binary data.
+//!
+//! *“Forty-two,” said Deep Thought, with infinite majesty and calm.*
+//!
+//! The parsers in this module operate on streams of [`u8`](prim@u8), making it possible to parse simple binary formats
+//! such as file headers or network protocols. Multi-byte integers are read with an explicit byte order.
+//!
+//! If the input ends before a parser has read all of the bytes that it requires, an error is produced at the end of
+//! the input (as if by [`Error::expected_input_found`] with `found` being `None`).
+
+use super::*;
+
+/// See [`array()`].
+#[must_use]
+pub struct Array<E, const N: usize>(PhantomData<E>);
+
+impl<E, const N: usize> Copy for Array<E, N> {}
+impl<E, const N: usize> Clone for Array<E, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: Error<u8>, const N: usize> Parser<u8, [u8; N]> for Array<E, N> {
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        _debugger: &mut D,
+        stream: &mut StreamOf<u8, E>,
+    ) -> PResult<u8, [u8; N], E> {
+        let mut bytes = [0; N];
+        for byte in bytes.iter_mut() {
+            match stream.next() {
+                (_, _, Some(b)) => *byte = b,
+                (at, span, found) => {
+                    return (
+                        Vec::new(),
+                        Err(Located::at(
+                            at,
+                            E::expected_input_found(span, Vec::new(), found),
+                        )),
+                    )
+                }
+            }
+        }
+        (Vec::new(), Ok((bytes, None)))
+    }
+
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<u8, E>,
+    ) -> PResult<u8, [u8; N], E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<u8, E>,
+    ) -> PResult<u8, [u8; N], E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// A parser that accepts exactly `N` bytes, without allocating.
+///
+/// The output type of this parser is `[u8; N]`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, bytes};
+/// let rgb = bytes::array::<Simple<u8>, 3>();
+///
+/// assert_eq!(rgb.parse(&[0xFF, 0x80, 0x00][..]), Ok([0xFF, 0x80, 0x00]));
+/// assert!(rgb.parse(&[0xFF, 0x80][..]).is_err());
+/// ```
+pub fn array<E, const N: usize>() -> Array<E, N> {
+    Array(PhantomData)
+}
+
+/// A parser that accepts exactly `n` bytes.
+///
+/// The output type of this parser is `Vec<u8>`. If the number of bytes is known at compile time, [`array()`] avoids
+/// allocating.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, bytes};
+/// // A length-prefixed payload
+/// let payload = bytes::u8::<Simple<u8>>().then_with(|len| bytes::take(len as usize));
+///
+/// assert_eq!(payload.parse(&[2, b'h', b'i'][..]), Ok(b"hi".to_vec()));
+/// assert!(payload.parse(&[3, b'h', b'i'][..]).is_err());
+/// ```
+pub fn take<E: Error<u8>>(n: usize) -> Repeated<Any<u8, E>> {
+    any().repeated().exactly(n)
+}
+
+/// A parser that accepts only the given sequence of bytes, such as the magic number at the start of a file.
+///
+/// The output type of this parser is `&[u8]`, the bytes that were accepted.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, bytes};
+/// let png = bytes::tag::<Simple<u8>>(b"\x89PNG");
+///
+/// assert_eq!(png.parse(&b"\x89PNG\r\n"[..]), Ok(&b"\x89PNG"[..]));
+/// assert!(png.parse(&b"GIF89a"[..]).is_err());
+/// ```
+pub fn tag<E: Error<u8>>(tag: &[u8]) -> Just<u8, &[u8], E> {
+    just(tag)
+}
+
+/// A parser that accepts a single byte.
+///
+/// The output type of this parser is [`u8`](prim@u8).
+pub fn u8<E: Error<u8>>() -> Any<u8, E> {
+    any()
+}
+
+macro_rules! int_parsers {
+    ($($ty:ident $n:literal $be:ident $le:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("A parser that accepts a big-endian [`", stringify!($ty), "`].")]
+            ///
+            #[doc = concat!("The output type of this parser is [`", stringify!($ty), "`].")]
+            pub fn $be<E: Error<u8>>() -> impl Parser<u8, $ty, Error = E> + Copy {
+                array::<E, $n>().map($ty::from_be_bytes)
+            }
+
+            #[doc = concat!("A parser that accepts a little-endian [`", stringify!($ty), "`].")]
+            ///
+            #[doc = concat!("The output type of this parser is [`", stringify!($ty), "`].")]
+            pub fn $le<E: Error<u8>>() -> impl Parser<u8, $ty, Error = E> + Copy {
+                array::<E, $n>().map($ty::from_le_bytes)
+            }
+        )*
+    };
+}
+
+int_parsers! {
+    u16 2 u16_be u16_le,
+    u32 4 u32_be u32_le,
+    u64 8 u64_be u64_le,
+    i16 2 i16_be i16_le,
+    i32 4 i32_be i32_le,
+    i64 8 i64_be i64_le,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Simple;
+
+    #[test]
+    fn integers() {
+        let input: &[u8] = &[0x12, 0x34, 0x56, 0x78];
+
+        assert_eq!(u16_be::<Simple<u8>>().parse(input), Ok(0x1234));
+        assert_eq!(u16_le::<Simple<u8>>().parse(input), Ok(0x3412));
+        assert_eq!(u32_be::<Simple<u8>>().parse(input), Ok(0x1234_5678));
+        assert_eq!(u32_le::<Simple<u8>>().parse(input), Ok(0x7856_3412));
+        assert_eq!(i16_be::<Simple<u8>>().parse(&[0xFF, 0xFE][..]), Ok(-2));
+    }
+
+    #[test]
+    fn truncated_input() {
+        let errors = u32_le::<Simple<u8>>()
+            .parse(&[0x12, 0x34, 0x56][..])
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 3..3);
+        assert_eq!(errors[0].found(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn truncated_reader() {
+        let mut error = None;
+        let errors = u32_le::<Simple<u8>>()
+            .parse(Stream::from_reader(&[0x12, 0x34][..], &mut error))
+            .unwrap_err();
+
+        assert!(error.is_none());
+        assert_eq!(errors[0].span(), 2..2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn failing_reader() {
+        use std::io::{self, Read};
+
+        // Produces two bytes, then fails
+        struct Failing(usize);
+        impl Read for Failing {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.0 == 0 {
+                    return Err(io::Error::new(io::ErrorKind::Other, "disk on fire"));
+                }
+                self.0 -= 1;
+                buf[0] = 0xFF;
+                Ok(1)
+            }
+        }
+
+        let mut error = None;
+        let res = u8::<Simple<u8>>()
+            .repeated()
+            .parse(Stream::from_reader(Failing(2), &mut error));
+
+        assert_eq!(res, Ok(vec![0xFF, 0xFF]));
+        assert_eq!(
+            error.map(|e| e.to_string()),
+            Some("disk on fire".to_string())
+        );
+    }
+}
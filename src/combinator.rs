@@ -6,6 +6,17 @@
 //! when accessed through their respective methods on [`Parser`].
 
 use super::*;
+use alloc::{collections::VecDeque, rc::Weak, string::ToString};
+use core::{
+    any::Any,
+    cell::RefCell,
+    ops::{Bound, RangeBounds},
+};
+
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// See [`Parser::ignored`].
 pub type Ignored<P, O> = To<P, O, ()>;
@@ -16,6 +27,13 @@ pub type IgnoreThen<A, B, O, U> = Map<Then<A, B>, fn((O, U)) -> U, (O, U)>;
 /// See [`Parser::then_ignore`].
 pub type ThenIgnore<A, B, O, U> = Map<Then<A, B>, fn((O, U)) -> O, (O, U)>;
 
+/// See [`Repeated::enumerate`] and [`SeparatedBy::enumerate`].
+pub type Enumerate<A, O> = Map<A, fn(Vec<O>) -> Vec<(usize, O)>, Vec<O>>;
+
+/// See [`Parser::with_trivia`].
+pub type WithTrivia<A, L, T, O, LO, TO> =
+    Map<Then<Then<L, A>, T>, fn(((LO, O), TO)) -> (LO, O, TO), ((LO, O), TO)>;
+
 /// See [`Parser::or`].
 #[must_use]
 #[derive(Copy, Clone)]
@@ -32,10 +50,21 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, B: Parser<I, O, Error = E>, E: Err
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
     ) -> PResult<I, O, E> {
+        // Only the recovered errors of the branch that is ultimately chosen are emitted: those produced by a rejected
+        // branch are discarded, since that branch didn't contribute to the output.
         let pre_state = stream.save();
+        let pre_emitted = stream.emitted.len();
+        // A committed failure ends the whole chain of alternatives that this is part of, as it would in `choice`
+        let chained = core::mem::take(&mut stream.chained);
+        let uncommit = |err: Located<I, E>| if chained { err } else { err.uncommit() };
 
-        #[allow(deprecated)]
-        let a_res = debugger.invoke(&self.0, stream);
+        let a_res = stream.cut_scope(|stream| {
+            #[allow(deprecated)]
+            {
+                stream.chained = self.0.is_choice();
+                debugger.invoke(&self.0, stream)
+            }
+        });
         let a_state = stream.save();
 
         // If the first parser succeeded and produced no secondary errors, don't bother trying the second parser
@@ -47,10 +76,22 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, B: Parser<I, O, Error = E>, E: Err
             }
         }
 
+        // A committed failure prevents the second parser from being attempted
+        if matches!(&a_res.1, Err(err) if err.committed) {
+            let (a_errors, a_res) = a_res;
+            return (a_errors, a_res.map_err(uncommit));
+        }
+
+        // Errors counted towards the error limit are set aside along with the position, since a zero-width recovery
+        // leaves the position untouched
+        let a_state = (a_state, stream.emitted.split_off(pre_emitted));
         stream.revert(pre_state);
 
-        #[allow(deprecated)]
-        let b_res = debugger.invoke(&self.1, stream);
+        let (b_errors, b_res) = stream.cut_scope(|stream| {
+            #[allow(deprecated)]
+            debugger.invoke(&self.1, stream)
+        });
+        let b_res = (b_errors, b_res.map_err(uncommit));
         let b_state = stream.save();
 
         if b_res.0.is_empty() {
@@ -58,13 +99,14 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, B: Parser<I, O, Error = E>, E: Err
                 return (b_errors, Ok(b_out));
             }
         }
+        let b_state = (b_state, stream.emitted.split_off(pre_emitted));
 
         #[inline]
         fn choose_between<I: Clone, O, E: Error<I>>(
             a_res: PResult<I, O, E>,
-            a_state: usize,
+            a_state: (usize, Vec<usize>),
             b_res: PResult<I, O, E>,
-            b_state: usize,
+            b_state: (usize, Vec<usize>),
             stream: &mut StreamOf<I, E>,
         ) -> PResult<I, O, E> {
             fn zip_with<A, B, R, F: FnOnce(A, B) -> R>(
@@ -129,7 +171,8 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, B: Parser<I, O, Error = E>, E: Err
             };
 
             if is_a {
-                stream.revert(a_state);
+                stream.revert(a_state.0);
+                stream.emitted.extend(a_state.1);
                 (
                     a_res.0,
                     a_res.1.map(|(out, alt)| {
@@ -140,7 +183,8 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, B: Parser<I, O, Error = E>, E: Err
                     }),
                 )
             } else {
-                stream.revert(b_state);
+                stream.revert(b_state.0);
+                stream.emitted.extend(b_state.1);
                 (
                     b_res.0,
                     b_res.1.map(|(out, alt)| {
@@ -166,6 +210,75 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, B: Parser<I, O, Error = E>, E: Err
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
+
+    fn is_choice(&self) -> bool {
+        true
+    }
+}
+
+/// See [`Parser::or_warn_ambiguous`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct OrWarnAmbiguous<A, B>(pub(crate) A, pub(crate) B);
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, B: Parser<I, O, Error = E>, E: Error<I>> Parser<I, O>
+    for OrWarnAmbiguous<A, B>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        Or(&self.0, &self.1).parse_inner(debugger, stream)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[cfg(debug_assertions)]
+        {
+            // Returns the number of inputs consumed if the parser matches cleanly, without consuming anything
+            fn match_len<I: Clone, O, P: Parser<I, O>>(
+                parser: &P,
+                stream: &mut StreamOf<I, P::Error>,
+            ) -> Option<usize> {
+                let start = stream.save();
+                stream.attempt(|stream| {
+                    #[allow(deprecated)]
+                    let (errors, res) = parser.parse_inner_silent(&mut Silent::new(), stream);
+                    let len = stream.save() - start;
+                    (
+                        false,
+                        Some(len).filter(|_| errors.is_empty() && res.is_ok()),
+                    )
+                })
+            }
+
+            if let (Some(a_len), Some(b_len)) = (match_len(&self.0, s), match_len(&self.1, s)) {
+                let at = s.save();
+                d.emit_with(|| {
+                    ParseEvent::Warning(alloc::format!(
+                        "Ambiguous choice at input position {}: branch 0 matches {} input(s), but branch 1 (which \
+                        is never taken here) would also have matched {} input(s)",
+                        at,
+                        a_len,
+                        b_len,
+                    ))
+                });
+            }
+        }
+
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
 }
 
 /// See [`Parser::or_not`].
@@ -187,6 +300,7 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, Option<O>>
             debugger.invoke(&self.0, stream)
         }) {
             (errors, Ok((out, alt))) => (errors, Ok((Some(out), alt))),
+            (errors, Err(err)) if err.committed => (errors, Err(err.uncommit())),
             (_, Err(err)) => (Vec::new(), Ok((None, Some(err)))),
         }
     }
@@ -330,6 +444,180 @@ impl<I: Clone, O, U, A: Parser<I, O, Error = E>, B: Parser<I, U, Error = E>, E:
     }
 }
 
+/// See [`Parser::then_after`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct ThenAfter<A, B, L>(pub(crate) A, pub(crate) B, pub(crate) L);
+
+impl<
+        I: Clone,
+        O,
+        U,
+        A: Parser<I, O, Error = E>,
+        B: Parser<I, U, Error = E>,
+        L: Into<E::Label> + Clone,
+        E: Error<I>,
+    > Parser<I, (O, U)> for ThenAfter<A, B, L>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, (O, U), E> {
+        #[allow(deprecated)]
+        let (mut a_errors, a_res) = debugger.invoke(&self.0, stream);
+        let (a_out, a_alt) = match a_res {
+            Ok(a_out) => a_out,
+            Err(a_err) => return (a_errors, Err(a_err)),
+        };
+
+        let b_start = stream.save();
+        #[allow(deprecated)]
+        let (mut b_errors, b_res) = debugger.invoke(&self.1, stream);
+        a_errors.append(&mut b_errors);
+        match b_res {
+            Ok((b_out, b_alt)) => (a_errors, Ok(((a_out, b_out), merge_alts(a_alt, b_alt)))),
+            // Only if the second pattern failed immediately do we know that its expected inputs are what follows the
+            // first pattern
+            Err(b_err) if b_err.at == b_start => {
+                let b_err = b_err.map(|e| e.with_after(self.2.clone().into()));
+                (a_errors, Err(b_err.max(a_alt)))
+            }
+            Err(b_err) => (a_errors, Err(b_err.max(a_alt))),
+        }
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, (O, U), E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, (O, U), E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::then_if_adjacent`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct ThenIfAdjacent<A, B>(pub(crate) A, pub(crate) B);
+
+impl<I: Clone, O, U, A: Parser<I, O, Error = E>, B: Parser<I, U, Error = E>, E: Error<I>>
+    Parser<I, (O, Option<U>)> for ThenIfAdjacent<A, B>
+where
+    <E::Span as Span>::Offset: PartialEq,
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, (O, Option<U>), E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (mut a_errors, a_res) = debugger.invoke(&self.0, stream);
+        match a_res {
+            Ok((a_out, a_alt)) => {
+                let a_end = stream.span_since(start).end();
+                let adjacent = stream.attempt(|stream| (false, stream.next().1.start() == a_end));
+                if !adjacent {
+                    return (a_errors, Ok(((a_out, None), a_alt)));
+                }
+
+                let b_start = stream.save();
+                match stream.try_parse(|stream| {
+                    #[allow(deprecated)]
+                    debugger.invoke(&self.1, stream)
+                }) {
+                    (mut b_errors, Ok((b_out, b_alt))) => {
+                        a_errors.append(&mut b_errors);
+                        (
+                            a_errors,
+                            Ok(((a_out, Some(b_out)), merge_alts(a_alt, b_alt))),
+                        )
+                    }
+                    // The second pattern got underway before failing, so it was not merely absent
+                    (mut b_errors, Err(b_err)) if b_err.at > b_start => {
+                        stream.revert(start);
+                        a_errors.append(&mut b_errors);
+                        (a_errors, Err(b_err))
+                    }
+                    (_, Err(b_err)) => (
+                        a_errors,
+                        Ok(((a_out, None), merge_alts(a_alt, Some(b_err)))),
+                    ),
+                }
+            }
+            Err(a_err) => (a_errors, Err(a_err)),
+        }
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, (O, Option<U>), E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, (O, Option<U>), E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+// Parse `a`, then the parser that `then` creates from its output and span. The output is that of the second parser,
+// alongside whatever `then` chose to keep of the first output.
+#[inline]
+fn then_dependent<I: Clone, O1, O2, K, A, B, D, E>(
+    a: &A,
+    then: impl FnOnce(O1, E::Span) -> (K, B),
+    debugger: &mut D,
+    stream: &mut StreamOf<I, E>,
+) -> PResult<I, (K, O2), E>
+where
+    A: Parser<I, O1, Error = E>,
+    B: Parser<I, O2, Error = E>,
+    D: Debugger,
+    E: Error<I>,
+{
+    let state = stream.save();
+
+    #[allow(deprecated)]
+    let (mut errors, res) = debugger.invoke(a, stream);
+    let res = res.and_then(|(first_out, first_alt)| {
+        let span = stream.span_since(state);
+        let (kept, second) = then(first_out, span);
+        // The second parser runs on the same stream, so its errors are already located correctly
+        #[allow(deprecated)]
+        let (second_errors, second_res) = debugger.invoke(&second, stream);
+        errors.extend(second_errors);
+        second_res
+            .map(|(second_out, second_alt)| ((kept, second_out), merge_alts(first_alt, second_alt)))
+    });
+    if res.is_err() {
+        stream.revert(state);
+    }
+    (errors, res)
+}
+
 /// See [`Parser::then_with`]
 #[must_use]
 pub struct ThenWith<I, O1, O2, A, B, F>(
@@ -364,34 +652,63 @@ impl<
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
     ) -> PResult<I, O2, E> {
-        let state = stream.save();
+        let (errors, res) = then_dependent(&self.0, |out, _| ((), (self.1)(out)), debugger, stream);
+        (errors, res.map(|(((), out), alt)| (out, alt)))
+    }
 
-        match {
-            #[allow(deprecated)]
-            debugger.invoke(&self.0, stream)
-        } {
-            (mut first_errs, Ok((first_out, first_alt))) => {
-                let second_out = self.1(first_out);
-                match {
-                    #[allow(deprecated)]
-                    debugger.invoke(&second_out, stream)
-                } {
-                    (second_errs, Ok((second_out, second_alt))) => {
-                        first_errs.extend(second_errs);
-                        (first_errs, Ok((second_out, first_alt.or(second_alt))))
-                    }
-                    (second_errs, Err(e)) => {
-                        stream.revert(state);
-                        first_errs.extend(second_errs);
-                        (first_errs, Err(e))
-                    }
-                }
-            }
-            (errs, Err(e)) => {
-                stream.revert(state);
-                (errs, Err(e))
-            }
-        }
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O2, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O2, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::then_with_span`].
+#[must_use]
+pub struct ThenWithSpan<I, O1, O2, A, B, F>(
+    pub(crate) A,
+    pub(crate) F,
+    pub(crate) PhantomData<(I, O1, O2, B)>,
+);
+
+impl<I, O1, O2, A: Clone, B, F: Clone> Clone for ThenWithSpan<I, O1, O2, A, B, F> {
+    fn clone(&self) -> Self {
+        ThenWithSpan(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I, O1, O2, A: Copy, B, F: Copy> Copy for ThenWithSpan<I, O1, O2, A, B, F> {}
+
+impl<
+        I: Clone,
+        O1,
+        O2,
+        A: Parser<I, O1, Error = E>,
+        B: Parser<I, O2, Error = E>,
+        F: Fn(O1, E::Span) -> B,
+        E: Error<I>,
+    > Parser<I, O2> for ThenWithSpan<I, O1, O2, A, B, F>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O2, E> {
+        let (errors, res) = then_dependent(
+            &self.0,
+            |out, span| ((), (self.1)(out, span)),
+            debugger,
+            stream,
+        );
+        (errors, res.map(|(((), out), alt)| (out, alt)))
     }
 
     #[inline]
@@ -406,6 +723,71 @@ impl<
     }
 }
 
+/// See [`Parser::then_ctx`].
+#[must_use]
+pub struct ThenCtx<I, O1, O2, A, B, F>(
+    pub(crate) A,
+    pub(crate) F,
+    pub(crate) PhantomData<(I, O1, O2, B)>,
+);
+
+impl<I, O1, O2, A: Clone, B, F: Clone> Clone for ThenCtx<I, O1, O2, A, B, F> {
+    fn clone(&self) -> Self {
+        ThenCtx(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I, O1, O2, A: Copy, B, F: Copy> Copy for ThenCtx<I, O1, O2, A, B, F> {}
+
+impl<
+        I: Clone,
+        O1,
+        O2,
+        A: Parser<I, O1, Error = E>,
+        B: Parser<I, O2, Error = E>,
+        F: Fn(&O1) -> B,
+        E: Error<I>,
+    > Parser<I, (O1, O2)> for ThenCtx<I, O1, O2, A, B, F>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, (O1, O2), E> {
+        then_dependent(
+            &self.0,
+            |out, _| {
+                let second = (self.1)(&out);
+                (out, second)
+            },
+            debugger,
+            stream,
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, (O1, O2), E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, (O1, O2), E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
 /// See [`Parser::delimited_by`].
 #[must_use]
 #[derive(Copy, Clone)]
@@ -420,8 +802,8 @@ impl<
         I: Clone,
         O,
         A: Parser<I, O, Error = E>,
-        L: Parser<I, U, Error = E> + Clone,
-        R: Parser<I, V, Error = E> + Clone,
+        L: Parser<I, U, Error = E>,
+        R: Parser<I, V, Error = E>,
         U,
         V,
         E: Error<I>,
@@ -435,17 +817,13 @@ impl<
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
     ) -> PResult<I, O, E> {
-        // TODO: Don't clone!
-        #[allow(deprecated)]
-        let (errors, res) = debugger.invoke(
-            &self
-                .start
-                .clone()
-                .ignore_then(&self.item)
-                .then_ignore(self.end.clone()),
+        // Borrow the delimiters rather than cloning them so that shared parsers (such as `Recursive` or
+        // `BoxedParser`) aren't duplicated on every invocation.
+        #[allow(deprecated)]
+        debugger.invoke(
+            &(&self.start).ignore_then(&self.item).then_ignore(&self.end),
             stream,
-        );
-        (errors, res)
+        )
     }
 
     #[inline]
@@ -460,14 +838,100 @@ impl<
     }
 }
 
-/// See [`Parser::repeated`].
+/// See [`Parser::delimited_by_or_empty`].
 #[must_use]
 #[derive(Copy, Clone)]
-pub struct Repeated<A>(pub(crate) A, pub(crate) usize, pub(crate) Option<usize>);
-
-impl<A> Repeated<A> {
-    /// Require that the pattern appear at least a minimum number of times.
-    pub fn at_least(mut self, min: usize) -> Self {
+pub struct DelimitedByOrEmpty<A, L, R, U, V> {
+    pub(crate) item: A,
+    pub(crate) start: L,
+    pub(crate) end: R,
+    pub(crate) phantom: PhantomData<(U, V)>,
+}
+
+impl<
+        I: Clone,
+        O,
+        A: Parser<I, O, Error = E>,
+        L: Parser<I, U, Error = E>,
+        R: Parser<I, V, Error = E>,
+        U,
+        V,
+        E: Error<I>,
+    > Parser<I, Option<O>> for DelimitedByOrEmpty<A, L, R, U, V>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, Option<O>, E> {
+        #[allow(deprecated)]
+        let (mut errors, start_res) = debugger.invoke(&self.start, stream);
+        let start_alt = match start_res {
+            Ok((_, alt)) => alt,
+            Err(err) => return (errors, Err(err)),
+        };
+
+        // If the end delimiter immediately follows the start delimiter, the content is genuinely empty
+        let end_err = match stream.try_parse(|stream| {
+            #[allow(deprecated)]
+            debugger.invoke(&self.end, stream)
+        }) {
+            (mut end_errors, Ok((_, end_alt))) => {
+                errors.append(&mut end_errors);
+                return (errors, Ok((None, merge_alts(start_alt, end_alt))));
+            }
+            (_, Err(end_err)) => end_err,
+        };
+
+        #[allow(deprecated)]
+        let (mut item_errors, item_res) = debugger.invoke(&self.item, stream);
+        errors.append(&mut item_errors);
+        let (out, item_alt) = match item_res {
+            Ok((out, alt)) => (out, merge_alts(start_alt, alt)),
+            // Report both the content and the end delimiter as being expected
+            Err(item_err) => return (errors, Err(item_err.max(end_err).max(start_alt))),
+        };
+
+        #[allow(deprecated)]
+        let (mut end_errors, end_res) = debugger.invoke(&self.end, stream);
+        errors.append(&mut end_errors);
+        match end_res {
+            Ok((_, end_alt)) => (errors, Ok((Some(out), merge_alts(item_alt, end_alt)))),
+            Err(err) => (errors, Err(err.max(item_alt))),
+        }
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Option<O>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Option<O>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::repeated`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct Repeated<A>(pub(crate) A, pub(crate) usize, pub(crate) Option<usize>);
+
+impl<A> Repeated<A> {
+    /// Require that the pattern appear at least a minimum number of times.
+    pub fn at_least(mut self, min: usize) -> Self {
         self.1 = min;
         self
     }
@@ -519,362 +983,379 @@ impl<A> Repeated<A> {
         self.2 = Some(n);
         self
     }
-}
-
-impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, Vec<O>> for Repeated<A> {
-    type Error = E;
-
-    #[inline]
-    fn parse_inner<D: Debugger>(
-        &self,
-        debugger: &mut D,
-        stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, Vec<O>, E> {
-        let mut errors = Vec::new();
-        let mut outputs = Vec::new();
-        let mut alt = None;
-        let mut old_offset = None;
 
-        loop {
-            if self.2.map_or(false, |max| outputs.len() >= max) {
-                break (errors, Ok((outputs, alt)));
+    /// Require that the number of times the pattern appears falls within the given range.
+    ///
+    /// This is equivalent to combining [`Repeated::at_least`] and [`Repeated::at_most`]. Once the maximum has been
+    /// reached, repetition stops without consuming any further input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, since no number of repetitions could satisfy it.
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let digits = filter::<_, _, Simple<char>>(char::is_ascii_digit)
+    ///     .repeated()
+    ///     .range(2..=4)
+    ///     .collect::<String>();
+    ///
+    /// assert!(digits.parse("1").is_err()); // Too few digits
+    /// assert_eq!(digits.parse("12"), Ok("12".to_string()));
+    /// assert_eq!(digits.parse("123456"), Ok("1234".to_string())); // Stops after the maximum
+    /// assert_eq!(digits.repeated().at_least(1).parse("123456"), Ok(vec!["1234".to_string(), "56".to_string()]));
+    /// ```
+    pub fn range<R: RangeBounds<usize>>(mut self, range: R) -> Self {
+        self.1 = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        self.2 = match range.end_bound() {
+            Bound::Included(&n) => {
+                assert!(
+                    self.1 <= n,
+                    "Repeated cannot parse at least {} and at most {}",
+                    self.1,
+                    n
+                );
+                Some(n)
             }
-
-            if let ControlFlow::Break(b) = stream.attempt(|stream| match { #[allow(deprecated)] debugger.invoke(&self.0, stream) } {
-                (mut a_errors, Ok((a_out, a_alt))) => {
-                    errors.append(&mut a_errors);
-                    alt = merge_alts(alt.take(), a_alt);
-                    outputs.push(a_out);
-
-                    if old_offset == Some(stream.offset()) {
-                        panic!("Repeated parser iteration succeeded but consumed no inputs (i.e: continuing \
-                            iteration would likely lead to an infinite loop, if the parser is pure). This is \
-                            likely indicative of a parser bug. Consider using a more specific error recovery \
-                            strategy.");
-                    } else {
-                        old_offset = Some(stream.offset());
-                    }
-
-                    (true, ControlFlow::Continue(()))
-                },
-                (mut a_errors, Err(a_err)) if outputs.len() < self.1 => {
-                    errors.append(&mut a_errors);
-                    (true, ControlFlow::Break((
-                        core::mem::take(&mut errors),
-                        Err(a_err),
-                    )))
-                },
-                (a_errors, Err(a_err)) => {
-                    // Find furthest alternative error
-                    // TODO: Handle multiple alternative errors
-                    // TODO: Should we really be taking *all* of these into consideration?
-                    let alt = merge_alts(
-                        alt.take(),
-                        merge_alts(
-                            Some(a_err),
-                            a_errors.into_iter().next(),
-                        ),
-                    );
-                    (false, ControlFlow::Break((
-                        core::mem::take(&mut errors),
-                        Ok((core::mem::take(&mut outputs), alt)),
-                    )))
-                },
-            }) {
-                break b;
+            Bound::Excluded(&n) => {
+                assert!(
+                    self.1 < n,
+                    "Repeated cannot parse at least {} and fewer than {}",
+                    self.1,
+                    n
+                );
+                Some(n - 1)
             }
-        }
+            Bound::Unbounded => None,
+        };
+        self
     }
 
-    #[inline]
-    fn parse_inner_verbose(
-        &self,
-        d: &mut Verbose,
-        s: &mut StreamOf<I, E>,
-    ) -> PResult<I, Vec<O>, E> {
-        #[allow(deprecated)]
-        self.parse_inner(d, s)
-    }
-    #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, Vec<O>, E> {
-        #[allow(deprecated)]
-        self.parse_inner(d, s)
+    /// Pair each output of the pattern with its zero-based index, like [`Iterator::enumerate`].
+    ///
+    /// The output type of this parser is `Vec<(usize, O)>`.
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let params = text::ident::<_, Simple<char>>()
+    ///     .padded()
+    ///     .repeated()
+    ///     .enumerate();
+    ///
+    /// assert_eq!(
+    ///     params.parse("x y z"),
+    ///     Ok(vec![(0, "x".to_string()), (1, "y".to_string()), (2, "z".to_string())]),
+    /// );
+    /// ```
+    pub fn enumerate<O>(self) -> Enumerate<Self, O> {
+        Map(
+            self,
+            |outputs| outputs.into_iter().enumerate().collect(),
+            PhantomData,
+        )
     }
 }
 
-/// See [`Parser::separated_by`].
-#[must_use]
-pub struct SeparatedBy<A, B, U> {
-    pub(crate) item: A,
-    pub(crate) delimiter: B,
-    pub(crate) at_least: usize,
-    pub(crate) at_most: Option<usize>,
-    pub(crate) allow_leading: bool,
-    pub(crate) allow_trailing: bool,
-    pub(crate) phantom: PhantomData<U>,
-}
+impl<A> Repeated<A> {
+    /// Continue repeating the pattern only while the given predicate holds for the span of the input consumed so far
+    /// (from the start of the first repetition to the end of the latest one).
+    ///
+    /// When a repetition would cause the predicate to fail, that repetition is rewound (i.e: its input is not
+    /// consumed) and the repetition ends. The first repetition is always accepted, so a single pattern that exceeds
+    /// the limit on its own is still parsed. If rewinding leaves fewer repetitions than the minimum required by
+    /// [`Repeated::at_least`], parsing fails.
+    ///
+    /// This is useful for column-limited or byte-budgeted formats, such as fixed-width records.
+    ///
+    /// The output type of this parser is `Vec<O>`, the same as [`Parser::repeated`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let word = text::ident::<_, Simple<char>>().then_ignore(just(' ').or_not());
+    ///
+    /// // Parse as many words as fit within 10 columns
+    /// let line = word.repeated().while_span(|span: &std::ops::Range<usize>| span.end <= 10);
+    ///
+    /// assert_eq!(
+    ///     line.then(word.repeated()).parse("the quick brown fox"),
+    ///     Ok((vec!["the".to_string(), "quick".to_string()], vec!["brown".to_string(), "fox".to_string()])),
+    /// );
+    /// // A single word that's too long on its own is still accepted
+    /// assert_eq!(line.parse("supercalifragilistic"), Ok(vec!["supercalifragilistic".to_string()]));
+    /// ```
+    pub fn while_span<F>(self, f: F) -> RepeatedWhileSpan<A, F> {
+        RepeatedWhileSpan(self, f)
+    }
 
-impl<A, B, U> SeparatedBy<A, B, U> {
-    /// Allow a leading separator to appear before the first item.
+    /// Append each repeated [`char`] directly to a caller-provided [`String`] buffer, rather than collecting them into
+    /// a new [`String`] as [`Parser::collect`] would.
     ///
-    /// Note that even if no items are parsed, a leading separator *is* permitted.
+    /// Neither an intermediate [`Vec`] nor a fresh [`String`] is allocated, so a buffer that is reused between
+    /// parses (for example, when transforming a stream of text chunk by chunk with [`Parser::parse_continue`]) allows
+    /// text to be processed without per-parse allocations.
+    ///
+    /// If the parser fails, any characters that it appended are removed from the buffer. However, characters are
+    /// *not* removed if an enclosing parser later backtracks over this one: use the output (the byte range of the
+    /// appended text within the buffer) to truncate the buffer if necessary.
+    ///
+    /// The output type of this parser is `Range<usize>`, the byte range of the text appended to the buffer.
     ///
     /// # Examples
     ///
     /// ```
     /// # use chumsky::prelude::*;
-    /// let r#enum = text::keyword::<_, _, Simple<char>>("enum")
-    ///     .padded()
-    ///     .ignore_then(text::ident()
-    ///         .padded()
-    ///         .separated_by(just('|'))
-    ///         .allow_leading());
+    /// use std::cell::RefCell;
     ///
-    /// assert_eq!(r#enum.parse("enum True | False"), Ok(vec!["True".to_string(), "False".to_string()]));
-    /// assert_eq!(r#enum.parse("
-    ///     enum
-    ///     | True
-    ///     | False
-    /// "), Ok(vec!["True".to_string(), "False".to_string()]));
+    /// let buffer = RefCell::new(String::new());
+    ///
+    /// // Normalise runs of whitespace into a single space
+    /// let word = filter::<_, _, Simple<char>>(|c: &char| !c.is_whitespace())
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .collect_into_string(&buffer);
+    /// let space = text::whitespace().at_least(1).map(|_| buffer.borrow_mut().push(' '));
+    /// let normalise = word.separated_by(space).then_ignore(end());
+    ///
+    /// assert_eq!(normalise.parse("hello \n  world"), Ok(vec![0..5, 6..11]));
+    /// assert_eq!(buffer.borrow().as_str(), "hello world");
     /// ```
-    pub fn allow_leading(mut self) -> Self {
-        self.allow_leading = true;
-        self
+    pub fn collect_into_string(self, buffer: &RefCell<String>) -> RepeatedIntoString<'_, A> {
+        RepeatedIntoString(self, buffer)
     }
 
-    /// Allow a trailing separator to appear after the last item.
+    /// Collect the outputs of the pattern directly into a [`VecDeque`], rather than into a [`Vec`].
     ///
-    /// Note that if no items are parsed, no leading separator is permitted.
+    /// If the number of repetitions is bounded (by [`Repeated::at_most`] or [`Repeated::exactly`]), the deque is
+    /// allocated with enough capacity for the maximum number of elements up-front. Otherwise, it is allocated with
+    /// capacity for the minimum number of elements. Either way, the up-front allocation is limited to a small number of
+    /// elements (currently 256), beyond which the deque grows as usual.
+    ///
+    /// Elements appear in the order that they were parsed (i.e: the front of the deque is the first element parsed),
+    /// unless [`RepeatedIntoDeque::rev`] is used.
+    ///
+    /// The output type of this parser is `VecDeque<O>`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use chumsky::prelude::*;
-    /// let numbers = text::int::<_, Simple<char>>(10)
+    /// use std::collections::VecDeque;
+    ///
+    /// let jobs = text::int::<_, Simple<char>>(10)
     ///     .padded()
-    ///     .separated_by(just(','))
-    ///     .allow_trailing()
-    ///     .delimited_by(just('('), just(')'));
+    ///     .repeated()
+    ///     .at_most(4)
+    ///     .collect_deque();
     ///
-    /// assert_eq!(numbers.parse("(1, 2)"), Ok(vec!["1".to_string(), "2".to_string()]));
-    /// assert_eq!(numbers.parse("(1, 2,)"), Ok(vec!["1".to_string(), "2".to_string()]));
+    /// let mut queue = jobs.parse("1 2 3").unwrap();
+    /// assert_eq!(queue.pop_front(), Some("1".to_string()));
+    /// assert!(queue.capacity() >= 4);
+    ///
+    /// let stack: VecDeque<_> = jobs.rev().parse("1 2 3").unwrap();
+    /// assert_eq!(stack, ["3", "2", "1"]);
     /// ```
-    pub fn allow_trailing(mut self) -> Self {
-        self.allow_trailing = true;
-        self
+    pub fn collect_deque(self) -> RepeatedIntoDeque<A> {
+        RepeatedIntoDeque(self, false)
     }
 
-    /// Require that the pattern appear at least a minimum number of times.
+    /// Count the number of times that the pattern was parsed, rather than collecting the outputs.
+    ///
+    /// The outputs of the pattern are dropped as soon as they are produced, so no collection is allocated.
+    ///
+    /// The output type of this parser is `usize`, the number of times the pattern was parsed.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # use chumsky::prelude::*;
-    /// let numbers = just::<_, _, Simple<char>>('-')
-    ///     .separated_by(just('.'))
-    ///     .at_least(2);
+    /// let indent = just::<_, _, Simple<char>>("  ").repeated().at_most(3).counted();
     ///
-    /// assert!(numbers.parse("").is_err());
-    /// assert!(numbers.parse("-").is_err());
-    /// assert_eq!(numbers.parse("-.-"), Ok(vec!['-', '-']));
-    /// ````
-    pub fn at_least(mut self, n: usize) -> Self {
-        self.at_least = n;
-        self
+    /// assert_eq!(indent.then_ignore(just('x')).parse("    x"), Ok(2));
+    /// assert!(indent.then_ignore(just('x')).parse("        x").is_err());
+    /// ```
+    pub fn counted<O>(self) -> Counted<A, O> {
+        Counted(self, PhantomData)
     }
 
-    /// Require that the pattern appear at most a maximum number of times.
+    /// Collect the outputs of the pattern directly into a collection implementing [`Extend`], such as a [`String`],
+    /// rather than into a [`Vec`].
+    ///
+    /// This produces the same output as `.collect::<C>()`, but each output is added to the collection as soon as it is
+    /// parsed, avoiding the allocation (and copy) of an intermediate [`Vec`]. This can make a difference to
+    /// performance when parsing long runs of tokens.
+    ///
+    /// The second type parameter, `O`, is the output type of the pattern and can be left to inference.
+    ///
+    /// The output type of this parser is `C`, the type being collected into.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # use chumsky::prelude::*;
-    /// let row_4 = text::int::<_, Simple<char>>(10)
-    ///     .padded()
-    ///     .separated_by(just(','))
-    ///     .at_most(4);
-    ///
-    /// let matrix_4x4 = row_4
-    ///     .separated_by(just(','))
-    ///     .at_most(4);
+    /// let word = filter::<_, _, Simple<char>>(|c: &char| c.is_alphabetic())
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .collect_direct::<String, _>();
     ///
-    /// assert_eq!(
-    ///     matrix_4x4.parse("0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15"),
-    ///     Ok(vec![
-    ///         vec!["0".to_string(), "1".to_string(), "2".to_string(), "3".to_string()],
-    ///         vec!["4".to_string(), "5".to_string(), "6".to_string(), "7".to_string()],
-    ///         vec!["8".to_string(), "9".to_string(), "10".to_string(), "11".to_string()],
-    ///         vec!["12".to_string(), "13".to_string(), "14".to_string(), "15".to_string()],
-    ///     ]),
-    /// );
-    /// ````
-    pub fn at_most(mut self, n: usize) -> Self {
-        self.at_most = Some(n);
-        self
+    /// assert_eq!(word.parse("hello world"), Ok("hello".to_string()));
+    /// assert!(word.parse("42").is_err());
+    /// ```
+    pub fn collect_direct<C, O>(self) -> RepeatedDirect<A, C, O> {
+        RepeatedDirect(self, PhantomData)
     }
 
-    /// Require that the pattern appear exactly the given number of times.
+    /// Fold the outputs of the pattern into a single value, starting from the given initial value.
+    ///
+    /// This behaves like [`Parser::fold`], but each output is folded into the accumulator as soon as it is parsed, so
+    /// no collection is allocated.
+    ///
+    /// The output type of this parser is `Acc`, the type of the accumulator.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # use chumsky::prelude::*;
-    /// let coordinate_3d = text::int::<_, Simple<char>>(10)
-    ///     .padded()
-    ///     .separated_by(just(','))
-    ///     .exactly(3)
-    ///     .then_ignore(end());
+    /// // A set of single-character flags, combined into a bitmask
+    /// let flag = just::<_, _, Simple<char>>('r').to(0b100u8)
+    ///     .or(just('w').to(0b010))
+    ///     .or(just('x').to(0b001));
+    /// let mode = flag.repeated().at_most(3).fold(0, |mask, flag| mask | flag);
     ///
-    /// // Not enough elements
-    /// assert!(coordinate_3d.parse("4, 3").is_err());
-    /// // Too many elements
-    /// assert!(coordinate_3d.parse("7, 2, 13, 4").is_err());
-    /// // Just the right number of elements
-    /// assert_eq!(coordinate_3d.parse("5, 0, 12"), Ok(vec!["5".to_string(), "0".to_string(), "12".to_string()]));
-    /// ````
-    pub fn exactly(mut self, n: usize) -> Self {
-        self.at_least = n;
-        self.at_most = Some(n);
-        self
-    }
-}
-
-impl<A: Copy, B: Copy, U> Copy for SeparatedBy<A, B, U> {}
-impl<A: Clone, B: Clone, U> Clone for SeparatedBy<A, B, U> {
-    fn clone(&self) -> Self {
-        Self {
-            item: self.item.clone(),
-            delimiter: self.delimiter.clone(),
-            at_least: self.at_least,
-            at_most: self.at_most,
-            allow_leading: self.allow_leading,
-            allow_trailing: self.allow_trailing,
-            phantom: PhantomData,
-        }
+    /// assert_eq!(mode.parse("rx"), Ok(0b101));
+    /// assert_eq!(mode.parse("rwx"), Ok(0b111));
+    /// assert_eq!(mode.parse(""), Ok(0));
+    /// ```
+    pub fn fold<Acc, F, O>(self, init: Acc, f: F) -> RepeatedFold<A, Acc, F, O>
+    where
+        Acc: Clone,
+        F: Fn(Acc, O) -> Acc,
+    {
+        RepeatedFold(self, init, f, PhantomData)
     }
-}
-
-impl<I: Clone, O, U, A: Parser<I, O, Error = E>, B: Parser<I, U, Error = E>, E: Error<I>>
-    Parser<I, Vec<O>> for SeparatedBy<A, B, U>
-{
-    type Error = E;
 
-    #[inline]
-    fn parse_inner<D: Debugger>(
+    fn parse_while<
+        I: Clone,
+        O,
+        D: Debugger,
+        E: Error<I>,
+        C: Extend<O>,
+        F: FnMut(&mut StreamOf<I, E>, usize) -> bool,
+    >(
         &self,
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, Vec<O>, E> {
-        if let Some(at_most) = self.at_most {
+        outputs: &mut C,
+        mut accept: F,
+    ) -> PResult<I, (), E>
+    where
+        A: Parser<I, O, Error = E>,
+    {
+        if let Some(at_most) = self.2 {
             assert!(
-                self.at_least <= at_most,
-                "SeparatedBy cannot parse at least {} and at most {}",
-                self.at_least,
+                self.1 <= at_most,
+                "Repeated cannot parse at least {} and at most {}",
+                self.1,
                 at_most
             );
         }
 
-        enum State<I, E> {
-            Terminated(Located<I, E>),
-            Continue,
-        }
-
-        fn parse_or_not<U, B: Parser<I, U, Error = E>, I: Clone, E: Error<I>, D: Debugger>(
-            delimiter: &B,
-            stream: &mut StreamOf<I, E>,
-            debugger: &mut D,
-            alt: Option<Located<I, E>>,
-        ) -> Option<Located<I, E>> {
-            match stream.try_parse(|stream| {
-                #[allow(deprecated)]
-                debugger.invoke(&delimiter, stream)
-            }) {
-                // These two paths are successful path so the furthest errors are merged with the alt.
-                (d_errors, Ok((_, d_alt))) => merge_alts(alt, merge_alts(d_alt, d_errors)),
-                (d_errors, Err(d_err)) => merge_alts(alt, merge_alts(Some(d_err), d_errors)),
-            }
-        }
-
-        fn parse<O, A: Parser<I, O, Error = E>, I: Clone, E: Error<I>, D: Debugger>(
-            item: &A,
-            stream: &mut StreamOf<I, E>,
-            debugger: &mut D,
-            outputs: &mut Vec<O>,
-            errors: &mut Vec<Located<I, E>>,
-            alt: Option<Located<I, E>>,
-        ) -> (State<I, E>, Option<Located<I, E>>) {
-            match stream.try_parse(|stream| {
-                #[allow(deprecated)]
-                debugger.invoke(item, stream)
-            }) {
-                (mut i_errors, Ok((i_out, i_alt))) => {
-                    outputs.push(i_out);
-                    errors.append(&mut i_errors);
-                    (State::Continue, merge_alts(alt, i_alt))
-                }
-                (mut i_errors, Err(i_err)) => {
-                    errors.append(&mut i_errors);
-                    (State::Terminated(i_err), alt)
-                }
-            }
-        }
-
-        let mut outputs = Vec::new();
         let mut errors = Vec::new();
+        let mut count = 0;
         let mut alt = None;
+        let mut old_offset = None;
+        let start = stream.save();
 
-        if self.allow_leading {
-            alt = parse_or_not(&self.delimiter, stream, debugger, alt);
-        }
-
-        let (mut state, mut alt) =
-            parse(&self.item, stream, debugger, &mut outputs, &mut errors, alt);
-
-        let mut offset = stream.save();
-        let error: Option<Located<I, E>>;
         loop {
-            if let State::Terminated(err) = state {
-                error = Some(err);
-                break;
-            }
-            offset = stream.save();
-
-            if self
-                .at_most
-                .map_or(false, |at_most| outputs.len() >= at_most)
-            {
-                error = None;
-                break;
+            if self.2.map_or(false, |max| count >= max) {
+                break (errors, Ok(((), alt)));
             }
 
-            match stream.try_parse(|stream| {
-                #[allow(deprecated)]
-                debugger.invoke(&self.delimiter, stream)
-            }) {
-                (mut d_errors, Ok((_, d_alt))) => {
-                    errors.append(&mut d_errors);
-                    alt = merge_alts(alt, d_alt);
+            if let ControlFlow::Break(b) = stream.attempt(|stream| {
+                let before = stream.save();
+                let (mut a_errors, a_res) = stream.cut_scope(|stream| {
+                    #[allow(deprecated)]
+                    debugger.invoke(&self.0, stream)
+                });
+                match a_res {
+                    Ok((_, _)) if count > 0 && !accept(stream, start) => {
+                        stream.revert(before);
+                        let res = if count < self.1 {
+                            let (at, span, found) = stream.next();
+                            Err(Located::at(at, E::expected_input_found(span, None, found)))
+                        } else {
+                            Ok(((), alt.take()))
+                        };
+                        (false, ControlFlow::Break((core::mem::take(&mut errors), res)))
+                    }
+                    Ok((a_out, a_alt)) => {
+                        errors.append(&mut a_errors);
+                        alt = merge_alts(alt.take(), a_alt);
+                        outputs.extend(Some(a_out));
+                        count += 1;
+
+                        if old_offset == Some(stream.offset()) {
+                            panic!("Repeated parser iteration succeeded but consumed no inputs (i.e: continuing \
+                                iteration would likely lead to an infinite loop, if the parser is pure). This is \
+                                likely indicative of a parser bug. Consider using a more specific error recovery \
+                                strategy.");
+                        } else {
+                            old_offset = Some(stream.offset());
+                        }
 
-                    let (i_state, i_alt) =
-                        parse(&self.item, stream, debugger, &mut outputs, &mut errors, alt);
-                    state = i_state;
-                    alt = i_alt;
-                }
-                (mut d_errors, Err(d_err)) => {
-                    errors.append(&mut d_errors);
-                    state = State::Terminated(d_err);
+                        (true, ControlFlow::Continue(()))
+                    }
+                    Err(a_err) if count < self.1 || a_err.committed => {
+                        errors.append(&mut a_errors);
+                        (
+                            true,
+                            ControlFlow::Break((
+                                core::mem::take(&mut errors),
+                                Err(a_err.uncommit()),
+                            )),
+                        )
+                    }
+                    Err(a_err) => {
+                        // Find furthest alternative error
+                        // TODO: Handle multiple alternative errors
+                        // TODO: Should we really be taking *all* of these into consideration?
+                        let alt = merge_alts(
+                            alt.take(),
+                            merge_alts(Some(a_err), a_errors.into_iter().next()),
+                        );
+                        (
+                            false,
+                            ControlFlow::Break((
+                                core::mem::take(&mut errors),
+                                Ok(((), alt)),
+                            )),
+                        )
+                    }
                 }
+            }) {
+                break b;
             }
         }
-        stream.revert(offset);
+    }
+}
 
-        if self.allow_trailing && !outputs.is_empty() {
-            alt = parse_or_not(&self.delimiter, stream, debugger, alt);
-        }
+impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, Vec<O>> for Repeated<A> {
+    type Error = E;
 
-        if outputs.len() >= self.at_least {
-            alt = merge_alts(alt, error);
-            (errors, Ok((outputs, alt)))
-        } else if let Some(error) = error {
-            // In all paths where `State = State::Terminated`, Some(err) is inserted into alt.
-            (errors, Err(error))
-        } else {
-            (errors, Ok((outputs, alt)))
-        }
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, Vec<O>, E> {
+        let mut outputs = Vec::new();
+        let (errors, res) = self.parse_while(debugger, stream, &mut outputs, |_, _| true);
+        (errors, res.map(|((), alt)| (outputs, alt)))
     }
 
     #[inline]
@@ -893,21 +1374,14 @@ impl<I: Clone, O, U, A: Parser<I, O, Error = E>, B: Parser<I, U, Error = E>, E:
     }
 }
 
-/// See [`Parser::debug`].
+/// See [`Repeated::while_span`].
 #[must_use]
-pub struct Debug<A>(
-    pub(crate) A,
-    pub(crate) Rc<dyn fmt::Display>,
-    pub(crate) core::panic::Location<'static>,
-);
-
-impl<A: Clone> Clone for Debug<A> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone(), self.2)
-    }
-}
+#[derive(Copy, Clone)]
+pub struct RepeatedWhileSpan<A, F>(pub(crate) Repeated<A>, pub(crate) F);
 
-impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, O> for Debug<A> {
+impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(&E::Span) -> bool, E: Error<I>>
+    Parser<I, Vec<O>> for RepeatedWhileSpan<A, F>
+{
     type Error = E;
 
     #[inline]
@@ -915,43 +1389,48 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, O> for Debu
         &self,
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, O, E> {
-        debugger.scope(
-            || ParserInfo::new("Name", self.1.clone(), self.2),
-            |debugger| {
-                #[allow(deprecated)]
-                let (errors, res) = debugger.invoke(&self.0, stream);
-
-                (errors, res)
-            },
-        )
+    ) -> PResult<I, Vec<O>, E> {
+        let mut outputs = Vec::new();
+        let (errors, res) = self
+            .0
+            .parse_while(debugger, stream, &mut outputs, |stream, start| {
+                (self.1)(&stream.span_since(start))
+            });
+        (errors, res.map(|((), alt)| (outputs, alt)))
     }
 
     #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Vec<O>, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
     #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, Vec<O>, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
 }
 
-/// See [`Parser::map`].
+/// See [`Repeated::collect_into_string`].
 #[must_use]
-pub struct Map<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+#[derive(Copy, Clone)]
+pub struct RepeatedIntoString<'a, A>(pub(crate) Repeated<A>, pub(crate) &'a RefCell<String>);
 
-impl<A: Copy, F: Copy, O> Copy for Map<A, F, O> {}
-impl<A: Clone, F: Clone, O> Clone for Map<A, F, O> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone(), PhantomData)
+// Appends to the buffer one character at a time so that nested parsers may also borrow it
+struct StringSink<'a>(&'a RefCell<String>);
+
+impl<'a> Extend<char> for StringSink<'a> {
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        self.0.borrow_mut().extend(iter);
     }
 }
 
-impl<I: Clone, O, A: Parser<I, O, Error = E>, U, F: Fn(O) -> U, E: Error<I>> Parser<I, U>
-    for Map<A, F, O>
+impl<'a, I: Clone, A: Parser<I, char, Error = E>, E: Error<I>> Parser<I, Range<usize>>
+    for RepeatedIntoString<'a, A>
 {
     type Error = E;
 
@@ -960,38 +1439,68 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, U, F: Fn(O) -> U, E: Error<I>> Par
         &self,
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, U, E> {
-        #[allow(deprecated)]
-        let (errors, res) = debugger.invoke(&self.0, stream);
-
-        (errors, res.map(|(out, alt)| ((&self.1)(out), alt)))
+    ) -> PResult<I, Range<usize>, E> {
+        // `Chain` is implemented for all types, so `String::len` must be named explicitly
+        let start = String::len(&self.1.borrow());
+        let (errors, res) =
+            self.0
+                .parse_while(debugger, stream, &mut StringSink(self.1), |_, _| true);
+        match res {
+            Ok(((), alt)) => (errors, Ok((start..String::len(&self.1.borrow()), alt))),
+            Err(err) => {
+                self.1.borrow_mut().truncate(start);
+                (errors, Err(err))
+            }
+        }
     }
 
     #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Range<usize>, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
     #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Range<usize>, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
 }
 
-/// See [`Parser::map_with_span`].
+/// See [`Repeated::collect_deque`].
 #[must_use]
-pub struct MapWithSpan<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+#[derive(Copy, Clone)]
+pub struct RepeatedIntoDeque<A>(pub(crate) Repeated<A>, pub(crate) bool);
 
-impl<A: Copy, F: Copy, O> Copy for MapWithSpan<A, F, O> {}
-impl<A: Clone, F: Clone, O> Clone for MapWithSpan<A, F, O> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone(), PhantomData)
+impl<A> RepeatedIntoDeque<A> {
+    /// Collect the elements in reverse order, such that the front of the deque is the last element parsed.
+    pub fn rev(mut self) -> Self {
+        self.1 = true;
+        self
     }
 }
 
-impl<I: Clone, O, A: Parser<I, O, Error = E>, U, F: Fn(O, E::Span) -> U, E: Error<I>> Parser<I, U>
-    for MapWithSpan<A, F, O>
+// Pushes elements onto the front of the deque, reversing their order
+struct DequeFrontSink<'a, O>(&'a mut VecDeque<O>);
+
+impl<'a, O> Extend<O> for DequeFrontSink<'a, O> {
+    fn extend<T: IntoIterator<Item = O>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|x| self.0.push_front(x));
+    }
+}
+
+// The largest capacity that `Repeated::collect_deque` will allocate up-front
+const MAX_PRESIZED_DEQUE: usize = 256;
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, VecDeque<O>>
+    for RepeatedIntoDeque<A>
 {
     type Error = E;
 
@@ -1000,49 +1509,66 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, U, F: Fn(O, E::Span) -> U, E: Erro
         &self,
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, U, E> {
-        let start = stream.save();
-        #[allow(deprecated)]
-        let (errors, res) = debugger.invoke(&self.0, stream);
-
-        (
-            errors,
-            res.map(|(out, alt)| ((self.1)(out, stream.span_since(start)), alt)),
-        )
+    ) -> PResult<I, VecDeque<O>, E> {
+        let Repeated(_, at_least, at_most) = self.0;
+        // Bounds may be far larger than the input, so don't trust them with the allocation entirely
+        let capacity = at_most.unwrap_or(at_least).min(MAX_PRESIZED_DEQUE);
+        let mut outputs = VecDeque::with_capacity(capacity);
+        let (errors, res) = if self.1 {
+            self.0.parse_while(
+                debugger,
+                stream,
+                &mut DequeFrontSink(&mut outputs),
+                |_, _| true,
+            )
+        } else {
+            self.0
+                .parse_while(debugger, stream, &mut outputs, |_, _| true)
+        };
+        (errors, res.map(|((), alt)| (outputs, alt)))
     }
 
     #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, VecDeque<O>, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
     #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, VecDeque<O>, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
 }
 
-/// See [`Parser::validate`].
+/// See [`Parser::counted`] and [`Repeated::counted`].
 #[must_use]
-pub struct Validate<A, U, F>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<U>);
+pub struct Counted<A, O>(pub(crate) Repeated<A>, pub(crate) PhantomData<O>);
 
-impl<A: Copy, U, F: Copy> Copy for Validate<A, U, F> {}
-impl<A: Clone, U, F: Clone> Clone for Validate<A, U, F> {
+impl<A: Copy, O> Copy for Counted<A, O> {}
+impl<A: Clone, O> Clone for Counted<A, O> {
     fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone(), PhantomData)
+        Self(self.0.clone(), PhantomData)
     }
 }
 
-impl<
-        I: Clone,
-        O,
-        U,
-        A: Parser<I, O, Error = E>,
-        F: Fn(O, E::Span, &mut dyn FnMut(E)) -> U,
-        E: Error<I>,
-    > Parser<I, U> for Validate<A, O, F>
-{
+// Counts elements without storing them
+struct CountSink(usize);
+
+impl<O> Extend<O> for CountSink {
+    fn extend<T: IntoIterator<Item = O>>(&mut self, iter: T) {
+        self.0 += iter.into_iter().count();
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, usize> for Counted<A, O> {
     type Error = E;
 
     #[inline]
@@ -1050,55 +1576,39 @@ impl<
         &self,
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, U, E> {
-        let start = stream.save();
-        #[allow(deprecated)]
-        let (mut errors, res) = debugger.invoke(&self.0, stream);
-
-        let pos = stream.save();
-        let span = stream.span_since(start);
-
-        let res = res.map(|(out, alt)| {
-            (
-                (&self.1)(out, span, &mut |e| errors.push(Located::at(pos, e))),
-                alt,
-            )
-        });
-
-        (errors, res)
+    ) -> PResult<I, usize, E> {
+        let mut count = CountSink(0);
+        let (errors, res) = self
+            .0
+            .parse_while(debugger, stream, &mut count, |_, _| true);
+        (errors, res.map(|((), alt)| (count.0, alt)))
     }
 
     #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, usize, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
     #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, usize, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
 }
 
-/// See [`Parser::foldl`].
+/// See [`Repeated::collect_direct`].
 #[must_use]
-pub struct Foldl<A, F, O, U>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<(O, U)>);
+pub struct RepeatedDirect<A, C, O>(pub(crate) Repeated<A>, pub(crate) PhantomData<(C, O)>);
 
-impl<A: Copy, F: Copy, O, U> Copy for Foldl<A, F, O, U> {}
-impl<A: Clone, F: Clone, O, U> Clone for Foldl<A, F, O, U> {
+impl<A: Copy, C, O> Copy for RepeatedDirect<A, C, O> {}
+impl<A: Clone, C, O> Clone for RepeatedDirect<A, C, O> {
     fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone(), PhantomData)
+        Self(self.0.clone(), PhantomData)
     }
 }
 
-impl<
-        I: Clone,
-        O,
-        A: Parser<I, (O, U), Error = E>,
-        U: IntoIterator,
-        F: Fn(O, U::Item) -> O,
-        E: Error<I>,
-    > Parser<I, O> for Foldl<A, F, O, U>
+impl<I: Clone, O, A: Parser<I, O, Error = E>, C: Default + Extend<O>, E: Error<I>> Parser<I, C>
+    for RepeatedDirect<A, C, O>
 {
     type Error = E;
 
@@ -1107,82 +1617,55 @@ impl<
         &self,
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, O, E> {
-        #[allow(deprecated)]
-        debugger.invoke(
-            &(&self.0).map(|(head, tail)| tail.into_iter().fold(head, &self.1)),
-            stream,
-        )
+    ) -> PResult<I, C, E> {
+        let mut outputs = C::default();
+        let (errors, res) = self
+            .0
+            .parse_while(debugger, stream, &mut outputs, |_, _| true);
+        (errors, res.map(|((), alt)| (outputs, alt)))
     }
 
     #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, C, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
     #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, C, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
 }
 
-/// See [`Parser::foldr`].
+/// See [`Repeated::fold`].
 #[must_use]
-pub struct Foldr<A, F, O, U>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<(O, U)>);
+pub struct RepeatedFold<A, Acc, F, O>(
+    pub(crate) Repeated<A>,
+    pub(crate) Acc,
+    pub(crate) F,
+    pub(crate) PhantomData<O>,
+);
 
-impl<A: Copy, F: Copy, O, U> Copy for Foldr<A, F, O, U> {}
-impl<A: Clone, F: Clone, O, U> Clone for Foldr<A, F, O, U> {
+impl<A: Copy, Acc: Copy, F: Copy, O> Copy for RepeatedFold<A, Acc, F, O> {}
+impl<A: Clone, Acc: Clone, F: Clone, O> Clone for RepeatedFold<A, Acc, F, O> {
     fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone(), PhantomData)
+        Self(self.0.clone(), self.1.clone(), self.2.clone(), PhantomData)
     }
 }
 
-impl<
-        I: Clone,
-        O: IntoIterator,
-        A: Parser<I, (O, U), Error = E>,
-        U,
-        F: Fn(O::Item, U) -> U,
-        E: Error<I>,
-    > Parser<I, U> for Foldr<A, F, O, U>
-where
-    O::IntoIter: DoubleEndedIterator,
-{
-    type Error = E;
-
-    #[inline]
-    fn parse_inner<D: Debugger>(
-        &self,
-        debugger: &mut D,
-        stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, U, E> {
-        #[allow(deprecated)]
-        debugger.invoke(
-            &(&self.0).map(|(init, end)| init.into_iter().rev().fold(end, |b, a| (&self.1)(a, b))),
-            stream,
-        )
-    }
+// Folds elements into an accumulator as they are produced
+struct FoldSink<'a, Acc, F>(Option<Acc>, &'a F);
 
-    #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
-        #[allow(deprecated)]
-        self.parse_inner(d, s)
-    }
-    #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
-        #[allow(deprecated)]
-        self.parse_inner(d, s)
+impl<'a, Acc, O, F: Fn(Acc, O) -> Acc> Extend<O> for FoldSink<'a, Acc, F> {
+    fn extend<T: IntoIterator<Item = O>>(&mut self, iter: T) {
+        for item in iter {
+            self.0 = self.0.take().map(|acc| (self.1)(acc, item));
+        }
     }
 }
 
-/// See [`Parser::map_err`].
-#[must_use]
-#[derive(Copy, Clone)]
-pub struct MapErr<A, F>(pub(crate) A, pub(crate) F);
-
-impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E) -> E, E: Error<I>> Parser<I, O>
-    for MapErr<A, F>
+impl<I: Clone, O, A: Parser<I, O, Error = E>, Acc: Clone, F: Fn(Acc, O) -> Acc, E: Error<I>>
+    Parser<I, Acc> for RepeatedFold<A, Acc, F, O>
 {
     type Error = E;
 
@@ -1191,36 +1674,34 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E) -> E, E: Error<I>> Parser
         &self,
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, O, E> {
-        #[allow(deprecated)]
-        let (errors, res) = debugger.invoke(&self.0, stream);
-        let mapper = |e: Located<I, E>| e.map(&self.1);
+    ) -> PResult<I, Acc, E> {
+        let mut acc = FoldSink(Some(self.1.clone()), &self.2);
+        let (errors, res) = self.0.parse_while(debugger, stream, &mut acc, |_, _| true);
         (
-            errors, //errors.into_iter().map(mapper).collect(),
-            res /*.map(|(out, alt)| (out, alt.map(mapper)))*/
-                .map_err(mapper),
+            errors,
+            res.map(|((), alt)| (acc.0.expect("accumulator is always present"), alt)),
         )
     }
 
     #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, Acc, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
     #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, Acc, E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
 }
 
-/// See [`Parser::map_err_with_span`].
+/// See [`Parser::repeated_until_value`].
 #[must_use]
 #[derive(Copy, Clone)]
-pub struct MapErrWithSpan<A, F>(pub(crate) A, pub(crate) F);
+pub struct RepeatedUntilValue<A, F>(pub(crate) A, pub(crate) F);
 
-impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E, E::Span) -> E, E: Error<I>> Parser<I, O>
-    for MapErrWithSpan<A, F>
+impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(&O) -> bool, E: Error<I>> Parser<I, (Vec<O>, O)>
+    for RepeatedUntilValue<A, F>
 {
     type Error = E;
 
@@ -1229,96 +1710,2072 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E, E::Span) -> E, E: Error<I
         &self,
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, O, E> {
-        let start = stream.save();
-        #[allow(deprecated)]
-        let (errors, res) = debugger.invoke(&self.0, stream);
-        let mapper = |e: Located<I, E>| {
-            let at = e.at;
-            e.map(|e| {
-                let span = stream.attempt(|stream| {
-                    stream.revert(at);
-                    (false, stream.span_since(start))
-                });
-                (self.1)(e, span)
-            })
-        };
-        (errors, res.map_err(mapper))
+    ) -> PResult<I, (Vec<O>, O), E> {
+        let mut errors = Vec::new();
+        let mut outputs = Vec::new();
+        let mut alt = None;
+
+        loop {
+            let before = stream.save();
+            #[allow(deprecated)]
+            let (mut a_errors, a_res) = debugger.invoke(&self.0, stream);
+            errors.append(&mut a_errors);
+            match a_res {
+                Ok((out, a_alt)) => {
+                    alt = merge_alts(alt.take(), a_alt);
+                    if (self.1)(&out) {
+                        break (errors, Ok(((outputs, out), alt)));
+                    } else if stream.save() == before {
+                        panic!("Repeated parser iteration succeeded but consumed no inputs (i.e: continuing \
+                            iteration would likely lead to an infinite loop, if the parser is pure). This is \
+                            likely indicative of a parser bug. Consider using a more specific error recovery \
+                            strategy.");
+                    }
+                    outputs.push(out);
+                }
+                // Running out of elements before the terminator is an error
+                Err(err) => break (errors, Err(err.max(alt))),
+            }
+        }
     }
 
     #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, (Vec<O>, O), E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
     #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, (Vec<O>, O), E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
 }
 
-/// See [`Parser::try_map`].
+/// See [`Parser::separated_by`].
 #[must_use]
-pub struct TryMap<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
-
-impl<A: Copy, F: Copy, O> Copy for TryMap<A, F, O> {}
-impl<A: Clone, F: Clone, O> Clone for TryMap<A, F, O> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone(), PhantomData)
-    }
+pub struct SeparatedBy<A, B, U> {
+    pub(crate) item: A,
+    pub(crate) delimiter: B,
+    pub(crate) at_least: usize,
+    pub(crate) at_most: Option<usize>,
+    pub(crate) allow_leading: bool,
+    pub(crate) allow_trailing: bool,
+    pub(crate) phantom: PhantomData<U>,
 }
 
-impl<
-        I: Clone,
-        O,
-        A: Parser<I, O, Error = E>,
-        U,
-        F: Fn(O, E::Span) -> Result<U, E>,
-        E: Error<I>,
-    > Parser<I, U> for TryMap<A, F, O>
-{
-    type Error = E;
-
-    #[inline]
-    fn parse_inner<D: Debugger>(
-        &self,
-        debugger: &mut D,
-        stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, U, E> {
-        let start = stream.save();
-        #[allow(deprecated)]
-        let (errors, res) = debugger.invoke(&self.0, stream);
-
-        let res = match res.map(|(out, alt)| ((&self.1)(out, stream.span_since(start)), alt)) {
-            Ok((Ok(out), alt)) => Ok((out, alt)),
-            Ok((Err(a_err), _)) => Err(Located::at(stream.save(), a_err)),
-            Err(err) => Err(err),
-        };
-
-        (errors, res)
+impl<A, B, U> SeparatedBy<A, B, U> {
+    /// Allow a leading separator to appear before the first item.
+    ///
+    /// Note that even if no items are parsed, a leading separator *is* permitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let r#enum = text::keyword::<_, _, Simple<char>>("enum")
+    ///     .padded()
+    ///     .ignore_then(text::ident()
+    ///         .padded()
+    ///         .separated_by(just('|'))
+    ///         .allow_leading());
+    ///
+    /// assert_eq!(r#enum.parse("enum True | False"), Ok(vec!["True".to_string(), "False".to_string()]));
+    /// assert_eq!(r#enum.parse("
+    ///     enum
+    ///     | True
+    ///     | False
+    /// "), Ok(vec!["True".to_string(), "False".to_string()]));
+    /// ```
+    pub fn allow_leading(mut self) -> Self {
+        self.allow_leading = true;
+        self
     }
 
-    #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
-        #[allow(deprecated)]
-        self.parse_inner(d, s)
-    }
-    #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
-        #[allow(deprecated)]
-        self.parse_inner(d, s)
+    /// Allow a trailing separator to appear after the last item.
+    ///
+    /// Note that if no items are parsed, no trailing separator is permitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let numbers = text::int::<_, Simple<char>>(10)
+    ///     .padded()
+    ///     .separated_by(just(','))
+    ///     .allow_trailing()
+    ///     .delimited_by(just('('), just(')'));
+    ///
+    /// assert_eq!(numbers.parse("(1, 2)"), Ok(vec!["1".to_string(), "2".to_string()]));
+    /// assert_eq!(numbers.parse("(1, 2,)"), Ok(vec!["1".to_string(), "2".to_string()]));
+    /// ```
+    pub fn allow_trailing(mut self) -> Self {
+        self.allow_trailing = true;
+        self
     }
-}
 
-/// See [`Parser::or_else`].
-#[must_use]
-#[derive(Copy, Clone)]
+    /// Require that the pattern appear at least a minimum number of times.
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let numbers = just::<_, _, Simple<char>>('-')
+    ///     .separated_by(just('.'))
+    ///     .at_least(2);
+    ///
+    /// assert!(numbers.parse("").is_err());
+    /// assert!(numbers.parse("-").is_err());
+    /// assert_eq!(numbers.parse("-.-"), Ok(vec!['-', '-']));
+    /// ````
+    pub fn at_least(mut self, n: usize) -> Self {
+        self.at_least = n;
+        self
+    }
+
+    /// Require that the pattern appear at most a maximum number of times.
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let row_4 = text::int::<_, Simple<char>>(10)
+    ///     .padded()
+    ///     .separated_by(just(','))
+    ///     .at_most(4);
+    ///
+    /// let matrix_4x4 = row_4
+    ///     .separated_by(just(','))
+    ///     .at_most(4);
+    ///
+    /// assert_eq!(
+    ///     matrix_4x4.parse("0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15"),
+    ///     Ok(vec![
+    ///         vec!["0".to_string(), "1".to_string(), "2".to_string(), "3".to_string()],
+    ///         vec!["4".to_string(), "5".to_string(), "6".to_string(), "7".to_string()],
+    ///         vec!["8".to_string(), "9".to_string(), "10".to_string(), "11".to_string()],
+    ///         vec!["12".to_string(), "13".to_string(), "14".to_string(), "15".to_string()],
+    ///     ]),
+    /// );
+    /// ````
+    pub fn at_most(mut self, n: usize) -> Self {
+        self.at_most = Some(n);
+        self
+    }
+
+    /// Require that the pattern appear exactly the given number of times.
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let coordinate_3d = text::int::<_, Simple<char>>(10)
+    ///     .padded()
+    ///     .separated_by(just(','))
+    ///     .exactly(3)
+    ///     .then_ignore(end());
+    ///
+    /// // Not enough elements
+    /// assert!(coordinate_3d.parse("4, 3").is_err());
+    /// // Too many elements
+    /// assert!(coordinate_3d.parse("7, 2, 13, 4").is_err());
+    /// // Just the right number of elements
+    /// assert_eq!(coordinate_3d.parse("5, 0, 12"), Ok(vec!["5".to_string(), "0".to_string(), "12".to_string()]));
+    /// ````
+    pub fn exactly(mut self, n: usize) -> Self {
+        self.at_least = n;
+        self.at_most = Some(n);
+        self
+    }
+
+    /// Pair each item with its zero-based index, like [`Iterator::enumerate`].
+    ///
+    /// The output type of this parser is `Vec<(usize, O)>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let args = text::int::<_, Simple<char>>(10)
+    ///     .separated_by(just(','))
+    ///     .enumerate();
+    ///
+    /// assert_eq!(
+    ///     args.parse("4,2"),
+    ///     Ok(vec![(0, "4".to_string()), (1, "2".to_string())]),
+    /// );
+    /// ```
+    pub fn enumerate<O>(self) -> Enumerate<Self, O> {
+        Map(
+            self,
+            |outputs| outputs.into_iter().enumerate().collect(),
+            PhantomData,
+        )
+    }
+
+    /// Keep the outputs of the separators, pairing each item with the separator that follows it.
+    ///
+    /// The separator after the last item is `None`, unless a trailing separator was permitted (with
+    /// [`SeparatedBy::allow_trailing`]) and found. A leading separator (permitted with [`SeparatedBy::allow_leading`])
+    /// has no item before it, and so is not included in the output.
+    ///
+    /// The output type of this parser is `Vec<(O, Option<U>)>`, where `U` is the output type of the separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Sep { Dot, Colons }
+    ///
+    /// let path = text::ident::<_, Simple<char>>()
+    ///     .separated_by(just('.').to(Sep::Dot).or(just("::").to(Sep::Colons)))
+    ///     .allow_trailing()
+    ///     .with_separators();
+    ///
+    /// assert_eq!(
+    ///     path.parse("std::fmt.Display"),
+    ///     Ok(vec![
+    ///         ("std".to_string(), Some(Sep::Colons)),
+    ///         ("fmt".to_string(), Some(Sep::Dot)),
+    ///         ("Display".to_string(), None),
+    ///     ]),
+    /// );
+    /// // A trailing separator is paired with the last item
+    /// assert_eq!(
+    ///     path.parse("a::"),
+    ///     Ok(vec![("a".to_string(), Some(Sep::Colons))]),
+    /// );
+    /// ```
+    pub fn with_separators(self) -> WithSeparators<A, B, U> {
+        WithSeparators(self)
+    }
+}
+
+impl<A: Copy, B: Copy, U> Copy for SeparatedBy<A, B, U> {}
+impl<A: Clone, B: Clone, U> Clone for SeparatedBy<A, B, U> {
+    fn clone(&self) -> Self {
+        Self {
+            item: self.item.clone(),
+            delimiter: self.delimiter.clone(),
+            at_least: self.at_least,
+            at_most: self.at_most,
+            allow_leading: self.allow_leading,
+            allow_trailing: self.allow_trailing,
+            phantom: PhantomData,
+        }
+    }
+}
+
+// The outputs of a separated sequence, receiving each item and each separator that was kept in the order that they
+// appear in the input
+trait SeparatedOutput<O, U>: Default {
+    fn item(&mut self, item: O);
+    fn separator(&mut self, separator: U);
+    fn len(&self) -> usize;
+}
+
+impl<O, U> SeparatedOutput<O, U> for Vec<O> {
+    fn item(&mut self, item: O) {
+        self.push(item);
+    }
+    fn separator(&mut self, _: U) {}
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+struct PairedSeparators<O, U>(Vec<(O, Option<U>)>);
+
+impl<O, U> Default for PairedSeparators<O, U> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<O, U> SeparatedOutput<O, U> for PairedSeparators<O, U> {
+    fn item(&mut self, item: O) {
+        self.0.push((item, None));
+    }
+    // Separators are paired with the item before them, so a leading separator has nowhere to go
+    fn separator(&mut self, separator: U) {
+        if let Some((_, sep)) = self.0.last_mut() {
+            *sep = Some(separator);
+        }
+    }
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<A, B, U> SeparatedBy<A, B, U> {
+    fn parse_separated<I: Clone, O, C: SeparatedOutput<O, U>, D: Debugger, E: Error<I>>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, C, E>
+    where
+        A: Parser<I, O, Error = E>,
+        B: Parser<I, U, Error = E>,
+    {
+        if let Some(at_most) = self.at_most {
+            assert!(
+                self.at_least <= at_most,
+                "SeparatedBy cannot parse at least {} and at most {}",
+                self.at_least,
+                at_most
+            );
+        }
+
+        enum State<I, E> {
+            Terminated(Located<I, E>),
+            Continue,
+        }
+
+        fn parse_or_not<U, B: Parser<I, U, Error = E>, I: Clone, E: Error<I>, D: Debugger>(
+            delimiter: &B,
+            stream: &mut StreamOf<I, E>,
+            debugger: &mut D,
+            alt: Option<Located<I, E>>,
+        ) -> (Option<U>, Option<Located<I, E>>) {
+            match stream.try_parse(|stream| {
+                #[allow(deprecated)]
+                debugger.invoke(&delimiter, stream)
+            }) {
+                // These two paths are successful path so the furthest errors are merged with the alt.
+                (d_errors, Ok((d_out, d_alt))) => {
+                    (Some(d_out), merge_alts(alt, merge_alts(d_alt, d_errors)))
+                }
+                (d_errors, Err(d_err)) => {
+                    (None, merge_alts(alt, merge_alts(Some(d_err), d_errors)))
+                }
+            }
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn parse<
+            O,
+            U,
+            C: SeparatedOutput<O, U>,
+            A: Parser<I, O, Error = E>,
+            I: Clone,
+            E: Error<I>,
+            D: Debugger,
+        >(
+            item: &A,
+            stream: &mut StreamOf<I, E>,
+            debugger: &mut D,
+            separator: Option<U>,
+            outputs: &mut C,
+            errors: &mut Vec<Located<I, E>>,
+            alt: Option<Located<I, E>>,
+        ) -> (State<I, E>, Option<Located<I, E>>) {
+            match stream.try_parse(|stream| {
+                #[allow(deprecated)]
+                debugger.invoke(item, stream)
+            }) {
+                (mut i_errors, Ok((i_out, i_alt))) => {
+                    // The separator is only kept once the item following it has been parsed
+                    if let Some(separator) = separator {
+                        outputs.separator(separator);
+                    }
+                    outputs.item(i_out);
+                    errors.append(&mut i_errors);
+                    (State::Continue, merge_alts(alt, i_alt))
+                }
+                (mut i_errors, Err(i_err)) => {
+                    errors.append(&mut i_errors);
+                    (State::Terminated(i_err), alt)
+                }
+            }
+        }
+
+        let mut outputs = C::default();
+        let mut errors = Vec::new();
+        let mut alt = None;
+
+        if self.allow_leading {
+            let (leading, l_alt) = parse_or_not(&self.delimiter, stream, debugger, alt);
+            alt = l_alt;
+            if let Some(leading) = leading {
+                outputs.separator(leading);
+            }
+        }
+
+        let (mut state, mut alt) = parse(
+            &self.item,
+            stream,
+            debugger,
+            None,
+            &mut outputs,
+            &mut errors,
+            alt,
+        );
+
+        let mut offset = stream.save();
+        let error: Option<Located<I, E>>;
+        loop {
+            if let State::Terminated(err) = state {
+                error = Some(err);
+                break;
+            }
+            offset = stream.save();
+
+            if self
+                .at_most
+                .map_or(false, |at_most| outputs.len() >= at_most)
+            {
+                error = None;
+                break;
+            }
+
+            match stream.try_parse(|stream| {
+                #[allow(deprecated)]
+                debugger.invoke(&self.delimiter, stream)
+            }) {
+                (mut d_errors, Ok((d_out, d_alt))) => {
+                    errors.append(&mut d_errors);
+                    alt = merge_alts(alt, d_alt);
+
+                    let (i_state, i_alt) = parse(
+                        &self.item,
+                        stream,
+                        debugger,
+                        Some(d_out),
+                        &mut outputs,
+                        &mut errors,
+                        alt,
+                    );
+                    state = i_state;
+                    alt = i_alt;
+                }
+                (mut d_errors, Err(d_err)) => {
+                    errors.append(&mut d_errors);
+                    state = State::Terminated(d_err);
+                }
+            }
+        }
+        stream.revert(offset);
+
+        if self.allow_trailing && outputs.len() > 0 {
+            let (trailing, t_alt) = parse_or_not(&self.delimiter, stream, debugger, alt);
+            alt = t_alt;
+            if let Some(trailing) = trailing {
+                outputs.separator(trailing);
+            }
+        }
+
+        let committed = matches!(&error, Some(error) if error.committed);
+        if outputs.len() >= self.at_least && !committed {
+            alt = merge_alts(alt, error);
+            (errors, Ok((outputs, alt)))
+        } else if let Some(error) = error {
+            // In all paths where `State = State::Terminated`, Some(err) is inserted into alt.
+            (errors, Err(error.uncommit()))
+        } else {
+            (errors, Ok((outputs, alt)))
+        }
+    }
+}
+
+impl<I: Clone, O, U, A: Parser<I, O, Error = E>, B: Parser<I, U, Error = E>, E: Error<I>>
+    Parser<I, Vec<O>> for SeparatedBy<A, B, U>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, Vec<O>, E> {
+        self.parse_separated(debugger, stream)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Vec<O>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, Vec<O>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`SeparatedBy::with_separators`].
+#[must_use]
+pub struct WithSeparators<A, B, U>(pub(crate) SeparatedBy<A, B, U>);
+
+impl<A: Copy, B: Copy, U> Copy for WithSeparators<A, B, U> {}
+impl<A: Clone, B: Clone, U> Clone for WithSeparators<A, B, U> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I: Clone, O, U, A: Parser<I, O, Error = E>, B: Parser<I, U, Error = E>, E: Error<I>>
+    Parser<I, Vec<(O, Option<U>)>> for WithSeparators<A, B, U>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, Vec<(O, Option<U>)>, E> {
+        let (errors, res) = self.0.parse_separated(debugger, stream);
+        (
+            errors,
+            res.map(|(PairedSeparators(outputs), alt)| (outputs, alt)),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Vec<(O, Option<U>)>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Vec<(O, Option<U>)>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::debug`].
+#[must_use]
+pub struct Debug<A>(
+    pub(crate) A,
+    pub(crate) Rc<dyn fmt::Display>,
+    pub(crate) core::panic::Location<'static>,
+);
+
+impl<A: Clone> Clone for Debug<A> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), self.2)
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, O> for Debug<A> {
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        #[cfg(feature = "profile")]
+        let start = stream.save();
+        let (errors, res) = debugger.scope(
+            || ParserInfo::new("Name", self.1.clone(), self.2),
+            |debugger| {
+                #[allow(deprecated)]
+                let (errors, res) = debugger.invoke(&self.0, stream);
+
+                (errors, res)
+            },
+        );
+        #[cfg(feature = "profile")]
+        crate::profile::record(&*self.1, self.2, stream.save() - start, res.is_err());
+        (errors, res)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::trace`] and [`Parser::trace_output`].
+#[cfg(feature = "std")]
+#[must_use]
+pub struct Trace<A, O>(
+    pub(crate) A,
+    pub(crate) Rc<dyn fmt::Display>,
+    pub(crate) Option<fn(&O) -> String>,
+);
+
+#[cfg(feature = "std")]
+impl<A: Clone, O> Clone for Trace<A, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), self.2)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, O> for Trace<A, O> {
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let start = stream.save();
+        eprintln!("[{}] attempt at {}", self.1, start);
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+        match &res {
+            Ok((out, _)) => {
+                let output = self.2.map(|f| format!(": {}", f(out))).unwrap_or_default();
+                eprintln!(
+                    "[{}] succeeded, consuming {}..{}{}",
+                    self.1,
+                    start,
+                    stream.save(),
+                    output
+                );
+            }
+            Err(e) => eprintln!("[{}] failed with an error at {}", self.1, e.at),
+        }
+        (errors, res)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::map`].
+#[must_use]
+pub struct Map<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+
+impl<A: Copy, F: Copy, O> Copy for Map<A, F, O> {}
+impl<A: Clone, F: Clone, O> Clone for Map<A, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, U, F: Fn(O) -> U, E: Error<I>> Parser<I, U>
+    for Map<A, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        (errors, res.map(|(out, alt)| ((&self.1)(out), alt)))
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// A value whose construction has been deferred by [`Parser::map_deferred`].
+///
+/// Call [`Deferred::force`] to construct the value.
+#[must_use]
+#[derive(Copy, Clone, Debug)]
+pub struct Deferred<O, F> {
+    value: O,
+    f: F,
+}
+
+impl<O, F> Deferred<O, F> {
+    /// Construct the value by applying the deferred function.
+    pub fn force<U>(self) -> U
+    where
+        F: FnOnce(O) -> U,
+    {
+        (self.f)(self.value)
+    }
+
+    /// Get the original output that the deferred function will be applied to.
+    pub fn original(&self) -> &O {
+        &self.value
+    }
+}
+
+/// See [`Parser::map_deferred`].
+#[must_use]
+pub struct MapDeferred<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+
+impl<A: Copy, F: Copy, O> Copy for MapDeferred<A, F, O> {}
+impl<A: Clone, F: Clone, O> Clone for MapDeferred<A, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Clone, E: Error<I>> Parser<I, Deferred<O, F>>
+    for MapDeferred<A, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, Deferred<O, F>, E> {
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        let f = &self.1;
+        (
+            errors,
+            res.map(|(value, alt)| {
+                (
+                    Deferred {
+                        value,
+                        f: f.clone(),
+                    },
+                    alt,
+                )
+            }),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Deferred<O, F>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Deferred<O, F>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::map_with_span`].
+#[must_use]
+pub struct MapWithSpan<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+
+impl<A: Copy, F: Copy, O> Copy for MapWithSpan<A, F, O> {}
+impl<A: Clone, F: Clone, O> Clone for MapWithSpan<A, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, U, F: Fn(O, E::Span) -> U, E: Error<I>> Parser<I, U>
+    for MapWithSpan<A, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        (
+            errors,
+            res.map(|(out, alt)| ((self.1)(out, stream.span_since(start)), alt)),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::map_slice`].
+#[must_use]
+pub struct MapSlice<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+
+impl<A: Copy, F: Copy, O> Copy for MapSlice<A, F, O> {}
+impl<A: Clone, F: Clone, O> Clone for MapSlice<A, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, U, F: Fn(&[(I, E::Span)]) -> U, E: Error<I>>
+    Parser<I, U> for MapSlice<A, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        (
+            errors,
+            res.map(|(_, alt)| ((self.1)(&stream.buffer[start..stream.save()]), alt)),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::slice_str`].
+#[must_use]
+pub struct SliceStr<'a, A, O>(pub(crate) A, pub(crate) &'a str, pub(crate) PhantomData<O>);
+
+impl<'a, A: Copy, O> Copy for SliceStr<'a, A, O> {}
+impl<'a, A: Clone, O> Clone for SliceStr<'a, A, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1, PhantomData)
+    }
+}
+
+impl<'a, I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I, Span = Range<usize>>>
+    Parser<I, &'a str> for SliceStr<'a, A, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, &'a str, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        (
+            errors,
+            res.map(|(_, alt)| (&self.1[stream.span_since(start)], alt)),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, &'a str, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, &'a str, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::spanned_map`].
+#[must_use]
+pub struct SpannedMap<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+
+impl<A: Copy, F: Copy, O> Copy for SpannedMap<A, F, O> {}
+impl<A: Clone, F: Clone, O> Clone for SpannedMap<A, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, U, F: Fn(O) -> U, E: Error<I>>
+    Parser<I, Spanned<U, E::Span>> for SpannedMap<A, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, Spanned<U, E::Span>, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        (
+            errors,
+            res.map(|(out, alt)| (Spanned::new((self.1)(out), stream.span_since(start)), alt)),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Spanned<U, E::Span>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Spanned<U, E::Span>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::intern`].
+#[must_use]
+pub struct Intern<'a, A, N, O>(
+    pub(crate) A,
+    pub(crate) &'a RefCell<N>,
+    pub(crate) PhantomData<O>,
+);
+
+impl<'a, A: Copy, N, O> Copy for Intern<'a, A, N, O> {}
+impl<'a, A: Clone, N, O> Clone for Intern<'a, A, N, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1, PhantomData)
+    }
+}
+
+impl<'a, I: Clone, O, A: Parser<I, O, Error = E>, N: Interner<O>, E: Error<I>> Parser<I, N::Handle>
+    for Intern<'a, A, N, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, N::Handle, E> {
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        (
+            errors,
+            res.map(|(out, alt)| (self.1.borrow_mut().intern(out), alt)),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, N::Handle, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, N::Handle, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::validate`].
+#[must_use]
+pub struct Validate<A, U, F>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<U>);
+
+impl<A: Copy, U, F: Copy> Copy for Validate<A, U, F> {}
+impl<A: Clone, U, F: Clone> Clone for Validate<A, U, F> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        O,
+        U,
+        A: Parser<I, O, Error = E>,
+        F: Fn(O, E::Span, &mut dyn FnMut(E)) -> U,
+        E: Error<I>,
+    > Parser<I, U> for Validate<A, O, F>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (mut errors, res) = debugger.invoke(&self.0, stream);
+
+        let pos = stream.save();
+        let span = stream.span_since(start);
+
+        let before = errors.len();
+        let res = res.map(|(out, alt)| {
+            (
+                (&self.1)(out, span, &mut |e| errors.push(Located::at(pos, e))),
+                alt,
+            )
+        });
+        stream.count_errors(errors.len() - before);
+
+        (errors, res)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::collect_map_spanned`].
+#[cfg(feature = "std")]
+#[must_use]
+pub struct CollectMapSpanned<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+
+#[cfg(feature = "std")]
+impl<A: Copy, F: Copy, O> Copy for CollectMapSpanned<A, F, O> {}
+#[cfg(feature = "std")]
+impl<A: Clone, F: Clone, O> Clone for CollectMapSpanned<A, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<
+        I: Clone,
+        O: IntoIterator<Item = Spanned<(K, V), E::Span>>,
+        K: Eq + std::hash::Hash,
+        V,
+        A: Parser<I, O, Error = E>,
+        F: Fn(&K, E::Span, E::Span) -> E,
+        E: Error<I>,
+    > Parser<I, std::collections::HashMap<K, (V, E::Span)>> for CollectMapSpanned<A, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, std::collections::HashMap<K, (V, E::Span)>, E> {
+        use std::collections::hash_map::Entry;
+
+        #[allow(deprecated)]
+        let (mut errors, res) = debugger.invoke(&self.0, stream);
+
+        let pos = stream.save();
+        let before = errors.len();
+        let res = res.map(|(out, alt)| {
+            let mut map = std::collections::HashMap::new();
+            for entry in out {
+                let ((key, value), span) = entry.into_parts();
+                match map.entry(key) {
+                    Entry::Vacant(entry) => {
+                        entry.insert((value, span));
+                    }
+                    // The first definition wins, later ones are reported
+                    Entry::Occupied(entry) => {
+                        let first = entry.get().1.clone();
+                        errors.push(Located::at(pos, (self.1)(entry.key(), first, span)));
+                    }
+                }
+            }
+            (map, alt)
+        });
+        stream.count_errors(errors.len() - before);
+
+        (errors, res)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, std::collections::HashMap<K, (V, E::Span)>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, std::collections::HashMap<K, (V, E::Span)>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::foldl`].
+#[must_use]
+pub struct Foldl<A, F, O, U>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<(O, U)>);
+
+impl<A: Copy, F: Copy, O, U> Copy for Foldl<A, F, O, U> {}
+impl<A: Clone, F: Clone, O, U> Clone for Foldl<A, F, O, U> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        O,
+        A: Parser<I, (O, U), Error = E>,
+        U: IntoIterator,
+        F: Fn(O, U::Item) -> O,
+        E: Error<I>,
+    > Parser<I, O> for Foldl<A, F, O, U>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        debugger.invoke(
+            &(&self.0).map(|(head, tail)| tail.into_iter().fold(head, &self.1)),
+            stream,
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::foldr`].
+#[must_use]
+pub struct Foldr<A, F, O, U>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<(O, U)>);
+
+impl<A: Copy, F: Copy, O, U> Copy for Foldr<A, F, O, U> {}
+impl<A: Clone, F: Clone, O, U> Clone for Foldr<A, F, O, U> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        O: IntoIterator,
+        A: Parser<I, (O, U), Error = E>,
+        U,
+        F: Fn(O::Item, U) -> U,
+        E: Error<I>,
+    > Parser<I, U> for Foldr<A, F, O, U>
+where
+    O::IntoIter: DoubleEndedIterator,
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        debugger.invoke(
+            &(&self.0).map(|(init, end)| init.into_iter().rev().fold(end, |b, a| (&self.1)(a, b))),
+            stream,
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::reduce`].
+#[must_use]
+pub struct Reduce<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+
+impl<A: Copy, F: Copy, O> Copy for Reduce<A, F, O> {}
+impl<A: Clone, F: Clone, O> Clone for Reduce<A, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        O: IntoIterator,
+        A: Parser<I, O, Error = E>,
+        F: Fn(O::Item, O::Item) -> O::Item,
+        E: Error<I>,
+    > Parser<I, O::Item> for Reduce<A, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O::Item, E> {
+        #[allow(deprecated)]
+        debugger.invoke(
+            &(&self.0).try_map(|items, span| {
+                let mut items = items.into_iter();
+                match items.next() {
+                    Some(first) => Ok(items.fold(first, &self.1)),
+                    None => Err(E::custom(span, "expected at least one item to reduce")),
+                }
+            }),
+            stream,
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, O::Item, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O::Item, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::fold`].
+#[must_use]
+pub struct Fold<A, Acc, F, O>(
+    pub(crate) A,
+    pub(crate) Acc,
+    pub(crate) F,
+    pub(crate) PhantomData<O>,
+);
+
+impl<A: Copy, Acc: Copy, F: Copy, O> Copy for Fold<A, Acc, F, O> {}
+impl<A: Clone, Acc: Clone, F: Clone, O> Clone for Fold<A, Acc, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), self.2.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        O: IntoIterator,
+        A: Parser<I, O, Error = E>,
+        Acc: Clone,
+        F: Fn(Acc, O::Item) -> Acc,
+        E: Error<I>,
+    > Parser<I, Acc> for Fold<A, Acc, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, Acc, E> {
+        #[allow(deprecated)]
+        debugger.invoke(
+            &(&self.0).map(|items| items.into_iter().fold(self.1.clone(), &self.2)),
+            stream,
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, Acc, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, Acc, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::flat_map`].
+#[must_use]
+pub struct FlatMap<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+
+impl<A: Copy, F: Copy, O> Copy for FlatMap<A, F, O> {}
+impl<A: Clone, F: Clone, O> Clone for FlatMap<A, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        O: IntoIterator,
+        A: Parser<I, O, Error = E>,
+        U,
+        Iter: IntoIterator<Item = U>,
+        F: Fn(O::Item) -> Iter,
+        E: Error<I>,
+    > Parser<I, Vec<U>> for FlatMap<A, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, Vec<U>, E> {
+        #[allow(deprecated)]
+        debugger.invoke(
+            &(&self.0).map(|items| items.into_iter().flat_map(&self.1).collect()),
+            stream,
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, Vec<U>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, Vec<U>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::foldl_with_span`].
+#[must_use]
+pub struct FoldlWithSpan<A, F, O, U>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<(O, U)>);
+
+impl<A: Copy, F: Copy, O, U> Copy for FoldlWithSpan<A, F, O, U> {}
+impl<A: Clone, F: Clone, O, U> Clone for FoldlWithSpan<A, F, O, U> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        O,
+        A: Parser<I, (O, U), Error = E>,
+        T,
+        U: IntoIterator<Item = Spanned<T, E::Span>>,
+        F: Fn(O, T, E::Span) -> O,
+        E: Error<I>,
+    > Parser<I, O> for FoldlWithSpan<A, F, O, U>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        debugger.invoke(
+            &(&self.0).map_with_span(|(head, tail), span: E::Span| {
+                tail.into_iter().fold(head, |a, b| {
+                    let (b, b_span) = b.into_parts();
+                    let span = E::Span::new(span.context(), span.start()..b_span.end());
+                    (self.1)(a, b, span)
+                })
+            }),
+            stream,
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::foldr_with_span`].
+#[must_use]
+pub struct FoldrWithSpan<A, F, O, U>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<(O, U)>);
+
+impl<A: Copy, F: Copy, O, U> Copy for FoldrWithSpan<A, F, O, U> {}
+impl<A: Clone, F: Clone, O, U> Clone for FoldrWithSpan<A, F, O, U> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        T,
+        O: IntoIterator<Item = Spanned<T, E::Span>>,
+        A: Parser<I, (O, U), Error = E>,
+        U,
+        F: Fn(T, U, E::Span) -> U,
+        E: Error<I>,
+    > Parser<I, U> for FoldrWithSpan<A, F, O, U>
+where
+    O::IntoIter: DoubleEndedIterator,
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        debugger.invoke(
+            &(&self.0).map_with_span(|(init, end), span: E::Span| {
+                init.into_iter().rev().fold(end, |b, a| {
+                    let (a, a_span) = a.into_parts();
+                    let span = E::Span::new(span.context(), a_span.start()..span.end());
+                    (self.1)(a, b, span)
+                })
+            }),
+            stream,
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::map_err`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct MapErr<A, F>(pub(crate) A, pub(crate) F);
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E) -> E, E: Error<I>> Parser<I, O>
+    for MapErr<A, F>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+        let mapper = |e: Located<I, E>| e.map(&self.1);
+        (
+            errors, //errors.into_iter().map(mapper).collect(),
+            res /*.map(|(out, alt)| (out, alt.map(mapper)))*/
+                .map_err(mapper),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::map_err_with_span`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct MapErrWithSpan<A, F>(pub(crate) A, pub(crate) F);
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E, E::Span) -> E, E: Error<I>> Parser<I, O>
+    for MapErrWithSpan<A, F>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+        let mapper = |e: Located<I, E>| {
+            let at = e.at;
+            e.map(|e| {
+                let span = stream.attempt(|stream| {
+                    stream.revert(at);
+                    (false, stream.span_since(start))
+                });
+                (self.1)(e, span)
+            })
+        };
+        (errors, res.map_err(mapper))
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::summarize_errors`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct SummarizeErrors<A, F>(pub(crate) A, pub(crate) F);
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(Vec<E>, E::Span) -> E, E: Error<I>> Parser<I, O>
+    for SummarizeErrors<A, F>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        match res {
+            Ok(out) if errors.is_empty() => (errors, Ok(out)),
+            Ok(out) => {
+                let span = stream.span_since(start);
+                let at = errors.iter().map(|e| e.at).max().unwrap_or(start);
+                let errors = errors.into_iter().map(|e| e.error).collect();
+                (vec![Located::at(at, (self.1)(errors, span))], Ok(out))
+            }
+            Err(err) => {
+                // The primary error determines the position and commitment of the summary, since it's what
+                // enclosing parsers use to choose between alternatives
+                let at = err.at;
+                let committed = err.committed;
+                let span = stream.span_between(start, at.max(start));
+                let errors = errors
+                    .into_iter()
+                    .chain(Some(err))
+                    .map(|e| e.error)
+                    .collect();
+                let mut err = Located::at(at, (self.1)(errors, span));
+                err.committed = committed;
+                (Vec::new(), Err(err))
+            }
+        }
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::try_map`].
+#[must_use]
+pub struct TryMap<A, F, O>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<O>);
+
+impl<A: Copy, F: Copy, O> Copy for TryMap<A, F, O> {}
+impl<A: Clone, F: Clone, O> Clone for TryMap<A, F, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        O,
+        A: Parser<I, O, Error = E>,
+        U,
+        F: Fn(O, E::Span) -> Result<U, E>,
+        E: Error<I>,
+    > Parser<I, U> for TryMap<A, F, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        let res = match res.map(|(out, alt)| ((&self.1)(out, stream.span_since(start)), alt)) {
+            Ok((Ok(out), alt)) => Ok((out, alt)),
+            Ok((Err(a_err), _)) => Err(Located::at(start, a_err)),
+            Err(err) => Err(err),
+        };
+
+        (errors, res)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::try_map_with_state`].
+#[must_use]
+pub struct TryMapWithState<A, F, O, St>(
+    pub(crate) A,
+    pub(crate) F,
+    pub(crate) PhantomData<(O, St)>,
+);
+
+impl<A: Copy, F: Copy, O, St> Copy for TryMapWithState<A, F, O, St> {}
+impl<A: Clone, F: Clone, O, St> Clone for TryMapWithState<A, F, O, St> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<
+        I: Clone,
+        O,
+        A: Parser<I, O, Error = E>,
+        U,
+        St: Any,
+        F: Fn(O, E::Span, &mut St) -> Result<U, E>,
+        E: Error<I>,
+    > Parser<I, U> for TryMapWithState<A, F, O, St>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        let res = match res {
+            Ok((out, alt)) => {
+                let span = stream.span_since(start);
+                match (self.1)(out, span, stream.state()) {
+                    Ok(out) => Ok((out, alt)),
+                    Err(a_err) => Err(Located::at(start, a_err)),
+                }
+            }
+            Err(err) => Err(err),
+        };
+
+        (errors, res)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::map_with_state`].
+#[must_use]
+pub struct MapWithState<A, F, O, St>(pub(crate) A, pub(crate) F, pub(crate) PhantomData<(O, St)>);
+
+impl<A: Copy, F: Copy, O, St> Copy for MapWithState<A, F, O, St> {}
+impl<A: Clone, F: Clone, O, St> Clone for MapWithState<A, F, O, St> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, U, St: Any, F: Fn(O, &mut St) -> U, E: Error<I>>
+    Parser<I, U> for MapWithState<A, F, O, St>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        (
+            errors,
+            res.map(|(out, alt)| ((self.1)(out, stream.state()), alt)),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::or_else`].
+#[must_use]
+#[derive(Copy, Clone)]
 pub struct OrElse<A, F>(pub(crate) A, pub(crate) F);
 
-impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E) -> Result<O, E>, E: Error<I>> Parser<I, O>
-    for OrElse<A, F>
-{
+impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E) -> Result<O, E>, E: Error<I>> Parser<I, O>
+    for OrElse<A, F>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let start = stream.save();
+
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+
+        let res = match res {
+            Ok(out) => Ok(out),
+            Err(err) => match (&self.1)(err.error) {
+                Err(e) => Err(Located {
+                    at: err.at,
+                    error: e,
+                    committed: err.committed,
+                    phantom: PhantomData,
+                }),
+                Ok(out) => {
+                    stream.revert(start);
+                    Ok((out, None))
+                },
+            },
+        };
+
+        (errors, res)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::labelled`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct Label<A, L>(pub(crate) A, pub(crate) L);
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, L: Into<E::Label> + Clone, E: Error<I>> Parser<I, O>
+    for Label<A, L>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let pre_state = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+        // The context of each error spans from the start of the pattern to the point at which the error occurred
+        let mut label = |e: Located<I, E>| {
+            let at = e.at.max(pre_state);
+            let span = stream.attempt(|stream| {
+                stream.revert(at);
+                (false, stream.span_since(pre_state))
+            });
+            e.map(|e| e.in_context(self.1.clone().into(), span))
+        };
+        let errors = errors.into_iter().map(&mut label).collect();
+        /* TODO: Only add the label if we committed to this pattern somewhat? */
+        let res = res.map_err(label);
+        (errors, res)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::to`].
+#[must_use]
+pub struct To<A, O, U>(pub(crate) A, pub(crate) U, pub(crate) PhantomData<O>);
+
+impl<A: Copy, U: Copy, O> Copy for To<A, O, U> {}
+impl<A: Clone, U: Clone, O> Clone for To<A, O, U> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, U: Clone, E: Error<I>> Parser<I, U> for To<A, O, U> {
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        debugger.invoke(&(&self.0).map(|_| self.1.clone()), stream)
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::rewind`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct Rewind<A>(pub(crate) A);
+
+impl<I: Clone, O, E: Error<I>, A> Parser<I, O> for Rewind<A>
+where
+    A: Parser<I, O, Error = E>,
+{
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, Self::Error>,
+    ) -> PResult<I, O, Self::Error>
+    where
+        Self: Sized,
+    {
+        let rewind_from = stream.save();
+        match {
+            #[allow(deprecated)]
+            debugger.invoke(&self.0, stream)
+        } {
+            (errors, Ok((out, alt))) => {
+                stream.revert(rewind_from);
+                (errors, Ok((out, alt)))
+            }
+            (errors, Err(err)) => (errors, Err(err)),
+        }
+    }
+
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, Self::Error>,
+    ) -> PResult<I, O, Self::Error> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, Self::Error>,
+    ) -> PResult<I, O, Self::Error> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::peek`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct Peek<A>(pub(crate) A);
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, O> for Peek<A> {
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let before = stream.save();
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+        stream.revert(before);
+        match res {
+            Ok(out) => (errors, Ok(out)),
+            // No input was consumed, so the error is located at the start of the pattern: otherwise, it might be
+            // preferred over errors from alternatives that actually consumed input
+            Err(err) => (errors, Err(Located { at: before, ..err })),
+        }
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::not_ahead`].
+#[must_use]
+pub struct NotAhead<A, O>(pub(crate) A, pub(crate) PhantomData<O>);
+
+impl<A: Copy, O> Copy for NotAhead<A, O> {}
+impl<A: Clone, O> Clone for NotAhead<A, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, ()> for NotAhead<A, O> {
     type Error = E;
 
     #[inline]
@@ -1326,49 +3783,52 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E) -> Result<O, E>, E: Error
         &self,
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, O, E> {
-        let start = stream.save();
-
+    ) -> PResult<I, (), E> {
+        let before = stream.save();
         #[allow(deprecated)]
-        let (errors, res) = debugger.invoke(&self.0, stream);
-
-        let res = match res {
-            Ok(out) => Ok(out),
-            Err(err) => match (&self.1)(err.error) {
-                Err(e) => Err(Located {
-                    at: err.at,
-                    error: e,
-                    phantom: PhantomData,
-                }),
-                Ok(out) => {
-                    stream.revert(start);
-                    Ok((out, None))
-                },
-            },
-        };
-
-        (errors, res)
+        let (_, res) = debugger.invoke(&self.0, stream);
+        stream.revert(before);
+        match res {
+            Ok(_) => {
+                let (at, span, found) = stream.next();
+                stream.revert(before);
+                (
+                    Vec::new(),
+                    Err(Located::at(
+                        at,
+                        E::expected_input_found(span, Vec::new(), found),
+                    )),
+                )
+            }
+            Err(_) => (Vec::new(), Ok(((), None))),
+        }
     }
 
     #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, (), E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
     #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, (), E> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
 }
 
-/// See [`Parser::labelled`].
+/// See [`Parser::and_is`].
 #[must_use]
-#[derive(Copy, Clone)]
-pub struct Label<A, L>(pub(crate) A, pub(crate) L);
+pub struct AndIs<A, B, U>(pub(crate) A, pub(crate) B, pub(crate) PhantomData<U>);
 
-impl<I: Clone, O, A: Parser<I, O, Error = E>, L: Into<E::Label> + Clone, E: Error<I>> Parser<I, O>
-    for Label<A, L>
+impl<A: Copy, B: Copy, U> Copy for AndIs<A, B, U> {}
+impl<A: Clone, B: Clone, U> Clone for AndIs<A, B, U> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, U, A: Parser<I, O, Error = E>, B: Parser<I, U, Error = E>, E: Error<I>>
+    Parser<I, O> for AndIs<A, B, U>
 {
     type Error = E;
 
@@ -1378,26 +3838,26 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, L: Into<E::Label> + Clone, E: Erro
         debugger: &mut D,
         stream: &mut StreamOf<I, E>,
     ) -> PResult<I, O, E> {
-        // let pre_state = stream.save();
+        let before = stream.save();
         #[allow(deprecated)]
         let (errors, res) = debugger.invoke(&self.0, stream);
-        let res = res.map_err(|e| {
-            /* TODO: Not this? */
-            /*if e.at > pre_state
-            {*/
-            // Only add the label if we committed to this pattern somewhat
-            e.map(|e| e.with_label(self.1.clone().into()))
-            /*} else {
-                e
-            }*/
-        });
-        (
-            errors
-                .into_iter()
-                .map(|e| e.map(|e| e.with_label(self.1.clone().into())))
-                .collect(),
-            res,
-        )
+        let (out, alt) = match res {
+            Ok(out) => out,
+            Err(err) => return (errors, Err(err)),
+        };
+        let after = stream.save();
+
+        // The second pattern only checks the input, so it always starts from (and leaves the stream at) the same
+        // position as the first
+        stream.revert(before);
+        #[allow(deprecated)]
+        let (_, res) = debugger.invoke(&self.1, stream);
+        stream.revert(after);
+        match res {
+            Ok(_) => (errors, Ok((out, alt))),
+            // Like `Parser::peek`, the error is located at the start of the pattern
+            Err(err) => (errors, Err(Located { at: before, ..err })),
+        }
     }
 
     #[inline]
@@ -1412,53 +3872,72 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, L: Into<E::Label> + Clone, E: Erro
     }
 }
 
-/// See [`Parser::to`].
+/// See [`Parser::try_window`].
 #[must_use]
-pub struct To<A, O, U>(pub(crate) A, pub(crate) U, pub(crate) PhantomData<O>);
-
-impl<A: Copy, U: Copy, O> Copy for To<A, O, U> {}
-impl<A: Clone, U: Clone, O> Clone for To<A, O, U> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone(), PhantomData)
-    }
-}
+#[derive(Copy, Clone)]
+pub struct TryWindow<A>(pub(crate) A, pub(crate) usize);
 
-impl<I: Clone, O, A: Parser<I, O, Error = E>, U: Clone, E: Error<I>> Parser<I, U> for To<A, O, U> {
+impl<I: Clone, O, E: Error<I>, A> Parser<I, O> for TryWindow<A>
+where
+    A: Parser<I, O, Error = E>,
+{
     type Error = E;
 
     #[inline]
     fn parse_inner<D: Debugger>(
         &self,
         debugger: &mut D,
-        stream: &mut StreamOf<I, E>,
-    ) -> PResult<I, U, E> {
+        stream: &mut StreamOf<I, Self::Error>,
+    ) -> PResult<I, O, Self::Error>
+    where
+        Self: Sized,
+    {
+        let start = stream.save();
         #[allow(deprecated)]
-        debugger.invoke(&(&self.0).map(|_| self.1.clone()), stream)
+        let (errors, res) = debugger.invoke(&self.0, stream);
+        match res {
+            // Failing beyond the window means that we're committed to this pattern
+            Err(mut err) if err.at >= start + self.1 => {
+                err.committed = true;
+                (errors, Err(err))
+            }
+            res => (errors, res),
+        }
     }
 
     #[inline]
-    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, Self::Error>,
+    ) -> PResult<I, O, Self::Error> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
+
     #[inline]
-    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, Self::Error>,
+    ) -> PResult<I, O, Self::Error> {
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
 }
 
-/// See [`Parser::rewind`].
+/// See [`Parser::cut`].
 #[must_use]
 #[derive(Copy, Clone)]
-pub struct Rewind<A>(pub(crate) A);
+pub struct Cut<A>(pub(crate) A);
 
-impl<I: Clone, O, E: Error<I>, A> Parser<I, O> for Rewind<A>
+impl<I: Clone, O, E: Error<I>, A> Parser<I, O> for Cut<A>
 where
     A: Parser<I, O, Error = E>,
 {
     type Error = E;
 
+    #[inline]
     fn parse_inner<D: Debugger>(
         &self,
         debugger: &mut D,
@@ -1467,19 +3946,15 @@ where
     where
         Self: Sized,
     {
-        let rewind_from = stream.save();
-        match {
-            #[allow(deprecated)]
-            debugger.invoke(&self.0, stream)
-        } {
-            (errors, Ok((out, alt))) => {
-                stream.revert(rewind_from);
-                (errors, Ok((out, alt)))
-            }
-            (errors, Err(err)) => (errors, Err(err)),
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+        if res.is_ok() {
+            stream.cut = true;
         }
+        (errors, res)
     }
 
+    #[inline]
     fn parse_inner_verbose(
         &self,
         d: &mut Verbose,
@@ -1489,6 +3964,7 @@ where
         self.parse_inner(d, s)
     }
 
+    #[inline]
     fn parse_inner_silent(
         &self,
         d: &mut Silent,
@@ -1499,6 +3975,86 @@ where
     }
 }
 
+/// See [`Parser::over_options`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct OverOptions<A, F>(pub(crate) A, pub(crate) F);
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, F: Fn(E) -> E2, E: Error<I>, E2> Parser<Option<I>, O>
+    for OverOptions<A, F>
+where
+    E2: Error<Option<I>, Span = E::Span>,
+{
+    type Error = E2;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<Option<I>, E2>,
+    ) -> PResult<Option<I>, O, E2> {
+        let start = stream.save();
+
+        // The inner parser sees the first hole (or the end of the input, whichever comes first) as its end of input.
+        // This is only found once the inner parser reaches it, so parsing doesn't need to scan ahead.
+        let eoi = Rc::new(RefCell::new(None));
+        let placeholder = stream.eoi.clone();
+        let mut state = stream.state.take();
+        let (inner_offset, (errors, res)) = {
+            let end = eoi.clone();
+            let mut inner = Stream::from_iter(
+                placeholder,
+                core::iter::from_fn(|| match stream.next() {
+                    (_, span, Some(Some(tok))) => Some((tok, span)),
+                    (_, span, _) => {
+                        *end.borrow_mut() = Some(span);
+                        None
+                    }
+                })
+                .fuse(),
+            );
+            inner.pending_eoi = Some(eoi);
+            inner.state = state.as_deref_mut();
+            #[allow(deprecated)]
+            let res = debugger.invoke(&self.0, &mut inner);
+            (inner.offset, res)
+        };
+        stream.state = state;
+        stream.revert(start + inner_offset);
+
+        let lift = |e: Located<I, E>| Located {
+            at: start + e.at,
+            error: (self.1)(e.error),
+            committed: e.committed,
+            phantom: PhantomData,
+        };
+        (
+            errors.into_iter().map(lift).collect(),
+            res.map(|(out, alt)| (out, alt.map(lift))).map_err(lift),
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<Option<I>, E2>,
+    ) -> PResult<Option<I>, O, E2> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<Option<I>, E2>,
+    ) -> PResult<Option<I>, O, E2> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
 /// See [`Parser::unwrapped`]
 #[must_use]
 pub struct Unwrapped<A, U, E>(
@@ -1556,6 +4112,153 @@ impl<I: Clone, O, A: Parser<I, Result<O, U>, Error = E>, U: fmt::Debug, E: Error
     }
 }
 
+/// A value that may indicate a failure instead, such as a [`Result`] or an [`Option`].
+///
+/// See [`Parser::try_unwrapped`].
+pub trait Fallible {
+    /// The type of the value, if there is one.
+    type Value;
+
+    /// Convert this into a [`Result`], with a message describing the failure if there was one.
+    fn into_result(self) -> Result<Self::Value, String>;
+}
+
+impl<T, E: fmt::Display> Fallible for Result<T, E> {
+    type Value = T;
+
+    fn into_result(self) -> Result<T, String> {
+        self.map_err(|e| e.to_string())
+    }
+}
+
+impl<T> Fallible for Option<T> {
+    type Value = T;
+
+    fn into_result(self) -> Result<T, String> {
+        self.ok_or_else(|| String::from("invalid input"))
+    }
+}
+
+/// See [`Parser::try_unwrapped`].
+#[must_use]
+pub struct TryUnwrapped<A, O>(pub(crate) A, pub(crate) PhantomData<O>);
+
+impl<A: Copy, O> Copy for TryUnwrapped<A, O> {}
+impl<A: Clone, O> Clone for TryUnwrapped<A, O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O: Fallible, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, O::Value>
+    for TryUnwrapped<A, O>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O::Value, E> {
+        #[allow(deprecated)]
+        debugger.invoke(
+            &(&self.0).try_map(|out, span| out.into_result().map_err(|msg| E::custom(span, msg))),
+            stream,
+        )
+    }
+
+    #[inline]
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, O::Value, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, E>,
+    ) -> PResult<I, O::Value, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+pub(crate) struct Memo<I, O, E> {
+    // The stream that the cache was filled by
+    session: Weak<()>,
+    // Maps the position at which the parser was invoked to its result and the position at which it finished
+    results: HashMap<usize, (PResult<I, O, E>, usize)>,
+}
+
+impl<I, O, E> Default for Memo<I, O, E> {
+    fn default() -> Self {
+        Self {
+            session: Weak::new(),
+            results: HashMap::default(),
+        }
+    }
+}
+
+/// See [`Parser::memoized`].
+#[must_use]
+pub struct Memoized<A, I, O, E>(pub(crate) A, pub(crate) Rc<RefCell<Memo<I, O, E>>>);
+
+impl<A: Clone, I, O, E> Clone for Memoized<A, I, O, E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<I: Clone, O: Clone, A: Parser<I, O, Error = E>, E: Error<I> + Clone> Parser<I, O>
+    for Memoized<A, I, O, E>
+{
+    type Error = E;
+
+    #[inline]
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let start = stream.save();
+        {
+            let mut memo = self.1.borrow_mut();
+            if !Weak::ptr_eq(&memo.session, &Rc::downgrade(&stream.session)) {
+                memo.session = Rc::downgrade(&stream.session);
+                memo.results.clear();
+            }
+            if let Some((res, end)) = memo.results.get(&start) {
+                stream.revert(*end);
+                return res.clone();
+            }
+        }
+
+        #[allow(deprecated)]
+        let res = debugger.invoke(&self.0, stream);
+        self.1
+            .borrow_mut()
+            .results
+            .insert(start, (res.clone(), stream.save()));
+        res
+    }
+
+    #[inline]
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    #[inline]
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec;
@@ -1626,6 +4329,19 @@ mod tests {
         assert!(parser.parse("-,-,").is_err());
     }
 
+    #[test]
+    fn separated_by_trailing_without_items() {
+        let parser = just::<_, _, Simple<char>>('-')
+            .separated_by(just(','))
+            .allow_trailing()
+            .then_ignore(end());
+
+        assert_eq!(parser.parse(""), Ok(vec![]));
+        assert_eq!(parser.parse("-,"), Ok(vec!['-']));
+        // A trailing separator is only permitted after an item
+        assert!(parser.parse(",").is_err());
+    }
+
     #[test]
     fn separated_by_leaves_last_separator() {
         let parser = just::<_, _, Simple<char>>('-')
@@ -1633,4 +4349,897 @@ mod tests {
             .chain(just(','));
         assert_eq!(parser.parse("-,-,-,"), Ok(vec!['-', '-', '-', ',']))
     }
+
+    #[test]
+    fn delimited_by_non_clone_delimiters() {
+        struct NoClone;
+
+        let token = NoClone;
+        let open = custom(move |stream: &mut StreamOf<char, Simple<char>>| {
+            let _ = &token;
+            #[allow(deprecated)]
+            just::<_, _, Simple<char>>('(').parse_inner(&mut Silent::new(), stream)
+        });
+        let parser = just('x').delimited_by(open, just(')'));
+
+        assert_eq!(parser.parse("(x)"), Ok('x'));
+        assert!(parser.parse("(x").is_err());
+    }
+
+    #[test]
+    fn repeated_while_span_at_least() {
+        let parser = just::<_, _, Simple<char>>('-')
+            .repeated()
+            .at_least(3)
+            .while_span(|span: &Range<usize>| span.end - span.start <= 2);
+
+        assert!(parser.parse("---").is_err());
+        assert_eq!(
+            parser.then(just('-').repeated()).parse("----"),
+            Err(vec![Simple::expected_input_found(2..3, None, Some('-'))]),
+        );
+
+        let parser = just::<_, _, Simple<char>>('-')
+            .repeated()
+            .at_least(2)
+            .while_span(|span: &Range<usize>| span.end - span.start <= 2);
+        assert_eq!(
+            parser.then(just('-').repeated()).parse("----"),
+            Ok((vec!['-'; 2], vec!['-'; 2])),
+        );
+    }
+
+    #[test]
+    fn delimited_by_or_empty() {
+        let args = just::<_, _, Simple<char>>('x')
+            .separated_by(just(','))
+            .at_least(1)
+            .delimited_by_or_empty(just('('), just(')'));
+
+        assert_eq!(args.parse("()"), Ok(None));
+        assert_eq!(args.parse("(x,x)"), Ok(Some(vec!['x', 'x'])));
+
+        let err = args.parse("(,)").unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err[0].span(), 1..2);
+        let mut expected = err[0].expected().cloned().collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(expected, vec![Some(')'), Some('x')]);
+    }
+
+    #[test]
+    fn delimited_by_or_empty_recovery() {
+        let args = just::<_, _, Simple<char>>('x')
+            .recover_with(skip_then_retry_until([')']))
+            .delimited_by_or_empty(just('('), just(')'));
+
+        let (out, errors) = args.parse_recovery("(?x)");
+        assert_eq!(out, Some(Some('x')));
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn or_discards_errors_of_rejected_branch() {
+        // `a` recovers from an error but `b` parses cleanly: `b` is chosen and `a`'s recovered error is discarded
+        let a = just::<_, _, Simple<char>>('a')
+            .recover_with(skip_then_retry_until([';']))
+            .then_ignore(just(';'));
+        let b = just('b').ignore_then(just('a')).then_ignore(just(';'));
+        let parser = a.or(b);
+
+        assert_eq!(parser.parse_recovery("ba;"), (Some('a'), Vec::new()));
+    }
+
+    #[test]
+    fn or_keeps_errors_of_chosen_branch() {
+        // `a` recovers from an error and `b` fails: `a` is chosen along with its recovered error
+        let a = just::<_, _, Simple<char>>('a')
+            .recover_with(skip_then_retry_until([';']))
+            .then_ignore(just(';'));
+        let b = just('b').ignore_then(just('a')).then_ignore(just(';'));
+
+        let (out, errors) = a.clone().or(b.clone()).parse_recovery("xa;");
+        assert_eq!(out, Some('a'));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 0..1);
+
+        // The same applies regardless of the order of the branches
+        let (out, errors) = b.or(a).parse_recovery("xa;");
+        assert_eq!(out, Some('a'));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 0..1);
+    }
+
+    #[test]
+    fn or_prefers_branch_without_recovered_errors() {
+        // Both branches succeed, but only `b` does so without recovery
+        let a = just::<_, _, Simple<char>>('a')
+            .recover_with(skip_then_retry_until([';']))
+            .then_ignore(just(';'));
+        let b = just('x').ignore_then(just('a')).then_ignore(just(';'));
+
+        assert_eq!(a.or(b).parse_recovery("xa;"), (Some('a'), Vec::new()));
+    }
+
+    #[test]
+    fn try_window_commits() {
+        let long = just::<_, _, Simple<char>>("abc")
+            .then(just('d'))
+            .try_window(2);
+        let short = just::<_, _, Simple<char>>("abc").to(('x', 'x'));
+
+        // Failing within the window backtracks as usual
+        let parser = just::<_, _, Simple<char>>('a')
+            .then(just('x'))
+            .try_window(2);
+        assert_eq!(parser.or(short).parse("abc"), Ok(('x', 'x')));
+
+        // Failing beyond the window prevents alternatives from being attempted
+        let parser = long.map(|(_, d)| (d, d)).or(short);
+        assert_eq!(
+            parser.parse("abce"),
+            Err(vec![Simple::expected_input_found(
+                3..4,
+                Some(Some('d')),
+                Some('e')
+            )]),
+        );
+        assert_eq!(
+            choice((long.map(|(_, d)| (d, d)), short))
+                .parse("abce")
+                .unwrap_err()[0]
+                .span(),
+            3..4,
+        );
+        assert!(long
+            .or_not()
+            .then_ignore(just("abce"))
+            .parse("abce")
+            .is_err());
+        assert_eq!(
+            long.repeated().parse("abcdabce").unwrap_err()[0].span(),
+            7..8,
+        );
+
+        // A chain of alternatives is a single choice, just like `choice`
+        let chained = long
+            .map(|(_, d)| (d, d))
+            .or(just('z').to(('z', 'z')))
+            .or(short);
+        assert_eq!(chained.parse("abce").unwrap_err()[0].span(), 3..4);
+
+        // Alternatives beyond the nearest enclosing choice are still attempted
+        let nested = long
+            .map(|(_, d)| (d, d))
+            .or(just('z').to(('z', 'z')))
+            .map(|out| out)
+            .or(short);
+        assert_eq!(nested.parse("abce"), Ok(('x', 'x')));
+        assert_eq!(
+            long.map(|(_, d)| (d, d))
+                .or_not()
+                .or(short.map(Some))
+                .parse("abce"),
+            Ok(Some(('x', 'x'))),
+        );
+    }
+
+    #[test]
+    fn over_options_holes() {
+        let word = text::ident::<_, Simple<char>>().over_options(|e| e.map(Some));
+        let words = word
+            .recover_with(skip_then_retry_until([]))
+            .separated_by(just(Some(' ')))
+            .then_ignore(end());
+
+        let tokens = |s: &str| {
+            s.chars()
+                .map(|c| if c == '?' { None } else { Some(c) })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            words.parse(tokens("ab cd")),
+            Ok(vec!["ab".to_string(), "cd".to_string()])
+        );
+
+        let (out, errors) = words.parse_recovery(tokens("ab ?cd ef"));
+        assert_eq!(
+            out,
+            Some(vec!["ab".to_string(), "cd".to_string(), "ef".to_string()])
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 3..4);
+        assert_eq!(errors[0].found(), None);
+    }
+
+    #[test]
+    fn over_options_end_of_input() {
+        let ab = just::<_, _, Simple<char>>('a')
+            .repeated()
+            .then(just('b'))
+            .over_options(|e| e.map(Some));
+
+        // Without any holes, the end of the inner input is the end of the outer input
+        let errors = ab.parse(vec![Some('a'), Some('a')]).unwrap_err();
+        assert_eq!(errors[0].span(), 2..2);
+        assert_eq!(errors[0].found(), None);
+
+        let errors = ab
+            .parse(vec![Some('a'), Some('a'), None, Some('b')])
+            .unwrap_err();
+        assert_eq!(errors[0].span(), 2..3);
+        assert_eq!(errors[0].found(), None);
+
+        // Nested lifting finds the end of each inner input separately
+        let nested = ab.over_options(|e| e.map(Some));
+        let errors = nested
+            .parse(vec![Some(Some('a')), Some(None), None])
+            .unwrap_err();
+        assert_eq!(errors[0].span(), 1..2);
+    }
+
+    #[test]
+    fn foldr_deep() {
+        let int = text::int::<_, Simple<char>>(10)
+            .from_str::<u32>()
+            .unwrapped();
+        let expr = int
+            .then_ignore(just('-'))
+            .repeated()
+            .then(int)
+            .foldr(|a, b| a - b)
+            .then_ignore(end());
+
+        let input = core::iter::repeat("9-")
+            .take(10_000)
+            .chain(core::iter::once("1"))
+            .collect::<String>();
+
+        // Right-associative: 9 - (9 - (... - (9 - 1)))
+        assert_eq!(expr.parse(input.as_str()), Ok(1));
+    }
+
+    #[test]
+    fn map_deferred_speculative() {
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let count = |x: char| {
+            calls.set(calls.get() + 1);
+            x
+        };
+        let a = just::<_, _, Simple<char>>('a').map_deferred(&count);
+        let parser = a
+            .then_ignore(just('!'))
+            .map(|a| a.force())
+            .or(a.map(|a| a.force()));
+
+        assert_eq!(parser.parse("a"), Ok('a'));
+        assert_eq!(calls.get(), 1);
+        assert_eq!(parser.parse("a!"), Ok('a'));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn collect_into_string_truncates_on_failure() {
+        let buffer = RefCell::new(String::from("> "));
+        let digits = filter::<_, _, Simple<char>>(char::is_ascii_digit)
+            .repeated()
+            .at_least(2)
+            .collect_into_string(&buffer);
+
+        assert_eq!(digits.parse("12"), Ok(2..4));
+        assert!(digits.parse("3").is_err());
+        assert_eq!(buffer.borrow().as_str(), "> 12");
+    }
+
+    #[test]
+    fn or_warn_ambiguous() {
+        let parser = just::<_, _, Simple<char>>("ab").or_warn_ambiguous(just("a"));
+
+        let mut debugger = Verbose::new();
+        let mut stream = Stream::from("ab");
+        #[allow(deprecated)]
+        let (_, res) = parser.parse_inner_verbose(&mut debugger, &mut stream);
+        assert_eq!(res.ok().map(|(out, _)| out), Some("ab"));
+        #[cfg(debug_assertions)]
+        assert_eq!(debugger.warnings().len(), 1);
+
+        let mut debugger = Verbose::new();
+        let mut stream = Stream::from("b");
+        #[allow(deprecated)]
+        let _ = just::<_, _, Simple<char>>('a')
+            .or_warn_ambiguous(just('b'))
+            .parse_inner_verbose(&mut debugger, &mut stream);
+        assert!(debugger.warnings().is_empty());
+    }
+
+    #[test]
+    fn summarize_errors_on_failure() {
+        let parser = just::<_, _, Simple<char>>('a')
+            .then(just('b'))
+            .then(just('c'))
+            .summarize_errors(|errors, span| {
+                Simple::custom(span, alloc::format!("{} errors", errors.len()))
+            });
+
+        assert_eq!(parser.parse("abc").ok(), Some((('a', 'b'), 'c')));
+
+        let errors = parser.parse("abx").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 0..2);
+        assert_eq!(
+            errors[0].reason(),
+            &error::SimpleReason::Custom("1 errors".into())
+        );
+    }
+
+    #[test]
+    fn then_with_span_column() {
+        // A `>` marker, after which the continuation line must put its own `>` in the same column
+        let quote = just::<_, _, Simple<char>>(' ')
+            .repeated()
+            .ignore_then(just('>'))
+            .then_with_span(|_, span: Range<usize>| {
+                just('\n')
+                    .ignore_then(just(' ').repeated().exactly(span.end - 1))
+                    .ignore_then(just('>'))
+            });
+
+        assert_eq!(quote.parse("  >\n  >"), Ok('>'));
+        assert_eq!(quote.parse(">\n>"), Ok('>'));
+
+        let errors = quote.parse("  >\n   >").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 6..7);
+    }
+
+    #[test]
+    fn try_map_error_at_start() {
+        let parser = text::int::<_, Simple<char>>(10)
+            .try_map(|_, span| Err(Simple::custom(span, "too large")))
+            .or(just('1').ignore_then(just('z')).map(String::from));
+
+        // The alternative made more progress than the start of the integer, so its error takes priority
+        let errors = parser.parse("12").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 1..2);
+
+        let errors = text::int::<_, Simple<char>>(10)
+            .try_map(|_, span| Err::<(), _>(Simple::custom(span, "too large")))
+            .parse("123")
+            .unwrap_err();
+        assert_eq!(errors[0].span(), 0..3);
+    }
+
+    #[test]
+    fn repeated_bounds() {
+        let digit = filter::<_, _, Simple<char>>(char::is_ascii_digit);
+
+        let none = digit.repeated().exactly(0).then(digit.repeated());
+        assert_eq!(none.parse("12"), Ok((vec![], vec!['1', '2'])));
+
+        let some = digit.repeated().range(2..=4).then_ignore(end());
+        assert_eq!(some.parse("12"), Ok(vec!['1', '2']));
+        assert_eq!(some.parse("1234"), Ok(vec!['1', '2', '3', '4']));
+        // The fifth digit is left for `end` to reject
+        assert_eq!(some.parse("12345").unwrap_err()[0].span(), 4..5);
+        // The error is located where the second digit was expected
+        assert_eq!(some.parse("1x").unwrap_err()[0].span(), 1..2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Repeated cannot parse at least 3 and fewer than 3")]
+    fn repeated_empty_range() {
+        let _ = just::<_, _, Simple<char>>('a').repeated().range(3..3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Repeated cannot parse at least 3 and at most 2")]
+    fn repeated_at_least_above_at_most() {
+        let _ = just::<_, _, Simple<char>>('a')
+            .repeated()
+            .at_least(3)
+            .at_most(2)
+            .parse("aa");
+    }
+
+    #[test]
+    fn not_ahead_consumes_nothing() {
+        let parser = just::<_, _, Simple<char>>('\\')
+            .not_ahead()
+            .ignore_then(any());
+
+        assert_eq!(parser.parse("a"), Ok('a'));
+        assert_eq!(parser.parse("\\").unwrap_err()[0].span(), 0..1);
+
+        // The lookahead pattern may be longer than the input eventually consumed
+        let parser = just::<_, _, Simple<char>>("ab")
+            .not_ahead()
+            .ignore_then(any().repeated().collect::<String>());
+        assert_eq!(parser.parse("ac"), Ok("ac".to_string()));
+        assert!(parser.parse("ab").is_err());
+    }
+
+    #[test]
+    fn peek_error_does_not_win() {
+        // A block is only attempted if it starts with `{`, but the peek looks further ahead than that
+        let block = just::<_, _, Simple<char>>("{}")
+            .peek()
+            .ignore_then(just('{').then(just('}')).to("block"));
+        let literal = just('{').ignore_then(just('x')).to("literal");
+        let parser = block.or(literal);
+
+        assert_eq!(parser.parse("{}"), Ok("block"));
+        assert_eq!(parser.parse("{x"), Ok("literal"));
+
+        // The literal consumed input, so its error takes priority over the failed peek
+        let errors = parser.parse("{y").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 1..2);
+        assert_eq!(errors[0].expected().collect::<Vec<_>>(), vec![&Some('x')]);
+    }
+
+    #[test]
+    fn rewind_reparses_keyword() {
+        let decl = text::keyword::<_, _, Simple<char>>("let")
+            .ignore_then(text::ident().padded())
+            .then_ignore(just('='))
+            .then(text::int(10).padded());
+        // Check for the `let` prefix before committing to the full declaration, which parses it again
+        let parser = just("let").rewind().ignore_then(decl);
+
+        assert_eq!(
+            parser.parse("let x = 5"),
+            Ok(("x".to_string(), "5".to_string()))
+        );
+        assert_eq!(parser.parse("x = 5").unwrap_err()[0].span(), 0..1);
+    }
+
+    #[test]
+    fn validate_errors_with_syntax_errors() {
+        let field = text::ident::<_, Simple<char>>()
+            .recover_with(nested_delimiters('(', ')', [], |_| String::new()))
+            .padded()
+            .map_with_span(|name, span| (name, span));
+        let fields = field
+            .separated_by(just(','))
+            .delimited_by(just('{'), just('}'))
+            .validate(|fields, _, emit| {
+                for (i, (name, span)) in fields.iter().enumerate() {
+                    if fields[..i].iter().any(|(other, _)| other == name) {
+                        emit(Simple::custom(span.clone(), "duplicate field"))
+                    }
+                }
+                fields.len()
+            });
+
+        let (out, errors) = fields.parse_recovery("{a, (x), a}");
+        assert_eq!(out, Some(3));
+        assert_eq!(errors.len(), 2);
+        // The syntax error is reported first, followed by the semantic error
+        assert_eq!(errors[0].span(), 4..5);
+        assert_eq!(errors[1].span(), 8..10);
+    }
+
+    #[test]
+    fn choice_operators() {
+        let op = choice::<_, Simple<char>>((just('+'), just('-'), just('*'), just('/')));
+
+        for c in ['+', '-', '*', '/'] {
+            assert_eq!(op.parse([c]), Ok(c));
+        }
+
+        // Like `Or`, the errors of every branch are merged
+        let errors = op.parse("x").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        let mut expected = errors[0].expected().cloned().collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(expected, vec![Some('*'), Some('+'), Some('-'), Some('/')]);
+    }
+
+    #[test]
+    fn select_error_span() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Token {
+            Num(u64),
+            Ident(&'static str),
+            Comma,
+        }
+
+        let atom = select! {
+            Token::Num(x) => x.to_string(),
+            Token::Ident(s) => s.to_string(),
+        };
+        let parser = atom.then_ignore(end::<error::Cheap<Token>>());
+
+        let tokens = vec![(Token::Comma, 4..5)];
+        let errors = parser
+            .parse(Stream::from_iter(5..5, tokens.into_iter()))
+            .unwrap_err();
+        assert_eq!(errors[0].span(), 4..5);
+
+        let tokens = vec![(Token::Ident("x"), 0..1)];
+        assert_eq!(
+            parser.parse(Stream::from_iter(1..1, tokens.into_iter())),
+            Ok("x".to_string())
+        );
+        let tokens = vec![(Token::Num(7), 0..1)];
+        assert_eq!(
+            parser.parse(Stream::from_iter(1..1, tokens.into_iter())),
+            Ok("7".to_string())
+        );
+    }
+
+    #[test]
+    fn take_until_tries_terminator_first() {
+        let comment = take_until(just::<_, _, Simple<char>>('*').then(just('/')));
+
+        assert_eq!(comment.parse("*/"), Ok((vec![], ('*', '/'))));
+        assert_eq!(
+            comment.parse("a*b*/"),
+            Ok((vec!['a', '*', 'b'], ('*', '/')))
+        );
+
+        // Running out of input is an error at the end of input
+        let errors = comment.parse("abc").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 3..3);
+        assert_eq!(errors[0].found(), None);
+    }
+
+    #[test]
+    fn rich_merge_keeps_richer_context() {
+        use crate::error::{Rich, SimpleReason};
+
+        let unexpected = Rich::<char>::expected_input_found(1..2, Some(Some('a')), Some('x'))
+            .in_context("inner", 0..2)
+            .in_context("outer", 0..2);
+        let custom = Rich::<char>::custom(1..2, "bad").with_related_span(0..1);
+
+        let merged = unexpected.clone().merge(custom.clone());
+        assert_eq!(merged.reason(), &SimpleReason::Custom("bad".to_string()));
+        assert_eq!(merged.contexts().count(), 2);
+        assert_eq!(merged.related_spans(), &[0..1]);
+
+        let merged = custom.merge(unexpected);
+        assert_eq!(merged.reason(), &SimpleReason::Custom("bad".to_string()));
+        assert_eq!(merged.expected().collect::<Vec<_>>(), vec![&Some('a')]);
+        assert_eq!(
+            merged
+                .contexts()
+                .map(|(label, _)| label)
+                .collect::<Vec<_>>(),
+            vec!["inner", "outer"],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "not of the type expected by a stateful parser, `usize`")]
+    fn map_with_state_wrong_type() {
+        let digit = filter::<_, _, Simple<char>>(char::is_ascii_digit).map_with_state(
+            |c, count: &mut usize| {
+                *count += 1;
+                c
+            },
+        );
+
+        let mut names = Vec::<String>::new();
+        let _ = digit.parse_recovery_with_state("1", &mut names);
+    }
+
+    #[test]
+    fn map_with_state_survives_recovery() {
+        let stmt = text::ident::<_, Simple<char>>()
+            .map_with_state(|name: String, names: &mut Vec<String>| names.push(name))
+            .then_ignore(just(';'))
+            .padded()
+            .recover_with(skip_until([';'], |_| ()).consume_end());
+
+        let mut names = Vec::<String>::new();
+        let (out, errors) = stmt
+            .repeated()
+            .then_ignore(end())
+            .parse_recovery_with_state("a; b c; d;", &mut names);
+        assert!(out.is_some());
+        assert_eq!(errors.len(), 1);
+        // `b` was interned even though its statement failed to parse
+        assert_eq!(names, ["a", "b", "d"]);
+    }
+
+    #[test]
+    fn memoized_parses_once_per_position() {
+        use core::cell::Cell;
+
+        let calls = Rc::new(Cell::new(0));
+        let word = {
+            let calls = calls.clone();
+            filter::<_, _, Simple<char>>(char::is_ascii_alphabetic)
+                .repeated()
+                .at_least(1)
+                .collect::<String>()
+                .map(move |s| {
+                    calls.set(calls.get() + 1);
+                    s
+                })
+        };
+        let alts = |word: BoxedParser<'static, char, String, Simple<char>>| {
+            word.clone()
+                .then_ignore(just('!'))
+                .or(word.clone().then_ignore(just('?')))
+                .or(word.then_ignore(just('.')))
+        };
+
+        let plain = alts(word.clone().boxed());
+        assert_eq!(plain.parse("hello."), Ok("hello".to_string()));
+        assert_eq!(calls.get(), 3);
+
+        calls.set(0);
+        let memoized = alts(word.memoized().boxed());
+        assert_eq!(memoized.parse("hello."), Ok("hello".to_string()));
+        assert_eq!(calls.get(), 1);
+
+        // The cache does not persist between parses
+        assert_eq!(memoized.parse("world?"), Ok("world".to_string()));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn trace_is_transparent() {
+        let plain = text::ident::<_, Simple<char>>()
+            .then_ignore(just(';'))
+            .or(text::int(10));
+        let traced = text::ident::<_, Simple<char>>()
+            .trace_output("ident")
+            .then_ignore(just(';').trace("semicolon"))
+            .or(text::int(10).trace("int"))
+            .trace("stmt");
+
+        for src in ["abc;", "123", "abc", ";"] {
+            assert_eq!(traced.parse(src), plain.parse(src));
+        }
+    }
+
+    #[test]
+    fn try_from_str_recovers() {
+        let float = filter::<_, _, Simple<char>>(|c: &char| c.is_ascii_alphanumeric() || *c == '.')
+            .repeated()
+            .at_least(1)
+            .collect::<String>()
+            .try_from_str::<f64>()
+            .then_ignore(just(','))
+            .recover_with(skip_until([','], |_| 0.0).consume_end());
+
+        let (floats, errors) = float
+            .repeated()
+            .then_ignore(end())
+            .parse_recovery("1.5,3.x,2.5,");
+        assert_eq!(floats, Some(vec![1.5, 0.0, 2.5]));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 4..7);
+    }
+
+    #[test]
+    fn simple_display_is_sorted_and_deduplicated() {
+        let atom = just::<_, _, Simple<char>>('(')
+            .or(just('0'))
+            .or(just('('))
+            .or(just(')'))
+            .ignored()
+            .or(end());
+
+        let errors = atom.parse("x").unwrap_err();
+        assert_eq!(
+            errors[0].to_string(),
+            "found \"x\" but expected one of \"(\", \")\", \"0\", end of input",
+        );
+        assert_eq!(
+            errors[0].expected_sorted(),
+            vec![&Some('('), &Some(')'), &Some('0'), &None],
+        );
+    }
+
+    #[test]
+    fn expected_ranges_are_displayed_and_merged() {
+        let ident_start = one_of_range::<_, _, Simple<char>>('a'..='z')
+            .or(one_of_range('A'..='Z'))
+            .or(just('_'));
+
+        let err = &ident_start.parse("1").unwrap_err()[0];
+        assert_eq!(
+            err.to_string(),
+            "found \"1\" but expected one of \"_\", \"a\"..=\"z\", \"A\"..=\"Z\"",
+        );
+
+        let digit = one_of_range::<_, _, error::Rich<char>>('0'..).or(end().to('!'));
+        let err = &digit.parse(" ").unwrap_err()[0];
+        assert_eq!(
+            err.to_string(),
+            "found \" \" but expected one of end of input, \"0\"..",
+        );
+        assert_eq!(
+            err.expected_ranges(),
+            &[(Bound::Included('0'), Bound::Unbounded)]
+        );
+    }
+
+    #[cfg(feature = "report")]
+    #[test]
+    fn report_multiline_and_end_of_input() {
+        let src = "let x = (1 +\n 2;";
+
+        let err = Simple::custom(8..16, "unclosed parenthesis");
+        assert_eq!(
+            crate::error::report(src, &err),
+            "error: unclosed parenthesis\n --> 1:9\n  |\n1 | let x = (1 +\n  |         ^^^^...\n",
+        );
+
+        let err = Simple::expected_input_found(16..16, Some(Some(')')), None);
+        assert_eq!(
+            crate::error::report(src, &err),
+            "error: found end of input but expected \")\"\n --> 2:4\n  |\n2 |  2;\n  |    ^\n",
+        );
+    }
+
+    #[test]
+    fn label_survives_consumed_input_and_merging() {
+        let ident = text::ident::<_, Simple<char>>().padded();
+        let body = ident
+            .repeated()
+            .delimited_by(just('{'), just('}'))
+            .labelled("function body");
+        let func = text::keyword("fn")
+            .padded()
+            .ignore_then(ident)
+            .then(body)
+            .labelled("function");
+        let stmt = func.or(text::keyword("let")
+            .padded()
+            .ignore_then(ident)
+            .ignored()
+            .to((String::new(), Vec::new())));
+
+        // The function consumed input before failing, but the innermost label is still reported
+        let errors = stmt.parse("fn foo { a 1 }").unwrap_err();
+        assert_eq!(errors[0].span(), 11..12);
+        assert_eq!(errors[0].label(), Some("function body"));
+
+        // Rich errors keep every enclosing label
+        let body = text::ident::<_, crate::error::Rich<char>>()
+            .padded()
+            .repeated()
+            .delimited_by(just('{'), just('}'))
+            .labelled("function body");
+        let func = text::keyword("fn")
+            .padded()
+            .ignore_then(text::ident().padded())
+            .then(body)
+            .labelled("function");
+        let errors = func.parse("fn foo { a 1 }").unwrap_err();
+        assert_eq!(
+            errors[0]
+                .contexts()
+                .map(|(label, _)| label)
+                .collect::<Vec<_>>(),
+            vec!["function body", "function"],
+        );
+
+        // An unlabelled alternative failing at the same position does not discard the label
+        let ab = just::<_, _, Simple<char>>('a')
+            .then(just('b'))
+            .or(just('a').then(just('c')).labelled("ac"));
+        assert_eq!(ab.parse("ax").unwrap_err()[0].label(), Some("ac"));
+    }
+
+    #[test]
+    fn with_separators_drops_unused_separators() {
+        let list = text::ident::<_, Simple<char>>()
+            .separated_by(just(',').to(1).or(just(';').to(2)))
+            .allow_leading()
+            .with_separators();
+
+        // The leading separator has no preceding item, and the final separator is not followed by an item
+        assert_eq!(
+            list.then_ignore(just(';')).parse(",a;b;"),
+            Ok(vec![("a".to_string(), Some(2)), ("b".to_string(), None)]),
+        );
+    }
+
+    #[test]
+    fn cut_commits_until_the_enclosing_alternative() {
+        let ab = just::<_, _, Simple<char>>('a')
+            .cut()
+            .then(just('b'))
+            .ignored();
+
+        let committed = ab.or(just("ac").ignored());
+        assert_eq!(committed.parse("ab"), Ok(()));
+        assert_eq!(committed.parse("ac").unwrap_err()[0].span(), 1..2);
+
+        // The cut doesn't outlive the alternative that contains it
+        let scoped = ab
+            .or(just('x').ignored())
+            .then(just('!'))
+            .ignored()
+            .or(just("ab?").ignored());
+        assert_eq!(scoped.parse("ab?"), Ok(()));
+
+        // A chain of alternatives is a single choice, so rewriting it with `choice` doesn't change what parses
+        let ac = just("ac").ignored();
+        let x = just('x').ignored();
+        assert_eq!(
+            ab.or(x).or(ac).parse("ac").unwrap_err()[0].span(),
+            choice((ab, x, ac)).parse("ac").unwrap_err()[0].span(),
+        );
+        assert_eq!(x.or(ab).or(ac).parse("ac").unwrap_err()[0].span(), 1..2);
+        assert_eq!(
+            choice((ab,)).or(ac).parse("ac").unwrap_err()[0].span(),
+            1..2
+        );
+        // Other points at which chumsky could backtrack end the commitment
+        assert_eq!(ab.or_not().ignored().or(ac).parse("ac"), Ok(()));
+        assert_eq!(ab.or(x).map(|out| out).or(ac).parse("ac"), Ok(()));
+        assert_eq!(
+            ab.or(just('x').ignored()).parse("ac").unwrap_err()[0].span(),
+            1..2
+        );
+
+        let items = ab.repeated().then_ignore(just('.'));
+        assert_eq!(items.parse("abab."), Ok(vec![(), ()]));
+        assert_eq!(items.parse("abac.").unwrap_err()[0].span(), 3..4);
+
+        // Committed failures can still be recovered from
+        let recovered = committed.recover_with(skip_until([';'], |_| ()));
+        let (out, errors) = recovered.then_ignore(just(';')).parse_recovery("ac;");
+        assert_eq!(out, Some(()));
+        assert_eq!(errors[0].span(), 1..2);
+    }
+
+    #[test]
+    fn flat_map_expands_outputs() {
+        let tok = filter::<_, _, Simple<char>>(|c: &char| !c.is_whitespace());
+        let expanded = tok.repeated().flat_map(|c| match c {
+            '*' => vec!['*', '*'],
+            '_' => vec![],
+            c => vec![c],
+        });
+
+        assert_eq!(expanded.parse("a*_b"), Ok(vec!['a', '*', '*', 'b']));
+        assert_eq!(expanded.parse("*"), Ok(vec!['*', '*']));
+    }
+
+    #[test]
+    fn and_is_only_consumes_the_first_pattern() {
+        let digits = filter::<_, _, Simple<char>>(char::is_ascii_digit).repeated();
+        // Digits, but only if the input starts with the given prefix
+        let prefixed = digits.and_is(just("12")).then(any().repeated());
+
+        assert_eq!(prefixed.parse("123x"), Ok((vec!['1', '2', '3'], vec!['x'])));
+        // The lookahead may consume more than the first pattern, but only the first pattern's input is consumed
+        assert_eq!(
+            digits.and_is(just("1a")).then(any()).parse("1a"),
+            Ok((vec!['1'], 'a'))
+        );
+        assert_eq!(prefixed.parse("213x").unwrap_err()[0].span(), 0..1);
+    }
+
+    #[test]
+    fn group_outputs_flat_tuple() {
+        let digit = filter::<_, _, Simple<char>>(char::is_ascii_digit);
+        let date = group((digit, just('-'), digit, just('-'), digit));
+
+        assert_eq!(date.parse("1-2-3"), Ok(('1', '-', '2', '-', '3')));
+        assert_eq!(date.parse("1-2_3").unwrap_err()[0].span(), 3..4);
+    }
+
+    #[test]
+    fn collect_deque_huge_bound() {
+        let parser = just::<_, _, Simple<char>>('a')
+            .repeated()
+            .at_most(usize::MAX / 2)
+            .collect_deque();
+
+        assert_eq!(parser.parse("aa").unwrap(), ['a', 'a']);
+    }
 }
@@ -34,6 +34,9 @@ impl ParserInfo {
 pub enum ParseEvent {
     /// Debugging information was emitted.
     Info(String),
+    /// A potential problem with the parser was detected, such as an ambiguity found by
+    /// [`Parser::or_warn_ambiguous`].
+    Warning(String),
 }
 
 /// A trait implemented by parser debuggers.
@@ -78,6 +81,7 @@ impl Verbose {
             }
             match event {
                 Ok(ParseEvent::Info(s)) => println!("{}", s),
+                Ok(ParseEvent::Warning(s)) => println!("Warning: {}", s),
                 Err((info, scope)) => {
                     println!(
                         "Entered {} at line {} in {}",
@@ -94,6 +98,18 @@ impl Verbose {
     pub(crate) fn print(&self) {
         self.print_inner(0)
     }
+
+    #[cfg(test)]
+    pub(crate) fn warnings(&self) -> Vec<&str> {
+        self.events
+            .iter()
+            .flat_map(|event| match event {
+                Ok(ParseEvent::Warning(s)) => vec![s.as_str()],
+                Ok(ParseEvent::Info(_)) => Vec::new(),
+                Err((_, scope)) => scope.warnings(),
+            })
+            .collect()
+    }
 }
 
 impl Debugger for Verbose {
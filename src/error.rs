@@ -8,7 +8,7 @@
 
 use super::*;
 use alloc::{format, string::ToString};
-use core::hash::Hash;
+use core::{hash::Hash, ops::Bound};
 
 #[cfg(not(feature = "std"))]
 use hashbrown::HashSet;
@@ -98,6 +98,21 @@ pub trait Error<I>: Sized {
         found: Option<I>,
     ) -> Self;
 
+    /// Create a new error describing an input that was expected to fall within a range, such as by [`one_of_range`].
+    ///
+    /// `found` having the value `None` indicates that the end of input was reached, but was not expected.
+    ///
+    /// The default implementation of this function uses [`Error::expected_input_found`] with no expected inputs, since
+    /// listing every input within the range would be impractical.
+    fn expected_range_found(
+        span: Self::Span,
+        range: (Bound<I>, Bound<I>),
+        found: Option<I>,
+    ) -> Self {
+        #![allow(unused_variables)]
+        Self::expected_input_found(span, None, found)
+    }
+
     /// Create a new error describing a delimiter that was not correctly closed.
     ///
     /// Provided to this function is the span of the unclosed delimiter, the delimiter itself, the span of the input
@@ -117,6 +132,17 @@ pub trait Error<I>: Sized {
         Self::expected_input_found(span, Some(Some(expected)), found)
     }
 
+    /// Create a new error with a custom message, such as one describing why an otherwise well-formed input was invalid.
+    ///
+    /// This is used by combinators that produce errors of their own, such as [`Parser::try_from_str`].
+    ///
+    /// The default implementation of this function discards the message and uses [`Error::expected_input_found`]
+    /// with no expected or found inputs.
+    fn custom<M: ToString>(span: Self::Span, msg: M) -> Self {
+        #![allow(unused_variables)]
+        Self::expected_input_found(span, None, None)
+    }
+
     /// Indicate that the error occurred while parsing a particular syntactic structure.
     ///
     /// How the error handles this information is up to it. It can append it to a list of structures to get a sort of
@@ -124,6 +150,53 @@ pub trait Error<I>: Sized {
     /// effect when the error already has a label.
     fn with_label(self, label: Self::Label) -> Self;
 
+    /// Indicate that the error occurred while parsing a particular syntactic structure, which spans from its start to
+    /// the point at which the error occurred.
+    ///
+    /// This is used by [`Parser::labelled`], and is useful for error types that keep a stack of contexts (such as
+    /// [`Rich`]) in order to produce notes like "while parsing this function".
+    ///
+    /// The default implementation of this function discards the span and calls [`Error::with_label`].
+    fn in_context(self, label: Self::Label, span: Self::Span) -> Self {
+        #![allow(unused_variables)]
+        self.with_label(label)
+    }
+
+    /// Indicate that the error occurred immediately after a particular syntactic structure was successfully parsed, and
+    /// so the error's expected inputs are precisely those that may follow it.
+    ///
+    /// This is used by [`Parser::then_after`] and is useful for tooling such as code completion, which needs to know
+    /// not just *what* may come next but *after what* (for example, "after `fn name`, `(` is expected").
+    ///
+    /// Like [`Error::with_label`], this method should have no effect when the error already has such a label, so that
+    /// the innermost structure is kept. The default implementation of this function discards the label.
+    fn with_after(self, label: Self::Label) -> Self {
+        #![allow(unused_variables)]
+        self
+    }
+
+    /// Attach a span that is related to the error, but is not the location at which it occurred.
+    ///
+    /// This is used by recovery strategies such as [`skip_then_retry_until`] to record the region of input that was
+    /// skipped during recovery (see
+    /// [`SkipThenRetryUntil::record_skipped`](crate::recovery::SkipThenRetryUntil::record_skipped)), allowing tooling
+    /// to highlight it.
+    ///
+    /// The default implementation of this function discards the span.
+    fn with_related_span(self, span: Self::Span) -> Self {
+        #![allow(unused_variables)]
+        self
+    }
+
+    /// Attach several related spans to the error, such as the pieces of a [`MultiSpan`](crate::span::MultiSpan).
+    ///
+    /// The default implementation of this function calls [`Error::with_related_span`] for each span.
+    fn with_related_spans<Iter: IntoIterator<Item = Self::Span>>(self, spans: Iter) -> Self {
+        spans
+            .into_iter()
+            .fold(self, |error, span| error.with_related_span(span))
+    }
+
     /// Merge two errors that point to the same input together, combining their information.
     fn merge(self, other: Self) -> Self;
 }
@@ -191,6 +264,15 @@ pub struct Simple<I: Hash + Eq, S = Range<usize>> {
     expected: HashSet<Option<I>, RandomState>,
     found: Option<I>,
     label: Option<&'static str>,
+    extra: Option<Box<SimpleExtra<I, S>>>,
+}
+
+// Information that few errors carry, kept behind a box so that it doesn't bloat every error (and every parser result)
+#[derive(Clone, Debug)]
+struct SimpleExtra<I, S> {
+    expected_ranges: Vec<(Bound<I>, Bound<I>)>,
+    after: Option<&'static str>,
+    related: Vec<S>,
 }
 
 impl<I: Hash + Eq, S: Clone> Simple<I, S> {
@@ -202,6 +284,7 @@ impl<I: Hash + Eq, S: Clone> Simple<I, S> {
             expected: HashSet::default(),
             found: None,
             label: None,
+            extra: None,
         }
     }
 
@@ -211,10 +294,48 @@ impl<I: Hash + Eq, S: Clone> Simple<I, S> {
     }
 
     /// Returns an iterator over possible expected patterns.
+    ///
+    /// Each pattern appears only once, but the order is unspecified. Use [`Simple::expected_sorted`] to get the
+    /// patterns in a deterministic order.
     pub fn expected(&self) -> impl ExactSizeIterator<Item = &Option<I>> + '_ {
         self.expected.iter()
     }
 
+    /// Returns the possible expected patterns in ascending order, with the end of input (`None`) last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let digit = one_of::<_, _, Simple<char>>("3120").then_ignore(end());
+    ///
+    /// let errors = digit.parse("x").unwrap_err();
+    /// assert_eq!(
+    ///     errors[0].expected_sorted(),
+    ///     vec![&Some('0'), &Some('1'), &Some('2'), &Some('3')],
+    /// );
+    /// ```
+    pub fn expected_sorted(&self) -> Vec<&Option<I>>
+    where
+        I: Ord,
+    {
+        let mut expected = self.expected.iter().collect::<Vec<_>>();
+        expected.sort_by(|a, b| match (a, b) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (a, b) => a.is_none().cmp(&b.is_none()),
+        });
+        expected
+    }
+
+    /// Returns the ranges that an input was expected to fall within, if any.
+    ///
+    /// See [`Error::expected_range_found`].
+    pub fn expected_ranges(&self) -> &[(Bound<I>, Bound<I>)] {
+        self.extra
+            .as_ref()
+            .map_or(&[], |extra| &extra.expected_ranges)
+    }
+
     /// Returns the input, if any, that was found instead of an expected pattern.
     pub fn found(&self) -> Option<&I> {
         self.found.as_ref()
@@ -230,6 +351,30 @@ impl<I: Hash + Eq, S: Clone> Simple<I, S> {
         self.label
     }
 
+    /// Returns the label of the structure that was parsed immediately before the error occurred, if any.
+    ///
+    /// See [`Error::with_after`].
+    pub fn after(&self) -> Option<&'static str> {
+        self.extra.as_ref().and_then(|extra| extra.after)
+    }
+
+    /// Returns the spans related to the error, if any.
+    ///
+    /// See [`Error::with_related_span`].
+    pub fn related_spans(&self) -> &[S] {
+        self.extra.as_ref().map_or(&[], |extra| &extra.related)
+    }
+
+    fn extra_mut(&mut self) -> &mut SimpleExtra<I, S> {
+        self.extra.get_or_insert_with(|| {
+            Box::new(SimpleExtra {
+                expected_ranges: Vec::new(),
+                after: None,
+                related: Vec::new(),
+            })
+        })
+    }
+
     /// Map the error's inputs using the given function.
     ///
     /// This can be used to unify the errors between parsing stages that operate upon two forms of input (for example,
@@ -246,6 +391,17 @@ impl<I: Hash + Eq, S: Clone> Simple<I, S> {
                 SimpleReason::Custom(msg) => SimpleReason::Custom(msg),
             },
             expected: self.expected.into_iter().map(|e| e.map(&mut f)).collect(),
+            extra: self.extra.map(|extra| {
+                Box::new(SimpleExtra {
+                    expected_ranges: extra
+                        .expected_ranges
+                        .into_iter()
+                        .map(|(start, end)| (map_bound(start, &mut f), map_bound(end, &mut f)))
+                        .collect(),
+                    after: extra.after,
+                    related: extra.related,
+                })
+            }),
             found: self.found.map(f),
             label: self.label,
         }
@@ -267,6 +423,7 @@ impl<I: Hash + Eq, S: Span + Clone + fmt::Debug> Error<I> for Simple<I, S> {
             expected: expected.into_iter().collect(),
             found,
             label: None,
+            extra: None,
         }
     }
 
@@ -286,14 +443,39 @@ impl<I: Hash + Eq, S: Span + Clone + fmt::Debug> Error<I> for Simple<I, S> {
             expected: core::iter::once(Some(expected)).collect(),
             found,
             label: None,
+            extra: None,
         }
     }
 
+    fn expected_range_found(
+        span: Self::Span,
+        range: (Bound<I>, Bound<I>),
+        found: Option<I>,
+    ) -> Self {
+        let mut this = Self::expected_input_found(span, None, found);
+        this.extra_mut().expected_ranges.push(range);
+        this
+    }
+
+    fn custom<M: ToString>(span: Self::Span, msg: M) -> Self {
+        Self::custom(span, msg)
+    }
+
     fn with_label(mut self, label: Self::Label) -> Self {
         self.label.get_or_insert(label);
         self
     }
 
+    fn with_after(mut self, label: Self::Label) -> Self {
+        self.extra_mut().after.get_or_insert(label);
+        self
+    }
+
+    fn with_related_span(mut self, span: Self::Span) -> Self {
+        self.extra_mut().related.push(span);
+        self
+    }
+
     fn merge(mut self, other: Self) -> Self {
         // TODO: Assert that `self.span == other.span` here?
         self.reason = match (&self.reason, &other.reason) {
@@ -304,6 +486,25 @@ impl<I: Hash + Eq, S: Span + Clone + fmt::Debug> Error<I> for Simple<I, S> {
         for expected in other.expected {
             self.expected.insert(expected);
         }
+        self.label = self.label.or(other.label);
+        match (&mut self.extra, other.extra) {
+            (_, None) => {}
+            (None, Some(other)) => self.extra = Some(other),
+            (Some(extra), Some(other)) => {
+                let SimpleExtra {
+                    expected_ranges,
+                    after,
+                    mut related,
+                } = *other;
+                for range in expected_ranges {
+                    if !extra.expected_ranges.contains(&range) {
+                        extra.expected_ranges.push(range);
+                    }
+                }
+                extra.after = extra.after.or(after);
+                extra.related.append(&mut related);
+            }
+        }
         self
     }
 }
@@ -319,6 +520,29 @@ impl<I: Hash + Eq, S: PartialEq> PartialEq for Simple<I, S> {
 
 impl<I: Hash + Eq, S: Eq> Eq for Simple<I, S> {}
 
+fn map_bound<I, U, F: FnMut(I) -> U>(bound: Bound<I>, mut f: F) -> Bound<U> {
+    match bound {
+        Bound::Included(x) => Bound::Included(f(x)),
+        Bound::Excluded(x) => Bound::Excluded(f(x)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+// Describe an expected range in the same style as expected inputs, such as `"a"..="z"`
+fn describe_range<I: fmt::Display>((start, end): &(Bound<I>, Bound<I>)) -> String {
+    let describe = |x: &I| format!("{:?}", x.to_string());
+    let start = match start {
+        Bound::Included(x) => describe(x),
+        Bound::Excluded(x) => format!("{} (exclusive)", describe(x)),
+        Bound::Unbounded => String::new(),
+    };
+    match end {
+        Bound::Included(x) => format!("{}..={}", start, describe(x)),
+        Bound::Excluded(x) => format!("{}..{}", start, describe(x)),
+        Bound::Unbounded => format!("{}..", start),
+    }
+}
+
 impl<I: fmt::Display + Hash + Eq, S: Span> fmt::Display for Simple<I, S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // TODO: Take `self.reason` into account
@@ -329,30 +553,24 @@ impl<I: fmt::Display + Hash + Eq, S: Span> fmt::Display for Simple<I, S> {
             write!(f, "found end of input")?;
         };
 
-        match self.expected.len() {
+        // Sort by the displayed form so that messages are stable, placing the end of input last
+        let mut expected = self
+            .expected
+            .iter()
+            .flatten()
+            .map(|x| format!("{:?}", x.to_string()))
+            .collect::<Vec<_>>();
+        expected.sort();
+        expected.dedup();
+        expected.extend(self.expected_ranges().iter().map(describe_range));
+        if self.expected.contains(&None) {
+            expected.push("end of input".to_string());
+        }
+
+        match expected.len() {
             0 => {} //write!(f, " but end of input was expected")?,
-            1 => write!(
-                f,
-                " but expected {}",
-                match self.expected.iter().next().unwrap() {
-                    Some(x) => format!("{:?}", x.to_string()),
-                    None => "end of input".to_string(),
-                },
-            )?,
-            _ => {
-                write!(
-                    f,
-                    " but expected one of {}",
-                    self.expected
-                        .iter()
-                        .map(|expected| match expected {
-                            Some(x) => format!("{:?}", x.to_string()),
-                            None => "end of input".to_string(),
-                        })
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                )?;
-            }
+            1 => write!(f, " but expected {}", expected[0])?,
+            _ => write!(f, " but expected one of {}", expected.join(", "))?,
         }
 
         Ok(())
@@ -365,6 +583,106 @@ impl<I: fmt::Debug + fmt::Display + Hash + Eq, S: Span + fmt::Display + fmt::Deb
 {
 }
 
+/// Render an error as a human-readable report, showing the line of `src` on which it occurred with the error's span
+/// underlined.
+///
+/// The spans of the error must be *character* indices into `src`, as produced when parsing a [`&str`] directly. If the
+/// span covers more than one line, only the first line is shown and the underline ends with `...`.
+///
+/// The report starts with the error's message: the custom message if the error was created with [`Simple::custom`], or
+/// otherwise what was found and what was expected instead. If the error has a label, a note is added saying what was
+/// being parsed.
+///
+/// This function is only available when the `report` feature (enabled by default) is enabled. For more elaborate
+/// reports, including colours and multiple labelled spans, consider a dedicated crate such as
+/// [`ariadne`](https://crates.io/crates/ariadne).
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, error};
+/// let call = text::ident::<_, Simple<char>>()
+///     .then(text::int(10).padded().separated_by(just(',')).delimited_by(just('('), just(')')))
+///     .padded()
+///     .labelled("function call");
+///
+/// let src = "\nmax(1, 2;\n";
+/// let errors = call.parse(src).unwrap_err();
+///
+/// assert_eq!(
+///     error::report(src, &errors[0]),
+///     "\
+/// error: found \";\" but expected one of \")\", \",\"
+///  --> 2:9
+///   |
+/// 2 | max(1, 2;
+///   |         ^
+///   = note: while parsing function call
+/// ",
+/// );
+/// ```
+#[cfg(feature = "report")]
+pub fn report(src: &str, error: &Simple<char>) -> String {
+    let span = error.span();
+    let message = match error.reason() {
+        SimpleReason::Custom(msg) => msg.clone(),
+        SimpleReason::Unclosed { delimiter, .. } => format!("unclosed delimiter {:?}", delimiter),
+        SimpleReason::Unexpected => error.to_string(),
+    };
+
+    // Find the line containing the start of the span, and the character index at which that line begins
+    let mut line_start = 0;
+    let mut lines = src.split('\n').enumerate().peekable();
+    let (line_idx, line) = loop {
+        let (idx, line) = lines.next().unwrap_or((0, ""));
+        let len = line.chars().count();
+        if span.start <= line_start + len || lines.peek().is_none() {
+            break (idx, line);
+        }
+        line_start += len + 1;
+    };
+    let shown = line.trim_end_matches('\r');
+    let line_len = shown.chars().count();
+
+    let col = span.start.saturating_sub(line_start).min(line_len);
+    let multiline = span.end > line_start + line.chars().count() + 1;
+    let end = span
+        .end
+        .saturating_sub(line_start)
+        .min(line_len)
+        .max(col + 1);
+
+    // Tabs are kept so that the underline lines up with the source, whatever the tab width
+    let padding = shown
+        .chars()
+        .take(col)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect::<String>();
+    let underline = "^".repeat(end - col);
+
+    let line_no = (line_idx + 1).to_string();
+    let gutter = " ".repeat(line_no.len());
+
+    let mut report = format!(
+        "error: {}\n{}--> {}:{}\n{} |\n{} | {}\n{} | {}{}{}\n",
+        message,
+        gutter,
+        line_no,
+        col + 1,
+        gutter,
+        line_no,
+        shown,
+        gutter,
+        padding,
+        underline,
+        if multiline { "..." } else { "" },
+    );
+    if let Some(label) = error.label() {
+        report += &format!("{} = note: while parsing {}\n", gutter, label);
+    }
+    report
+}
+
 /// A minimal error type that tracks only the error span and label. This type is most useful when you want fast parsing
 /// but do not particularly care about the quality of error messages.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -407,9 +725,422 @@ impl<I, S: Span + Clone + fmt::Debug> Error<I> for Cheap<I, S> {
         self
     }
 
-    fn merge(self, _: Self) -> Self {
+    fn merge(mut self, other: Self) -> Self {
+        // Keep the label of either error, so that it is not lost when merged with an unlabelled alternative
+        self.label = self.label.or(other.label);
+        self
+    }
+}
+
+/// An error type that keeps more context than [`Simple`], for use by tools that produce detailed diagnostics.
+///
+/// In addition to the information tracked by [`Simple`], this type keeps a stack of *contexts*: the label and span of
+/// each syntactic structure (as named by [`Parser::labelled`]) that was being parsed when the error occurred,
+/// innermost first. Unlike [`Simple`], the inputs do not need to implement [`Hash`].
+///
+/// When errors are merged, the richer information is kept: an unclosed delimiter is preferred to a custom message,
+/// which is preferred to an unexpected input, and the longer context stack is kept.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, error::{Rich, SimpleReason}};
+/// let args = text::int::<_, Rich<char>>(10)
+///     .padded()
+///     .separated_by(just(','))
+///     .delimited_by(just('('), just(')'))
+///     .labelled("arguments");
+/// let call = text::ident()
+///     .then(args)
+///     .labelled("function call");
+///
+/// let errors = call.parse("foo(1, 2 3)").unwrap_err();
+/// assert_eq!(errors[0].span(), 9..10);
+/// assert_eq!(errors[0].reason(), &SimpleReason::Unexpected);
+/// assert_eq!(errors[0].found(), Some(&'3'));
+/// assert_eq!(
+///     errors[0].contexts().collect::<Vec<_>>(),
+///     vec![("arguments", &(3..9)), ("function call", &(0..9))],
+/// );
+/// assert_eq!(
+///     errors[0].to_string(),
+///     "found \"3\" but expected one of \")\", \",\", while parsing arguments at 3..9, \
+///     while parsing function call at 0..9",
+/// );
+/// // `Rich` errors can be used wherever a standard error is expected
+/// let _: Box<dyn std::error::Error> = Box::new(errors[0].clone());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rich<I, S = Range<usize>> {
+    span: S,
+    reason: SimpleReason<I, S>,
+    expected: Vec<Option<I>>,
+    found: Option<I>,
+    expected_ranges: Vec<(Bound<I>, Bound<I>)>,
+    contexts: Vec<(&'static str, S)>,
+    related: Vec<S>,
+}
+
+impl<I, S: Clone> Rich<I, S> {
+    /// Create an error with a custom error message.
+    pub fn custom<M: ToString>(span: S, msg: M) -> Self {
+        Self {
+            span,
+            reason: SimpleReason::Custom(msg.to_string()),
+            expected: Vec::new(),
+            found: None,
+            expected_ranges: Vec::new(),
+            contexts: Vec::new(),
+            related: Vec::new(),
+        }
+    }
+
+    /// Returns the span that the error occurred at.
+    pub fn span(&self) -> S {
+        self.span.clone()
+    }
+
+    /// Returns the reason for the error.
+    pub fn reason(&self) -> &SimpleReason<I, S> {
+        &self.reason
+    }
+
+    /// Returns an iterator over possible expected patterns.
+    pub fn expected(&self) -> impl ExactSizeIterator<Item = &Option<I>> + '_ {
+        self.expected.iter()
+    }
+
+    /// Returns the ranges that an input was expected to fall within, if any.
+    ///
+    /// See [`Error::expected_range_found`].
+    pub fn expected_ranges(&self) -> &[(Bound<I>, Bound<I>)] {
+        &self.expected_ranges
+    }
+
+    /// Returns the input, if any, that was found instead of an expected pattern.
+    pub fn found(&self) -> Option<&I> {
+        self.found.as_ref()
+    }
+
+    /// Returns an iterator over the label and span of each syntactic structure that was being parsed when the error
+    /// occurred, innermost first.
+    ///
+    /// See [`Error::in_context`].
+    pub fn contexts(&self) -> impl ExactSizeIterator<Item = (&'static str, &S)> + '_ {
+        self.contexts.iter().map(|(label, span)| (*label, span))
+    }
+
+    /// Returns the spans related to the error, if any.
+    ///
+    /// See [`Error::with_related_span`].
+    pub fn related_spans(&self) -> &[S] {
+        &self.related
+    }
+}
+
+impl<I: PartialEq, S: Span + Clone + fmt::Debug> Error<I> for Rich<I, S> {
+    type Span = S;
+    type Label = &'static str;
+
+    fn expected_input_found<Iter: IntoIterator<Item = Option<I>>>(
+        span: Self::Span,
+        expected: Iter,
+        found: Option<I>,
+    ) -> Self {
+        let mut this = Self {
+            span,
+            reason: SimpleReason::Unexpected,
+            expected: Vec::new(),
+            found,
+            expected_ranges: Vec::new(),
+            contexts: Vec::new(),
+            related: Vec::new(),
+        };
+        this.add_expected(expected);
+        this
+    }
+
+    fn unclosed_delimiter(
+        unclosed_span: Self::Span,
+        delimiter: I,
+        span: Self::Span,
+        expected: I,
+        found: Option<I>,
+    ) -> Self {
+        Self {
+            span,
+            reason: SimpleReason::Unclosed {
+                span: unclosed_span,
+                delimiter,
+            },
+            expected: alloc::vec![Some(expected)],
+            found,
+            expected_ranges: Vec::new(),
+            contexts: Vec::new(),
+            related: Vec::new(),
+        }
+    }
+
+    fn expected_range_found(
+        span: Self::Span,
+        range: (Bound<I>, Bound<I>),
+        found: Option<I>,
+    ) -> Self {
+        let mut this = Self::expected_input_found(span, None, found);
+        this.expected_ranges.push(range);
+        this
+    }
+
+    fn custom<M: ToString>(span: Self::Span, msg: M) -> Self {
+        Self::custom(span, msg)
+    }
+
+    fn with_label(self, label: Self::Label) -> Self {
+        let span = self.span.clone();
+        self.in_context(label, span)
+    }
+
+    fn in_context(mut self, label: Self::Label, span: Self::Span) -> Self {
+        self.contexts.push((label, span));
+        self
+    }
+
+    fn with_related_span(mut self, span: Self::Span) -> Self {
+        self.related.push(span);
+        self
+    }
+
+    fn merge(mut self, mut other: Self) -> Self {
+        fn richness<I, S>(reason: &SimpleReason<I, S>) -> u8 {
+            match reason {
+                SimpleReason::Unexpected => 0,
+                SimpleReason::Custom(_) => 1,
+                SimpleReason::Unclosed { .. } => 2,
+            }
+        }
+
+        if richness(&other.reason) > richness(&self.reason) {
+            self.reason = other.reason;
+        }
+        self.add_expected(other.expected);
+        for range in other.expected_ranges {
+            if !self.expected_ranges.contains(&range) {
+                self.expected_ranges.push(range);
+            }
+        }
+        if other.contexts.len() > self.contexts.len() {
+            self.contexts = other.contexts;
+        }
+        self.related.append(&mut other.related);
+        self
+    }
+}
+
+impl<I: PartialEq, S> Rich<I, S> {
+    fn add_expected<Iter: IntoIterator<Item = Option<I>>>(&mut self, expected: Iter) {
+        for expected in expected {
+            if !self.expected.contains(&expected) {
+                self.expected.push(expected);
+            }
+        }
+    }
+}
+
+impl<I: fmt::Display, S: fmt::Debug> fmt::Display for Rich<I, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.reason {
+            SimpleReason::Unexpected => {
+                match &self.found {
+                    Some(found) => write!(f, "found {:?}", found.to_string())?,
+                    None => write!(f, "found end of input")?,
+                }
+                let expected = self
+                    .expected
+                    .iter()
+                    .map(|expected| match expected {
+                        Some(x) => format!("{:?}", x.to_string()),
+                        None => "end of input".to_string(),
+                    })
+                    .chain(self.expected_ranges.iter().map(describe_range))
+                    .collect::<Vec<_>>();
+                match expected.len() {
+                    0 => {}
+                    1 => write!(f, " but expected {}", expected[0])?,
+                    _ => write!(f, " but expected one of {}", expected.join(", "))?,
+                }
+            }
+            // Spans are shown in their debug form (such as `3..9`), since most span types don't implement `Display`
+            SimpleReason::Unclosed { span, delimiter } => {
+                write!(f, "unclosed delimiter ({:?}) in {}", span, delimiter)?
+            }
+            SimpleReason::Custom(string) => write!(f, "error {}", string)?,
+        }
+
+        for (label, span) in &self.contexts {
+            write!(f, ", while parsing {} at {:?}", label, span)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: fmt::Debug + fmt::Display, S: fmt::Debug> std::error::Error for Rich<I, S> {}
+
+/// The severity of a diagnostic within a [`Report`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// A diagnostic that prevents the input from being considered valid.
+    Error,
+    /// A diagnostic that does not prevent the input from being considered valid, but should still be shown.
+    Warning,
+}
+
+/// A collection of diagnostics produced while parsing, each with a [`Severity`].
+///
+/// Chumsky itself only produces errors, so a report produced by [`Parser::parse_report`] initially contains no
+/// warnings. Warnings (such as those produced by validation or by later compilation stages) may be added with
+/// [`Report::with_warning`] or [`Report::push_warning`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, error::{Report, Severity}};
+/// let digits = text::digits::<_, Simple<char>>(10).then_ignore(end());
+///
+/// let (out, mut report) = digits.parse_report("12a4");
+/// assert_eq!(out, None);
+/// assert!(report.has_errors());
+///
+/// report.push_warning(Simple::custom(0..1, "leading digit"));
+/// report.sort_by_span();
+///
+/// let severities = report.iter().map(|(severity, _)| severity).collect::<Vec<_>>();
+/// assert_eq!(severities, vec![Severity::Warning, Severity::Error]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report<E> {
+    diagnostics: Vec<(Severity, E)>,
+}
+
+impl<E> Default for Report<E> {
+    fn default() -> Self {
+        Self {
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+impl<E> Report<E> {
+    /// Create a new, empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new report from a list of errors.
+    pub fn from_errors<Iter: IntoIterator<Item = E>>(errors: Iter) -> Self {
+        Self {
+            diagnostics: errors.into_iter().map(|e| (Severity::Error, e)).collect(),
+        }
+    }
+
+    /// Add an error to the report.
+    pub fn with_error(mut self, error: E) -> Self {
+        self.push_error(error);
+        self
+    }
+
+    /// Add a warning to the report.
+    pub fn with_warning(mut self, warning: E) -> Self {
+        self.push_warning(warning);
         self
     }
+
+    /// Add an error to the report.
+    pub fn push_error(&mut self, error: E) {
+        self.diagnostics.push((Severity::Error, error));
+    }
+
+    /// Add a warning to the report.
+    pub fn push_warning(&mut self, warning: E) {
+        self.diagnostics.push((Severity::Warning, warning));
+    }
+
+    /// Returns an iterator over the errors in the report.
+    pub fn errors(&self) -> impl Iterator<Item = &E> + '_ {
+        self.with_severity(Severity::Error)
+    }
+
+    /// Returns an iterator over the warnings in the report.
+    pub fn warnings(&self) -> impl Iterator<Item = &E> + '_ {
+        self.with_severity(Severity::Warning)
+    }
+
+    fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &E> + '_ {
+        self.diagnostics
+            .iter()
+            .filter(move |(s, _)| *s == severity)
+            .map(|(_, e)| e)
+    }
+
+    /// Returns true if the report contains any errors.
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    /// Returns true if the report contains no diagnostics at all.
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    /// Returns the total number of diagnostics in the report.
+    pub fn len(&self) -> usize {
+        self.diagnostics.len()
+    }
+
+    /// Iterate over the diagnostics in the report, alongside their severity.
+    pub fn iter(&self) -> impl Iterator<Item = (Severity, &E)> + '_ {
+        self.diagnostics.iter().map(|(s, e)| (*s, e))
+    }
+
+    /// Sort the diagnostics in the report by the given key. Diagnostics with equal keys keep their relative order.
+    pub fn sort_by_key<K: Ord, F: FnMut(&E) -> K>(&mut self, mut f: F) {
+        self.diagnostics.sort_by_key(|(_, e)| f(e));
+    }
+
+    /// Consume the report, returning its diagnostics.
+    pub fn into_diagnostics(self) -> Vec<(Severity, E)> {
+        self.diagnostics
+    }
+}
+
+impl<I: Hash + Eq, S: Span> Report<Simple<I, S>>
+where
+    S::Offset: Ord,
+{
+    /// Sort the diagnostics in the report by the position of their spans in the input.
+    pub fn sort_by_span(&mut self) {
+        self.sort_by_key(|e| (e.span.start(), e.span.end()));
+    }
+}
+
+impl<I, S: Span> Report<Cheap<I, S>>
+where
+    S::Offset: Ord,
+{
+    /// Sort the diagnostics in the report by the position of their spans in the input.
+    pub fn sort_by_span(&mut self) {
+        self.sort_by_key(|e| (e.span.start(), e.span.end()));
+    }
+}
+
+impl<E> IntoIterator for Report<E> {
+    type Item = (Severity, E);
+    type IntoIter = alloc::vec::IntoIter<(Severity, E)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.diagnostics.into_iter()
+    }
 }
 
 /// An internal type used to facilitate error prioritisation. You shouldn't need to interact with this type during
@@ -417,40 +1148,70 @@ impl<I, S: Span + Clone + fmt::Debug> Error<I> for Cheap<I, S> {
 pub struct Located<I, E> {
     pub(crate) at: usize,
     pub(crate) error: E,
+    // If true, the nearest enclosing choice (a `choice`, or a chain of `Parser::or`) should not attempt other branches
+    // after this error (see `Parser::try_window` and `Parser::cut`). Alternatives further out are unaffected, so the
+    // flag is cleared once the error has passed through that choice.
+    pub(crate) committed: bool,
     pub(crate) phantom: PhantomData<I>,
 }
 
+impl<I, E: Clone> Clone for Located<I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            at: self.at,
+            error: self.error.clone(),
+            committed: self.committed,
+            phantom: PhantomData,
+        }
+    }
+}
+
 impl<I, E: Error<I>> Located<I, E> {
     /// Create a new [`Located`] with the give input position and error.
     pub fn at(at: usize, error: E) -> Self {
         Self {
             at,
             error,
+            committed: false,
             phantom: PhantomData,
         }
     }
 
     /// Get the maximum of two located errors. If they hold the same position in the input, merge them.
+    ///
+    /// `self` is the primary error, so the result is committed only if `self` is.
     pub fn max(self, other: impl Into<Option<Self>>) -> Self {
         let other = match other.into() {
             Some(other) => other,
             None => return self,
         };
+        let committed = self.committed;
         match self.at.cmp(&other.at) {
-            Ordering::Greater => self,
-            Ordering::Less => other,
+            Ordering::Greater => Self { committed, ..self },
+            Ordering::Less => Self { committed, ..other },
             Ordering::Equal => Self {
                 error: self.error.merge(other.error),
+                committed,
                 ..self
             },
         }
     }
 
+    // Called once the error has passed through the nearest enclosing choice, after which it no longer prevents other
+    // alternatives from being attempted
+    pub(crate) fn uncommit(self) -> Self {
+        Self {
+            committed: false,
+            ..self
+        }
+    }
+
     /// Map the error with the given function.
     pub fn map<U, F: FnOnce(E) -> U>(self, f: F) -> Located<I, U> {
         Located {
             at: self.at,
             error: f(self.error),
+            committed: self.committed,
             phantom: PhantomData,
         }
     }
@@ -0,0 +1,40 @@
+//! Types and traits related to interning parser outputs.
+//!
+//! *“It is a mistake to think you can solve any major problems just with potatoes.”*
+//!
+//! Source files often contain the same identifiers many times over. Rather than allocating a fresh [`String`] for every
+//! occurrence, [`Parser::intern`] can be used to deduplicate outputs through an [`Interner`], yielding a cheap handle
+//! instead.
+//!
+//! [`Interner`] is implemented for [`BTreeSet<Rc<str>>`], but you can implement it for your own interner (or a wrapper
+//! around an existing interning crate) too.
+
+use super::*;
+use alloc::collections::BTreeSet;
+
+/// A trait implemented by types that can deduplicate values of type `T`, producing a handle for each.
+pub trait Interner<T> {
+    /// The handle produced when a value is interned.
+    ///
+    /// This is usually something cheap to clone or compare, such as an [`Rc<str>`] or an integer index.
+    type Handle;
+
+    /// Intern the given value, returning a handle to it. Interning equal values must produce equivalent handles.
+    fn intern(&mut self, value: T) -> Self::Handle;
+}
+
+impl<T: AsRef<str>> Interner<T> for BTreeSet<Rc<str>> {
+    type Handle = Rc<str>;
+
+    fn intern(&mut self, value: T) -> Self::Handle {
+        let value = value.as_ref();
+        match self.get(value) {
+            Some(handle) => handle.clone(),
+            None => {
+                let handle: Rc<str> = Rc::from(value);
+                self.insert(handle.clone());
+                handle
+            }
+        }
+    }
+}
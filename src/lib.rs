@@ -6,11 +6,16 @@
 
 extern crate alloc;
 
+pub mod bytes;
 pub mod chain;
 pub mod combinator;
 pub mod debug;
 pub mod error;
+pub mod intern;
+pub mod pratt;
 pub mod primitive;
+#[cfg(feature = "profile")]
+pub mod profile;
 pub mod recovery;
 pub mod recursive;
 pub mod span;
@@ -26,12 +31,15 @@ use crate::{
     combinator::*,
     debug::*,
     error::{merge_alts, Located},
+    intern::Interner,
     primitive::*,
     recovery::*,
+    span::Spanned,
 };
 
 use alloc::{boxed::Box, rc::Rc, string::String, sync::Arc, vec, vec::Vec};
 use core::{
+    cell::RefCell,
     cmp::Ordering,
     // TODO: Enable when stable
     //lazy::OnceCell,
@@ -57,16 +65,20 @@ pub mod prelude {
     pub use super::{
         error::{Error as _, Simple},
         primitive::{
-            any, choice, empty, end, filter, filter_map, just, none_of, one_of, seq, take_until,
-            todo,
+            any, choice, empty, end, filter, filter_labelled, filter_map, filter_map_with_state,
+            group, just, none_of, none_of_range, one_of, one_of_range, seq, take_until, todo,
+            value,
+        },
+        recovery::{
+            incomplete, insert_missing, nested_delimiters, nested_delimiters_any,
+            skip_then_retry_at_indent, skip_then_retry_until, skip_until, via_parser,
         },
-        recovery::{nested_delimiters, skip_then_retry_until, skip_until},
         recursive::{recursive, Recursive},
         select,
         span::Span as _,
         text,
         text::TextParser as _,
-        BoxedParser, Parser,
+        ArcParser, BoxedParser, Parser,
     };
 }
 
@@ -106,11 +118,21 @@ fn parse_recovery_inner<
 where
     P: Sized,
 {
+    let mut stream = stream.into();
     #[allow(deprecated)]
-    let (mut errors, res) = parser.parse_inner(debugger, &mut stream.into());
+    let (mut errors, res) = parser.parse_inner(debugger, &mut stream);
     let out = match res {
-        Ok((out, _)) => Some(out),
+        Ok((out, _)) => {
+            if let Some(limit) = stream.error_limit {
+                errors.truncate(limit.max(1));
+            }
+            Some(out)
+        }
         Err(err) => {
+            // The error that halted parsing is always reported
+            if let Some(limit) = stream.error_limit {
+                errors.truncate(limit.max(1) - 1);
+            }
             errors.push(err);
             None
         }
@@ -185,6 +207,18 @@ pub trait Parser<I: Clone, O> {
         s: &mut StreamOf<I, Self::Error>,
     ) -> PResult<I, O, Self::Error>;
 
+    /// Whether this parser is a choice between alternatives (such as [`Parser::or`] or [`choice`]), such that a chain
+    /// of alternatives like `a.or(b).or(c)` behaves like `choice((a, b, c))`. Do not call this method directly.
+    ///
+    /// If you *really* need to implement this trait, this method should not be overridden.
+    #[doc(hidden)]
+    #[deprecated(
+        note = "This method is excluded from the semver guarantees of chumsky. If you decide to use it, broken builds are your fault."
+    )]
+    fn is_choice(&self) -> bool {
+        false
+    }
+
     /// Parse a stream of tokens, yielding an output if possible, and any errors encountered along the way.
     ///
     /// If `None` is returned (i.e: parsing failed) then there will *always* be at least one item in the error `Vec`.
@@ -201,6 +235,92 @@ pub trait Parser<I: Clone, O> {
         parse_recovery_inner(self, &mut Silent::new(), stream)
     }
 
+    /// Parse a stream of tokens with a mutable user-defined state attached, yielding an output if possible, and any
+    /// errors encountered along the way.
+    ///
+    /// This is shorthand for attaching the state with [`Stream::with_state`] and then calling
+    /// [`Parser::parse_recovery`]. The state is available to stateful combinators such as
+    /// [`Parser::map_with_state`] and [`Parser::try_map_with_state`].
+    ///
+    /// Because the state is stored type-erased, `St` must be `'static`: it may own data, but cannot borrow any (such as
+    /// `&str` slices of the source). See [`Stream::with_state`] for details.
+    ///
+    /// The state is shared mutably by all branches of the parser, including those that are later backtracked or
+    /// recovered from: any changes made by a branch that ultimately fails to parse are *not* undone.
+    ///
+    /// # Panics
+    ///
+    /// Parsing will panic if any stateful combinator in the parser expects a state of a type other than `St`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let ident = text::ident::<_, Simple<char>>()
+    ///     .map_with_state(|name, names: &mut Vec<String>| {
+    ///         match names.iter().position(|n| *n == name) {
+    ///             Some(id) => id,
+    ///             None => { names.push(name); names.len() - 1 }
+    ///         }
+    ///     })
+    ///     .padded();
+    ///
+    /// let mut names = Vec::<String>::new();
+    /// let (ids, errors) = ident.repeated().parse_recovery_with_state("a b a c b", &mut names);
+    /// assert_eq!(ids, Some(vec![0, 1, 0, 2, 1]));
+    /// assert!(errors.is_empty());
+    /// assert_eq!(names, ["a", "b", "c"]);
+    /// ```
+    fn parse_recovery_with_state<'a, Iter, S, St>(
+        &self,
+        stream: S,
+        state: &'a mut St,
+    ) -> (Option<O>, Vec<Self::Error>)
+    where
+        Self: Sized,
+        Iter: Iterator<Item = (I, <Self::Error as Error<I>>::Span)> + 'a,
+        S: Into<Stream<'a, I, <Self::Error as Error<I>>::Span, Iter>>,
+        St: core::any::Any,
+    {
+        self.parse_recovery(stream.into().with_state(state))
+    }
+
+    /// Parse a stream of tokens, yielding an output if possible, and at most `max_errors` errors encountered along the
+    /// way.
+    ///
+    /// Once `max_errors - 1` errors have been emitted, no further recovery is attempted and parsing halts at the next
+    /// error (which is reported last), so badly broken input doesn't produce a flood of cascading errors. This is
+    /// shorthand for limiting the errors with [`Stream::with_error_limit`] and then calling [`Parser::parse_recovery`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let item = just::<_, _, Simple<char>>('a')
+    ///     .recover_with(skip_then_retry_until([',']))
+    ///     .separated_by(just(','));
+    ///
+    /// let (items, errors) = item.parse_recovery("a,1a,2a,3a,a");
+    /// assert_eq!(items, Some(vec!['a'; 5]));
+    /// assert_eq!(errors.len(), 3);
+    ///
+    /// let (items, errors) = item.then_ignore(end()).parse_recovery_with_limit("a,1a,2a,3a,a", 2);
+    /// assert_eq!(items, None);
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    fn parse_recovery_with_limit<'a, Iter, S>(
+        &self,
+        stream: S,
+        max_errors: usize,
+    ) -> (Option<O>, Vec<Self::Error>)
+    where
+        Self: Sized,
+        Iter: Iterator<Item = (I, <Self::Error as Error<I>>::Span)> + 'a,
+        S: Into<Stream<'a, I, <Self::Error as Error<I>>::Span, Iter>>,
+    {
+        self.parse_recovery(stream.into().with_error_limit(max_errors))
+    }
+
     /// Parse a stream of tokens, yielding an output if possible, and any errors encountered along the way. Unlike
     /// [`Parser::parse_recovery`], this function will produce verbose debugging output as it executes.
     ///
@@ -248,12 +368,127 @@ pub trait Parser<I: Clone, O> {
         }
     }
 
+    /// Parse a string, yielding an output *or* any errors that were encountered along the way.
+    ///
+    /// Unlike passing a [`&str`] to [`Parser::parse`] (which produces spans of character indices), the spans of the
+    /// input are UTF-8 byte offsets into the string, as produced by [`Stream::from_str_with_byte_spans`]. This means
+    /// that spans can be used to slice the original string directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let word = filter::<_, _, Simple<char>>(|c: &char| c.is_alphabetic())
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .map_with_span(|_, span| span)
+    ///     .padded()
+    ///     .repeated();
+    ///
+    /// let src = "café au lait";
+    /// let spans = word.parse_str(src).unwrap();
+    /// assert_eq!(spans, vec![0..5, 6..8, 9..13]); // 'é' is two bytes long
+    /// assert_eq!(&src[spans[0].clone()], "café");
+    /// ```
+    fn parse_str<'a>(&self, s: &'a str) -> Result<O, Vec<Self::Error>>
+    where
+        Self: Sized,
+        I: 'a,
+        Self::Error: Error<I, Span = Range<usize>>,
+        Stream<'a, char, Range<usize>, Box<dyn Iterator<Item = (char, Range<usize>)> + 'a>>:
+            Into<Stream<'a, I, Range<usize>, Box<dyn Iterator<Item = (I, Range<usize>)> + 'a>>>,
+    {
+        self.parse(Stream::from_str_with_byte_spans(s))
+    }
+
+    /// Parse a stream of tokens, yielding an output if possible, and a [`Report`](error::Report) containing any errors
+    /// encountered along the way.
+    ///
+    /// This is equivalent to [`Parser::parse_recovery`], but collects the errors into a report that further
+    /// diagnostics (such as warnings) can be added to and that can be sorted for display.
+    ///
+    /// Although the signature of this function looks complicated, it's simpler than you think! You can pass a
+    /// `&[I]`, a [`&str`], or a [`Stream`] to it.
+    fn parse_report<'a, Iter, S>(&self, stream: S) -> (Option<O>, error::Report<Self::Error>)
+    where
+        Self: Sized,
+        Iter: Iterator<Item = (I, <Self::Error as Error<I>>::Span)> + 'a,
+        S: Into<Stream<'a, I, <Self::Error as Error<I>>::Span, Iter>>,
+    {
+        let (output, errors) = self.parse_recovery(stream);
+        (output, error::Report::from_errors(errors))
+    }
+
+    /// Parse a single pattern from the front of a [`Stream`], handing the stream back afterwards so that parsing can
+    /// continue from where this parser left off.
+    ///
+    /// This is useful when the input consists of a sequence of independent items that may each require a different
+    /// parser (for example, alternating headers and records). Unlike [`Parser::parse`], this function does not require
+    /// that the entire input is consumed.
+    ///
+    /// If parsing succeeds, the returned stream is positioned immediately after the parsed pattern. If any errors are
+    /// encountered, the returned stream is rewound to where it was before this function was called so that another
+    /// parser may be tried instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, Stream};
+    /// let header = just::<_, _, Simple<char>>('#').ignore_then(text::ident()).then_ignore(just('\n'));
+    /// let record = text::int::<_, Simple<char>>(10).then_ignore(just('\n'));
+    ///
+    /// let stream = Stream::from("#nums\n42\n");
+    ///
+    /// // A record can't be parsed here, so the stream is left untouched...
+    /// let (stream, res) = record.parse_continue(stream);
+    /// assert!(res.is_err());
+    /// // ...and we can try parsing a header instead
+    /// let (stream, res) = header.parse_continue(stream);
+    /// assert_eq!(res, Ok("nums".to_string()));
+    /// let (_, res) = record.parse_continue(stream);
+    /// assert_eq!(res, Ok("42".to_string()));
+    /// ```
+    fn parse_continue<'a, Iter>(
+        &self,
+        mut stream: Stream<'a, I, <Self::Error as Error<I>>::Span, Iter>,
+    ) -> (
+        Stream<'a, I, <Self::Error as Error<I>>::Span, Iter>,
+        Result<O, Vec<Self::Error>>,
+    )
+    where
+        Self: Sized,
+        Iter: Iterator<Item = (I, <Self::Error as Error<I>>::Span)> + 'a,
+    {
+        let res = {
+            let stream: &mut StreamOf<I, Self::Error> = &mut stream;
+            let start = stream.save();
+            #[allow(deprecated)]
+            let (mut errors, res) = self.parse_inner(&mut Silent::new(), stream);
+            match res {
+                Ok((out, _)) if errors.is_empty() => Ok(out),
+                res => {
+                    if let Err(err) = res {
+                        errors.push(err);
+                    }
+                    stream.revert(start);
+                    Err(errors.into_iter().map(|e| e.error).collect())
+                }
+            }
+        };
+        (stream, res)
+    }
+
     /// Include this parser in the debugging output produced by [`Parser::parse_recovery_verbose`].
     ///
     /// You'll probably want to make sure that this doesn't end up in production code: it exists only to help you debug
     /// your parser. Additionally, its API is quite likely to change in future versions.
     /// Use this parser like a print statement, to display whatever you pass as the argument 'x'
     ///
+    /// To print a trace of this parser without using [`Parser::parse_recovery_verbose`], see [`Parser::trace`].
+    ///
+    /// When the `profile` feature is enabled, parsers named with this method also record profiling counters. See
+    /// the `profile` module.
+    ///
     /// This method will receive more extensive documentation as the crate's debugging features mature.
     #[track_caller]
     fn debug<T>(self, x: T) -> Debug<Self>
@@ -264,6 +499,65 @@ pub trait Parser<I: Clone, O> {
         Debug(self, Rc::new(x), *core::panic::Location::caller())
     }
 
+    /// Print a trace of every attempt to parse this parser to standard error, labelled with the given label.
+    ///
+    /// Each attempt prints the position in the input (as the number of tokens consumed since the start) at which it
+    /// began and, once finished, whether it succeeded (and the positions of the inputs it consumed) or failed (and
+    /// the position of the error). This is useful for working out why a grammar doesn't match an input, such as when
+    /// an earlier alternative of [`Parser::or`] consumes input that a later one was expected to handle.
+    ///
+    /// Tracing is entirely transparent: it does not change the output, errors or error positions of the parser. To
+    /// also print the output of the parser, use [`Parser::trace_output`]. For tracing that is only performed by
+    /// [`Parser::parse_recovery_verbose`], use [`Parser::debug`].
+    ///
+    /// You'll probably want to make sure that this doesn't end up in production code: it exists only to help you debug
+    /// your parser.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let word = text::ident::<_, Simple<char>>().trace("word");
+    ///
+    /// // Prints:
+    /// // [word] attempt at 0
+    /// // [word] succeeded, consuming 0..5
+    /// assert_eq!(word.parse("hello"), Ok("hello".to_string()));
+    /// ```
+    #[cfg(feature = "std")]
+    fn trace<T>(self, label: T) -> Trace<Self, O>
+    where
+        Self: Sized,
+        T: fmt::Display + 'static,
+    {
+        Trace(self, Rc::new(label), None)
+    }
+
+    /// Like [`Parser::trace`], but also prints the output of the parser when it succeeds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let digits = text::digits::<_, Simple<char>>(10).trace_output("digits");
+    ///
+    /// // Prints:
+    /// // [digits] attempt at 0
+    /// // [digits] succeeded, consuming 0..3: "123"
+    /// assert_eq!(digits.parse("123"), Ok("123".to_string()));
+    /// ```
+    #[cfg(feature = "std")]
+    fn trace_output<T>(self, label: T) -> Trace<Self, O>
+    where
+        Self: Sized,
+        T: fmt::Display + 'static,
+        O: fmt::Debug,
+    {
+        Trace(self, Rc::new(label), Some(|out| format!("{:?}", out)))
+    }
+
     /// Map the output of this parser to another value.
     ///
     /// The output type of this parser is `U`, the same as the function's output.
@@ -298,6 +592,46 @@ pub trait Parser<I: Clone, O> {
         Map(self, f, PhantomData)
     }
 
+    /// Map the output of this parser to another value, deferring the (potentially expensive) mapping until the value
+    /// is actually needed.
+    ///
+    /// Chumsky applies [`Parser::map`] as soon as the pattern has been parsed, even if an enclosing pattern later fails
+    /// and the output is discarded (for example, by a speculative [`Parser::or`] branch). When the mapping function
+    /// allocates (building [`String`]s, boxing AST nodes, etc.), this wasted work can be significant. This combinator
+    /// instead produces a [`Deferred`] value that holds the original output and a copy of the function: call
+    /// [`Deferred::force`] (usually via [`Parser::map`]) at the point at which the grammar has committed to the
+    /// pattern.
+    ///
+    /// The function is cloned into every output, so it should be cheap to clone (closures that capture nothing are
+    /// [`Copy`]).
+    ///
+    /// The output type of this parser is [`Deferred<O, F>`](combinator::Deferred).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let word = filter::<_, _, Simple<char>>(char::is_ascii_alphabetic)
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .map_deferred(|chars| chars.into_iter().collect::<String>());
+    ///
+    /// // The first branch fails after `word` has been parsed, but no `String` is ever constructed for it
+    /// let call = word.then_ignore(just("()")).map(|name| format!("call {}", name.force()));
+    /// let var = word.then_ignore(end()).map(|name| format!("var {}", name.force()));
+    /// let expr = call.or(var);
+    ///
+    /// assert_eq!(expr.parse("foo"), Ok("var foo".to_string()));
+    /// assert_eq!(expr.parse("foo()"), Ok("call foo".to_string()));
+    /// ```
+    fn map_deferred<U, F>(self, f: F) -> MapDeferred<Self, F, O>
+    where
+        Self: Sized,
+        F: FnOnce(O) -> U + Clone,
+    {
+        MapDeferred(self, f, PhantomData)
+    }
+
     /// Map the output of this parser to another value, making use of the pattern's span when doing so.
     ///
     /// This is very useful when generating an AST that attaches a span to each AST node.
@@ -329,6 +663,163 @@ pub trait Parser<I: Clone, O> {
         MapWithSpan(self, f, PhantomData)
     }
 
+    /// Map the inputs consumed by this parser, along with their spans, to a value. The output of this parser is
+    /// discarded.
+    ///
+    /// The inputs are borrowed directly from the stream, so no collection is allocated. This is useful for tokens
+    /// such as identifiers and numbers, where the input that makes up the token is of more interest than the output of
+    /// the parser that recognised it. If only the span of the consumed input is needed, use [`Parser::map_with_span`]
+    /// instead. When parsing a [`&str`] with byte spans, [`Parser::slice_str`] produces the consumed text directly.
+    ///
+    /// The output type of this parser is `U`, the same as the function's output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// // Count the digits of a number, ignoring separators
+    /// let number = filter::<_, _, Simple<char>>(|c: &char| c.is_ascii_digit() || *c == '_')
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .map_slice(|toks| toks.iter().filter(|(c, _)| *c != '_').count());
+    ///
+    /// assert_eq!(number.parse("1_000_000"), Ok(7));
+    /// ```
+    fn map_slice<U, F>(self, f: F) -> MapSlice<Self, F, O>
+    where
+        Self: Sized,
+        F: Fn(&[(I, <Self::Error as Error<I>>::Span)]) -> U,
+    {
+        MapSlice(self, f, PhantomData)
+    }
+
+    /// Produce the slice of the source text consumed by this parser. The output of this parser is discarded.
+    ///
+    /// The spans of the input must be *byte* offsets into `source`, such as those produced by
+    /// [`Parser::parse_str`] or [`Stream::from_str_with_byte_spans`]. No allocation is performed.
+    ///
+    /// # Panics
+    ///
+    /// Parsing will panic if the spans of the input do not correspond to valid byte ranges of `source`.
+    ///
+    /// The output type of this parser is `&str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let src = "größe = 42";
+    ///
+    /// let ident = filter::<_, _, Simple<char>>(|c: &char| c.is_alphabetic())
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .slice_str(src);
+    /// let assign = ident.then_ignore(just('=').padded()).then(text::int(10).slice_str(src));
+    ///
+    /// assert_eq!(assign.parse_str(src), Ok(("größe", "42")));
+    /// ```
+    fn slice_str(self, source: &str) -> SliceStr<'_, Self, O>
+    where
+        Self: Sized,
+        Self::Error: Error<I, Span = Range<usize>>,
+    {
+        SliceStr(self, source, PhantomData)
+    }
+
+    /// Map the output of this parser to another value and attach the pattern's span to the result.
+    ///
+    /// This is shorthand for `.map_with_span(|x, span| Spanned::new(f(x), span))`, which is the form that most AST
+    /// nodes need.
+    ///
+    /// The output type of this parser is [`Spanned<U, Span>`](span::Spanned), where `U` is the function's output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, span::Spanned};
+    /// #[derive(Debug, PartialEq)]
+    /// enum Expr {
+    ///     Num(u64),
+    /// }
+    ///
+    /// let num = text::int::<_, Simple<char>>(10)
+    ///     .from_str()
+    ///     .unwrapped()
+    ///     .spanned_map(Expr::Num)
+    ///     .padded();
+    ///
+    /// assert_eq!(num.parse("42"), Ok(Spanned::new(Expr::Num(42), 0..2)));
+    /// assert_eq!(num.parse("   1234 "), Ok(Spanned::new(Expr::Num(1234), 3..7)));
+    /// ```
+    fn spanned_map<U, F>(self, f: F) -> SpannedMap<Self, F, O>
+    where
+        Self: Sized,
+        F: Fn(O) -> U,
+    {
+        SpannedMap(self, f, PhantomData)
+    }
+
+    /// Attach the pattern's span to the output of this parser.
+    ///
+    /// This is shorthand for `.spanned_map(|x| x)`. The span covers all of the input matched by the pattern, from its
+    /// first token to its last. Use [`Spanned::into_parts`](span::Spanned::into_parts) to get an `(O, Span)` tuple.
+    ///
+    /// The output type of this parser is [`Spanned<O, Span>`](span::Spanned).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, span::Spanned};
+    /// let call = text::ident::<_, Simple<char>>()
+    ///     .then(text::int(10).padded().separated_by(just(',')).delimited_by(just('('), just(')')))
+    ///     .spanned()
+    ///     .padded();
+    ///
+    /// let call = call.parse(" max(1, 2) ").unwrap();
+    /// assert_eq!(call.span(), &(1..10));
+    /// assert_eq!(call.into_parts().0, ("max".to_string(), vec!["1".to_string(), "2".to_string()]));
+    /// ```
+    fn spanned(self) -> SpannedMap<Self, fn(O) -> O, O>
+    where
+        Self: Sized,
+    {
+        self.spanned_map(|x| x)
+    }
+
+    /// Intern the output of this parser using the given [`Interner`], producing a handle to the deduplicated value.
+    ///
+    /// This is useful when parsing large inputs that contain many repetitions of the same identifiers: equal outputs
+    /// share a single allocation rather than each occurrence allocating a fresh value.
+    ///
+    /// The output type of this parser is `N::Handle`, the handle type of the interner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// use std::{cell::RefCell, collections::BTreeSet, rc::Rc};
+    ///
+    /// let interner = RefCell::new(BTreeSet::<Rc<str>>::new());
+    ///
+    /// let idents = text::ident::<_, Simple<char>>()
+    ///     .intern(&interner)
+    ///     .padded()
+    ///     .repeated();
+    ///
+    /// let out = idents.parse("foo bar foo").unwrap();
+    /// assert_eq!(&*out[0], "foo");
+    /// // Both occurrences of `foo` share the same allocation
+    /// assert!(Rc::ptr_eq(&out[0], &out[2]));
+    /// assert_eq!(interner.borrow().len(), 2);
+    /// ```
+    fn intern<N>(self, interner: &RefCell<N>) -> Intern<'_, Self, N, O>
+    where
+        Self: Sized,
+        N: Interner<O>,
+    {
+        Intern(self, interner, PhantomData)
+    }
+
     /// Map the primary error of this parser to another value.
     ///
     /// This function is most useful when using a custom error type, allowing you to augment errors according to
@@ -365,8 +856,25 @@ pub trait Parser<I: Clone, O> {
     /// This function is useful for augmenting errors to allow them to display the span of the initial part of a
     /// pattern, for example to add a "while parsing" clause to your error messages.
     ///
+    /// Only the primary error is mapped: errors that the parser recovered from are left untouched, as with
+    /// [`Parser::map_err`].
+    ///
     /// The output type of this parser is `O`, the same as the original parser.
     ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let call = text::ident::<_, Simple<char>>()
+    ///     .then(text::int(10).padded().separated_by(just(',')).delimited_by(just('('), just(')')))
+    ///     .map_err_with_span(|e, span| e.with_label("function call").with_related_spans(vec![span]));
+    ///
+    /// let errors = call.parse("foo(1, 2").unwrap_err();
+    /// assert_eq!(errors[0].span(), 8..8); // The end of input
+    /// assert_eq!(errors[0].label(), Some("function call"));
+    /// // The span covers the call up to the point of the error
+    /// assert_eq!(errors[0].related_spans(), &[0..8]);
+    /// ```
     // TODO: Map E -> D, not E -> E
     fn map_err_with_span<F>(self, f: F) -> MapErrWithSpan<Self, F>
     where
@@ -376,12 +884,66 @@ pub trait Parser<I: Clone, O> {
         MapErrWithSpan(self, f)
     }
 
+    /// Replace all of the errors produced by this parser (both those it recovered from and, if it failed, its primary
+    /// error) with a single summary error.
+    ///
+    /// The function is given the errors, in the order in which they were produced, and the span of the input that the
+    /// parser covered. If the parser produced no errors, the function is not called.
+    ///
+    /// This is useful for collapsing noisy recovery within a large construct into a concise top-level diagnostic, such
+    /// as "this function contains 3 errors". Note that this is opt-in and *discards* the individual errors: if you wish
+    /// to keep some of their detail, attach it to the summary (for example, using [`Error::with_related_spans`]).
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// // Statements are integers, but we recover from identifiers by emitting an error
+    /// let ident = filter(|c: &char| c.is_alphabetic())
+    ///     .map(|_| "0".to_string())
+    ///     .validate(|out, span, emit| {
+    ///         emit(Simple::custom(span, "expected an integer"));
+    ///         out
+    ///     });
+    /// let stmt = text::int::<_, Simple<char>>(10)
+    ///     .or(ident)
+    ///     .then_ignore(just(';'))
+    ///     .padded();
+    /// let block = stmt
+    ///     .repeated()
+    ///     .delimited_by(just('{'), just('}'))
+    ///     .summarize_errors(|errors, span| {
+    ///         let related = errors.iter().map(|e| e.span()).collect::<Vec<_>>();
+    ///         Simple::custom(span, format!("this block contains {} errors", errors.len()))
+    ///             .with_related_spans(related)
+    ///     });
+    ///
+    /// let (out, errors) = block.parse_recovery("{ 1; x; 2; y; 3; }");
+    /// assert_eq!(out.map(|stmts| stmts.len()), Some(5));
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].to_string(), Simple::<char>::custom(0..18, "this block contains 2 errors").to_string());
+    /// assert_eq!(errors[0].related_spans(), &[5..6, 11..12]);
+    /// ```
+    fn summarize_errors<F>(self, f: F) -> SummarizeErrors<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Vec<Self::Error>, <Self::Error as Error<I>>::Span) -> Self::Error,
+    {
+        SummarizeErrors(self, f)
+    }
+
     /// After a successful parse, apply a fallible function to the output. If the function produces an error, treat it
     /// as a parsing error.
     ///
     /// If you wish parsing of this pattern to continue when an error is generated instead of halting, consider using
     /// [`Parser::validate`] instead.
     ///
+    /// The error is located at the start of the input matched by this parser, rather than at the end. This means that
+    /// when the error is weighed against those of other alternatives (such as with [`Parser::or`]), it is treated as
+    /// having occurred where the pattern began, so an alternative that made more progress takes priority.
+    ///
     /// The output type of this parser is `U`, the [`Ok`] return value of the function.
     ///
     /// # Examples
@@ -404,6 +966,93 @@ pub trait Parser<I: Clone, O> {
         TryMap(self, f, PhantomData)
     }
 
+    /// Like [`Parser::try_map`], but the function may also access and mutate a user-defined state that was attached
+    /// to the input with [`Stream::with_state`].
+    ///
+    /// This is useful for context-sensitive checks that must be performed during parsing, such as rejecting duplicate
+    /// definitions or undeclared names. The state type `St` must be `'static` (see [`Stream::with_state`]).
+    ///
+    /// The state is shared by all branches of the parser, including those that are later backtracked: any changes
+    /// made by a branch that ultimately fails to parse are *not* undone.
+    ///
+    /// # Panics
+    ///
+    /// Parsing will panic if no state was attached to the stream, or if the attached state is not of type `St`.
+    ///
+    /// The output type of this parser is `U`, the [`Ok`] return value of the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, Stream};
+    /// use std::collections::HashSet;
+    ///
+    /// let decl = text::keyword::<_, _, Simple<char>>("let")
+    ///     .ignore_then(text::ident().padded())
+    ///     .try_map_with_state(|name, span, declared: &mut HashSet<String>| {
+    ///         if declared.insert(name.clone()) {
+    ///             Ok(name)
+    ///         } else {
+    ///             Err(Simple::custom(span, format!("`{}` is already declared", name)))
+    ///         }
+    ///     })
+    ///     .then_ignore(just(';').padded());
+    ///
+    /// let mut declared = HashSet::<String>::new();
+    /// let decls = decl.repeated().then_ignore(end());
+    /// assert!(decls.parse(Stream::from("let x; let y;").with_state(&mut declared)).is_ok());
+    ///
+    /// let mut declared = HashSet::<String>::new();
+    /// assert!(decls.parse(Stream::from("let x; let x;").with_state(&mut declared)).is_err());
+    /// ```
+    fn try_map_with_state<U, St, F>(self, f: F) -> TryMapWithState<Self, F, O, St>
+    where
+        Self: Sized,
+        St: core::any::Any,
+        F: Fn(O, <Self::Error as Error<I>>::Span, &mut St) -> Result<U, Self::Error>,
+    {
+        TryMapWithState(self, f, PhantomData)
+    }
+
+    /// Like [`Parser::map`], but the function may also access and mutate a user-defined state that was attached to
+    /// the input with [`Stream::with_state`] or [`Parser::parse_recovery_with_state`].
+    ///
+    /// This is useful for performing side effects during parsing, such as interning identifiers into a symbol table.
+    /// The state type `St` must be `'static` (see [`Stream::with_state`]).
+    ///
+    /// The state is shared by all branches of the parser, including those that are later backtracked: any changes
+    /// made by a branch that ultimately fails to parse are *not* undone.
+    ///
+    /// # Panics
+    ///
+    /// Parsing will panic if no state was attached to the stream, or if the attached state is not of type `St`.
+    ///
+    /// The output type of this parser is `U`, the return value of the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, Stream};
+    /// let digit = filter::<_, _, Simple<char>>(char::is_ascii_digit)
+    ///     .map_with_state(|c, count: &mut usize| {
+    ///         *count += 1;
+    ///         c
+    ///     });
+    ///
+    /// let mut count = 0usize;
+    /// let digits = digit.repeated().collect::<String>();
+    /// assert_eq!(digits.parse(Stream::from("123").with_state(&mut count)), Ok("123".to_string()));
+    /// assert_eq!(count, 3);
+    /// ```
+    fn map_with_state<U, St, F>(self, f: F) -> MapWithState<Self, F, O, St>
+    where
+        Self: Sized,
+        St: core::any::Any,
+        F: Fn(O, &mut St) -> U,
+    {
+        MapWithState(self, f, PhantomData)
+    }
+
     /// Validate an output, producing non-terminal errors if it does not fulfil certain criteria.
     ///
     /// This function also permits mapping the output to a value of another type, similar to [`Parser::map`].
@@ -411,7 +1060,10 @@ pub trait Parser<I: Clone, O> {
     /// If you wish parsing of this pattern to halt when an error is generated instead of continuing, consider using
     /// [`Parser::try_map`] instead.
     ///
-    /// The output type of this parser is `O`, the same as the original parser.
+    /// Errors emitted by the function are reported alongside any other errors by [`Parser::parse_recovery`], while the
+    /// output of the function is still produced.
+    ///
+    /// The output type of this parser is `U`, the output of the function.
     ///
     /// # Examples
     ///
@@ -445,7 +1097,14 @@ pub trait Parser<I: Clone, O> {
     /// This does not label recovered errors generated by sub-patterns within the parser, only error *directly* emitted
     /// by the parser.
     ///
-    /// This does not label errors where the labelled pattern consumed input (i.e: in unambiguous cases).
+    /// The label is applied to the pattern's errors whether or not it consumed input before failing, so it survives
+    /// even when a long alternative fails part-way through and its error is the furthest one. When errors from
+    /// several alternatives are merged, the label of either is kept.
+    ///
+    /// When labelled patterns are nested, how the labels are combined is up to the error type (see
+    /// [`Error::in_context`]). [`Simple`](error::Simple) keeps only the innermost label, whereas
+    /// [`Rich`](error::Rich) keeps every label, allowing notes like "while parsing a function body" to be reported
+    /// alongside the more specific label.
     ///
     /// The output type of this parser is `O`, the same as the original parser.
     ///
@@ -537,6 +1196,11 @@ pub trait Parser<I: Clone, O> {
     /// The output of the original parser must be of type `(impl IntoIterator<Item = A>, B)`. Because right-folds work
     /// backwards, the iterator must implement [`DoubleEndedIterator`] so that it can be reversed.
     ///
+    /// Reversing the iterator is free: when the output is a [`Vec`] (such as that produced by [`Parser::repeated`]),
+    /// its elements are taken from the back of the vector in place, without reallocating or copying it. This makes
+    /// right-folds the preferred way to build deeply right-associative structures, since they avoid the deep
+    /// recursion that an equivalent [`recursive`] grammar would require.
+    ///
     /// The output type of this parser is `B`, the right-hand component of the original parser's output.
     ///
     /// # Examples
@@ -560,11 +1224,179 @@ pub trait Parser<I: Clone, O> {
     fn foldr<'a, A, B, F>(self, f: F) -> Foldr<Self, F, A, B>
     where
         Self: Parser<I, (A, B)> + Sized,
-        A: IntoIterator,
+        A: IntoIterator,
+        A::IntoIter: DoubleEndedIterator,
+        F: Fn(A::Item, B) -> B + 'a,
+    {
+        Foldr(self, f, PhantomData)
+    }
+
+    /// Left-fold the output of the parser into a single value, using the first item as the initial value.
+    ///
+    /// This is like [`Parser::foldl`], but for parsers that output a single sequence of items, such as
+    /// [`Parser::separated_by`], where every item is of the same type. `[a, b, c]` is reduced to `f(f(a, b), c)`, and a
+    /// sequence of one item is left as it is.
+    ///
+    /// If the sequence is empty, there is nothing to reduce and a [`Error::custom`] error is produced, spanning the
+    /// input consumed by the original parser. Use [`Repeated::at_least`] (or similar) to rule this out
+    /// ahead of time.
+    ///
+    /// The output type of this parser is `O::Item`, the type of the items in the original parser's output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Debug, PartialEq)]
+    /// enum Pat {
+    ///     Ident(String),
+    ///     Or(Box<Pat>, Box<Pat>),
+    /// }
+    ///
+    /// let pat = text::ident::<_, Simple<char>>()
+    ///     .map(Pat::Ident)
+    ///     .padded()
+    ///     .separated_by(just('|'))
+    ///     .reduce(|a, b| Pat::Or(Box::new(a), Box::new(b)));
+    ///
+    /// assert_eq!(pat.parse("a"), Ok(Pat::Ident("a".to_string())));
+    /// assert_eq!(
+    ///     pat.parse("a | b | c"),
+    ///     Ok(Pat::Or(
+    ///         Box::new(Pat::Or(
+    ///             Box::new(Pat::Ident("a".to_string())),
+    ///             Box::new(Pat::Ident("b".to_string())),
+    ///         )),
+    ///         Box::new(Pat::Ident("c".to_string())),
+    ///     )),
+    /// );
+    /// // Nothing to reduce
+    /// assert!(pat.parse("").is_err());
+    /// ```
+    fn reduce<F>(self, f: F) -> Reduce<Self, F, O>
+    where
+        Self: Sized,
+        O: IntoIterator,
+        F: Fn(O::Item, O::Item) -> O::Item,
+    {
+        Reduce(self, f, PhantomData)
+    }
+
+    /// Fold the output of the parser into a single value, starting from the given initial value.
+    ///
+    /// This is like [`Iterator::fold`], and is useful for aggregating the items of a sequence, such as summing numbers
+    /// or combining flags into a bitmask. Unlike [`Parser::foldl`], the original parser need only output a sequence of
+    /// items, rather than a `(head, tail)` pair. The initial value is cloned for every parse.
+    ///
+    /// When applied directly to [`Parser::repeated`], [`Repeated::fold`] is used instead, which folds each item as
+    /// soon as it is parsed so that no [`Vec`] is allocated.
+    ///
+    /// The output type of this parser is `Acc`, the type of the accumulator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let sum = text::int::<_, Simple<char>>(10)
+    ///     .from_str::<u32>()
+    ///     .unwrapped()
+    ///     .separated_by(just('+'))
+    ///     .fold(0, |a, b| a + b);
+    ///
+    /// assert_eq!(sum.parse("1+12+3"), Ok(16));
+    /// assert_eq!(sum.parse(""), Ok(0));
+    /// ```
+    fn fold<Acc, F>(self, init: Acc, f: F) -> Fold<Self, Acc, F, O>
+    where
+        Self: Sized,
+        O: IntoIterator,
+        Acc: Clone,
+        F: Fn(Acc, O::Item) -> Acc,
+    {
+        Fold(self, init, f, PhantomData)
+    }
+
+    /// Left-fold the output of the parser into a single value, like [`Parser::foldl`], giving the function the span of
+    /// each intermediate value.
+    ///
+    /// The right-hand component of the original parser's output must be an iterator of [`Spanned`] values, such as
+    /// those produced by [`Parser::spanned_map`]. The span given to the function covers everything from the start of
+    /// the pattern to the end of the value being folded in, so it grows to encompass each operand as the fold proceeds.
+    /// This is useful for attaching spans to the nodes of left-associative syntax trees.
+    ///
+    /// The output type of this parser is `A`, the left-hand component of the original parser's output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Debug, PartialEq)]
+    /// enum Expr {
+    ///     Num(u64),
+    ///     Sub(Box<Expr>, Box<Expr>, std::ops::Range<usize>),
+    /// }
+    ///
+    /// let num = text::int::<_, Simple<char>>(10).map(|s: String| Expr::Num(s.parse().unwrap()));
+    /// let sub = num
+    ///     .then(just('-').ignore_then(num).spanned_map(|rhs| rhs).repeated())
+    ///     .foldl_with_span(|lhs, rhs, span| Expr::Sub(Box::new(lhs), Box::new(rhs), span));
+    ///
+    /// use Expr::*;
+    /// assert_eq!(
+    ///     sub.parse("3-2-1"),
+    ///     Ok(Sub(Box::new(Sub(Box::new(Num(3)), Box::new(Num(2)), 0..3)), Box::new(Num(1)), 0..5)),
+    /// );
+    /// ```
+    fn foldl_with_span<A, B, T, F>(self, f: F) -> FoldlWithSpan<Self, F, A, B>
+    where
+        Self: Parser<I, (A, B)> + Sized,
+        B: IntoIterator<
+            Item = span::Spanned<T, <<Self as Parser<I, (A, B)>>::Error as Error<I>>::Span>,
+        >,
+        F: Fn(A, T, <<Self as Parser<I, (A, B)>>::Error as Error<I>>::Span) -> A,
+    {
+        FoldlWithSpan(self, f, PhantomData)
+    }
+
+    /// Right-fold the output of the parser into a single value, like [`Parser::foldr`], giving the function the span
+    /// of each intermediate value.
+    ///
+    /// The left-hand component of the original parser's output must be an iterator of [`Spanned`] values, such as those
+    /// produced by [`Parser::spanned_map`]. The span given to the function covers everything from the start of the
+    /// value being folded in to the end of the pattern.
+    ///
+    /// The output type of this parser is `B`, the right-hand component of the original parser's output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Debug, PartialEq)]
+    /// enum Expr {
+    ///     Num(u64),
+    ///     Neg(Box<Expr>, std::ops::Range<usize>),
+    /// }
+    ///
+    /// let num = text::int::<_, Simple<char>>(10).map(|s: String| Expr::Num(s.parse().unwrap()));
+    /// let neg = just('-')
+    ///     .spanned_map(|op| op)
+    ///     .repeated()
+    ///     .then(num)
+    ///     .foldr_with_span(|_, expr, span| Expr::Neg(Box::new(expr), span));
+    ///
+    /// use Expr::*;
+    /// assert_eq!(neg.parse("--1"), Ok(Neg(Box::new(Neg(Box::new(Num(1)), 1..3)), 0..3)));
+    /// ```
+    fn foldr_with_span<A, B, T, F>(self, f: F) -> FoldrWithSpan<Self, F, A, B>
+    where
+        Self: Parser<I, (A, B)> + Sized,
+        A: IntoIterator<
+            Item = span::Spanned<T, <<Self as Parser<I, (A, B)>>::Error as Error<I>>::Span>,
+        >,
         A::IntoIter: DoubleEndedIterator,
-        F: Fn(A::Item, B) -> B + 'a,
+        F: Fn(T, B, <<Self as Parser<I, (A, B)>>::Error as Error<I>>::Span) -> B,
     {
-        Foldr(self, f, PhantomData)
+        FoldrWithSpan(self, f, PhantomData)
     }
 
     /// Ignore the output of this parser, yielding `()` as an output instead.
@@ -600,6 +1432,9 @@ pub trait Parser<I: Clone, O> {
     /// This is commonly useful for collecting [`Vec<char>`] outputs into [`String`]s, or [`(T, U)`] into a
     /// [`HashMap`] and is analogous to [`Iterator::collect`].
     ///
+    /// The output of the original parser is collected only once it has been produced in full. When collecting the
+    /// outputs of [`Parser::repeated`], [`Repeated::collect_direct`] avoids building an intermediate [`Vec`].
+    ///
     /// The output type of this parser is `C`, the type being collected into.
     ///
     /// # Examples
@@ -622,6 +1457,53 @@ pub trait Parser<I: Clone, O> {
         self.map(|items| C::from_iter(items.into_iter()))
     }
 
+    /// Collect spanned key-value pairs into a [`HashMap`], keeping the span of each entry alongside its value.
+    ///
+    /// This is useful for struct or object literals, where later semantic errors (such as "field `x` defined here")
+    /// need to point at individual entries. Each item's span usually comes from [`Parser::map_with_span`] with
+    /// [`Spanned::new`](span::Spanned::new).
+    ///
+    /// If a key appears more than once, the first entry is kept and `duplicate` is called with the key, the span of
+    /// the first entry and the span of the duplicate. The error it produces is emitted as a non-terminal error (see
+    /// [`Parser::validate`]), so parsing continues.
+    ///
+    /// The output type of this parser is `HashMap<K, (V, Span)>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, span::Spanned};
+    /// let field = text::ident::<_, Simple<char>>()
+    ///     .then_ignore(just(':').padded())
+    ///     .then(text::int(10))
+    ///     .map_with_span(Spanned::new)
+    ///     .padded();
+    ///
+    /// let object = field
+    ///     .separated_by(just(','))
+    ///     .collect_map_spanned(|key, _first, span| {
+    ///         Simple::custom(span, format!("field `{}` is defined more than once", key))
+    ///     })
+    ///     .delimited_by(just('{'), just('}'));
+    ///
+    /// let fields = object.parse("{ x: 1, y: 2 }").unwrap();
+    /// assert_eq!(fields["y"], ("2".to_string(), 8..12));
+    ///
+    /// let (fields, errors) = object.parse_recovery("{x:1,x:2}");
+    /// assert_eq!(fields.unwrap()["x"], ("1".to_string(), 1..4));
+    /// assert_eq!(errors[0].span(), 5..8);
+    /// ```
+    #[cfg(feature = "std")]
+    fn collect_map_spanned<K, V, F>(self, duplicate: F) -> CollectMapSpanned<Self, F, O>
+    where
+        Self: Sized,
+        O: IntoIterator<Item = Spanned<(K, V), <Self::Error as Error<I>>::Span>>,
+        K: Eq + std::hash::Hash,
+        F: Fn(&K, <Self::Error as Error<I>>::Span, <Self::Error as Error<I>>::Span) -> Self::Error,
+    {
+        CollectMapSpanned(self, duplicate, PhantomData)
+    }
+
     /// Parse one thing and then another thing, yielding a tuple of the two outputs.
     ///
     /// The output type of this parser is `(O, U)`, a combination of the outputs of both parsers.
@@ -646,6 +1528,95 @@ pub trait Parser<I: Clone, O> {
         Then(self, other)
     }
 
+    /// Parse one thing and then another thing, like [`Parser::then`], recording that an error produced by the second
+    /// parser before it consumed any input occurred *after* the first pattern (see [`Error::with_after`]).
+    ///
+    /// This is intended for tooling such as code completion: if the input is truncated at the cursor, the error at the
+    /// end of the input tells you both what may come next (its expected inputs) and which structure was just
+    /// completed, such as "after `fn name`, `(` is expected". Errors produced after the second pattern has consumed
+    /// input are not labelled, since they belong to a more deeply nested sequence.
+    ///
+    /// The output type of this parser is `(O, U)`, a combination of the outputs of both parsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let ident = text::ident::<_, Simple<char>>().padded();
+    /// let func = text::keyword("fn")
+    ///     .ignore_then(ident)
+    ///     .then_after(
+    ///         ident.separated_by(just(',')).delimited_by(just('('), just(')')),
+    ///         "fn name",
+    ///     )
+    ///     .then_after(just("{}").padded(), "fn signature");
+    ///
+    /// // The cursor is at the end of the input
+    /// let err = func.parse("fn foo").unwrap_err().remove(0);
+    /// assert_eq!(err.after(), Some("fn name"));
+    /// assert!(err.expected().any(|e| *e == Some('(')));
+    ///
+    /// let err = func.parse("fn foo(a, b)").unwrap_err().remove(0);
+    /// assert_eq!(err.after(), Some("fn signature"));
+    ///
+    /// // The error occurred within the arguments, not immediately after the name
+    /// let err = func.parse("fn foo(a,").unwrap_err().remove(0);
+    /// assert_eq!(err.after(), None);
+    /// ```
+    fn then_after<U, P, L>(self, other: P, label: L) -> ThenAfter<Self, P, L>
+    where
+        Self: Sized,
+        P: Parser<I, U, Error = Self::Error>,
+        L: Into<<Self::Error as Error<I>>::Label> + Clone,
+    {
+        ThenAfter(self, other, label)
+    }
+
+    /// Parse one thing and then, only if the following input is immediately adjacent to it (i.e: there is no gap
+    /// between the end of the first pattern's span and the start of the next input's span), attempt to parse another
+    /// thing.
+    ///
+    /// If the following input is not adjacent, or the second parser fails without consuming any input, the second
+    /// pattern is not parsed and no input is consumed by it. If the second parser fails after consuming input, the
+    /// adjacent input was evidently meant to be part of it, so its error is produced instead.
+    ///
+    /// This is useful for languages in which whitespace changes the meaning of the grammar. For example, `foo()` might
+    /// be a call while `foo ()` is an identifier followed by a parenthesised expression. Note that this relies on the
+    /// spans of the input: it's most useful when parsing tokens that have been lexed with whitespace removed.
+    ///
+    /// The output type of this parser is `(O, Option<U>)`, a combination of the outputs of both parsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, Stream};
+    /// #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    /// enum Token { Ident(&'static str), Open, Close }
+    ///
+    /// let call = select! { Token::Ident(name) => name }
+    ///     .then_if_adjacent(just::<_, _, Simple<Token>>(Token::Open).then(just(Token::Close)));
+    ///
+    /// // `foo()`
+    /// let tokens = vec![(Token::Ident("foo"), 0..3), (Token::Open, 3..4), (Token::Close, 4..5)];
+    /// assert_eq!(call.parse(Stream::from_iter(5..5, tokens.into_iter())), Ok(("foo", Some((Token::Open, Token::Close)))));
+    ///
+    /// // `foo ()`
+    /// let tokens = vec![(Token::Ident("foo"), 0..3), (Token::Open, 4..5), (Token::Close, 5..6)];
+    /// assert_eq!(call.parse(Stream::from_iter(6..6, tokens.into_iter())).unwrap(), ("foo", None));
+    ///
+    /// // `foo(`
+    /// let tokens = vec![(Token::Ident("foo"), 0..3), (Token::Open, 3..4)];
+    /// assert_eq!(call.parse(Stream::from_iter(4..4, tokens.into_iter())).unwrap_err()[0].span(), 4..4);
+    /// ```
+    fn then_if_adjacent<U, P>(self, other: P) -> ThenIfAdjacent<Self, P>
+    where
+        Self: Sized,
+        P: Parser<I, U, Error = Self::Error>,
+        <<Self::Error as Error<I>>::Span as Span>::Offset: PartialEq,
+    {
+        ThenIfAdjacent(self, other)
+    }
+
     /// Parse one thing and then another thing, creating the second parser from the result of
     /// the first. If you only have a couple cases to handle, prefer [`Parser::or`].
     ///
@@ -667,6 +1638,19 @@ pub trait Parser<I: Clone, O> {
     /// assert_eq!(successive_letters.parse(*b"ab"), Ok(b'b')); // 'b' follows 'a'
     /// assert!(successive_letters.parse(*b"ac").is_err()); // 'c' does not follow 'a'
     /// ```
+    ///
+    /// Length-prefixed data, such as Pascal strings or binary TLV records, is a common use:
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::Cheap};
+    /// // A byte giving the length of the string, followed by that many bytes
+    /// let pascal_string = any::<u8, Cheap<u8>>()
+    ///     .then_with(|len| any().repeated().exactly(len as usize));
+    ///
+    /// assert_eq!(pascal_string.parse(*b"\x03abc"), Ok(b"abc".to_vec()));
+    /// assert!(pascal_string.then_ignore(end()).parse(*b"\x02abc").is_err()); // Trailing input
+    /// assert!(pascal_string.parse(*b"\x04abc").is_err()); // Too short
+    /// ```
     fn then_with<U, P, F: Fn(O) -> P>(self, other: F) -> ThenWith<I, O, U, Self, P, F>
     where
         Self: Sized,
@@ -675,6 +1659,106 @@ pub trait Parser<I: Clone, O> {
         ThenWith(self, other, PhantomData)
     }
 
+    /// Parse one thing and then another thing, creating the second parser from the result of the first and the span
+    /// of the input that the first parser consumed.
+    ///
+    /// This is like [`Parser::then_with`], but gives the second parser access to positional information. This is
+    /// useful for context-sensitive syntax such as alignment checks, where what comes next depends on *where* the
+    /// first part was found rather than just on what it was.
+    ///
+    /// The second parser runs on the same input as the first, so any errors it produces are located as normal.
+    ///
+    /// The output of this parser is `U`, the result of the second parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// // An indented `-` marker, followed by a continuation line that must be aligned with the marker
+    /// let item = just::<_, _, Simple<char>>(' ')
+    ///     .repeated()
+    ///     .ignore_then(just('-'))
+    ///     .then_with_span(|_, span| {
+    ///         let column = span.end - 1;
+    ///         just('\n')
+    ///             .ignore_then(just(' ').repeated().exactly(column))
+    ///             .ignore_then(text::ident())
+    ///     });
+    ///
+    /// assert_eq!(item.parse("  -\n  foo"), Ok("foo".to_string()));
+    /// assert!(item.parse("  -\n foo").is_err()); // Not indented enough
+    /// assert!(item.parse("  -\n   foo").is_err()); // Indented too far
+    /// ```
+    fn then_with_span<U, P, F>(self, other: F) -> ThenWithSpan<I, O, U, Self, P, F>
+    where
+        Self: Sized,
+        P: Parser<I, U, Error = Self::Error>,
+        F: Fn(O, <Self::Error as Error<I>>::Span) -> P,
+    {
+        ThenWithSpan(self, other, PhantomData)
+    }
+
+    /// Parse one thing and then another thing, creating the second parser from a reference to the output of the first,
+    /// and yielding both outputs.
+    ///
+    /// This is like [`Parser::then_with`], but the first output is borrowed rather than consumed, so it is still
+    /// available afterwards. This makes it a good fit for constructs where a closing token must repeat an earlier one,
+    /// such as the terminator of a here-document or the name in an XML closing tag.
+    ///
+    /// Grammars like this are context-sensitive: what may appear later in the input depends on the *value* of earlier
+    /// input, which is beyond what LL(1) (or any context-free) grammar can express. Be aware that error recovery
+    /// degrades accordingly. If the first parser only succeeds thanks to error recovery, the second parser is built
+    /// from a recovered (and likely wrong) value, and any errors it then produces point into the second parser rather
+    /// than at the root cause. Similarly, a mismatched closing token is reported as expecting the exact opening value.
+    ///
+    /// The output type of this parser is `(O, U)`, a combination of the outputs of both parsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let open = text::ident::<_, Simple<char>>().delimited_by(just('<'), just('>'));
+    /// let element = open.then_ctx(|name: &String| {
+    ///     none_of('<')
+    ///         .repeated()
+    ///         .collect::<String>()
+    ///         .then_ignore(just("</").then(just(name.clone())).then(just('>')))
+    /// });
+    ///
+    /// assert_eq!(
+    ///     element.parse("<b>bold</b>"),
+    ///     Ok(("b".to_string(), "bold".to_string())),
+    /// );
+    /// // The closing tag does not match the opening tag
+    /// assert!(element.parse("<b>bold</i>").is_err());
+    /// ```
+    ///
+    /// Here-documents, terminated by a line containing only the name given at the start:
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let heredoc = just::<_, _, Simple<char>>("<<")
+    ///     .ignore_then(text::ident())
+    ///     .then_ignore(text::newline())
+    ///     .then_ctx(|name: &String| {
+    ///         let terminator = just(name.clone()).then(text::newline().or(end()));
+    ///         take_until(terminator).map(|(body, _)| body.into_iter().collect::<String>())
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     heredoc.parse("<<EOF\nhello\nEOF is here\nEOF\n"),
+    ///     Ok(("EOF".to_string(), "hello\nEOF is here\n".to_string())),
+    /// );
+    /// ```
+    fn then_ctx<U, P, F>(self, other: F) -> ThenCtx<I, O, U, Self, P, F>
+    where
+        Self: Sized,
+        P: Parser<I, U, Error = Self::Error>,
+        F: Fn(&O) -> P,
+    {
+        ThenCtx(self, other, PhantomData)
+    }
+
     /// Parse one thing and then another thing, attempting to chain the two outputs into a [`Vec`].
     ///
     /// The output type of this parser is `Vec<T>`, composed of the elements of the outputs of both parsers.
@@ -698,6 +1782,20 @@ pub trait Parser<I: Clone, O> {
     /// assert!(int.parse("-0").is_err());
     /// assert!(int.parse("05").is_err());
     /// ```
+    ///
+    /// Optional outputs (i.e: `Option<T>`) contribute either zero or one element to the chain, which makes optional
+    /// prefixes such as signs easy to express:
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let signed = just::<_, _, Simple<char>>('-')
+    ///     .or_not()
+    ///     .chain::<char, _, _>(text::digits(10))
+    ///     .collect::<String>();
+    ///
+    /// assert_eq!(signed.parse("42"), Ok("42".to_string()));
+    /// assert_eq!(signed.parse("-42"), Ok("-42".to_string()));
+    /// ```
     fn chain<T, U, P>(self, other: P) -> Map<Then<Self, P>, fn((O, U)) -> Vec<T>, (O, U)>
     where
         Self: Sized,
@@ -728,6 +1826,42 @@ pub trait Parser<I: Clone, O> {
         self.map(|xs| xs.into_iter().flat_map(|xs| xs.into_iter()).collect())
     }
 
+    /// Map each item of this parser's output (such as the outputs of a [`Parser::repeated`] parser) to zero or more
+    /// values, concatenating the results.
+    ///
+    /// This is similar to [`Iterator::flat_map`], and is useful for transformations that expand or remove tokens, such
+    /// as expanding a macro into its definition. Unlike [`Parser::then_with`], the function does not produce a
+    /// parser: it only transforms outputs that have already been parsed. Unlike [`Parser::flatten`], the items of the
+    /// output do not need to already be collections.
+    ///
+    /// The output type of this parser is `Vec<U>`, where the original parser output was
+    /// `impl IntoIterator<Item = T>` and the function returns `impl IntoIterator<Item = U>` for each `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// // `!` is shorthand for `abc`, and `#` is ignored
+    /// let letters = one_of::<_, _, Simple<char>>("abc!#")
+    ///     .repeated()
+    ///     .flat_map(|c| match c {
+    ///         '!' => vec!['a', 'b', 'c'],
+    ///         '#' => vec![],
+    ///         c => vec![c],
+    ///     });
+    ///
+    /// assert_eq!(letters.parse("a!#c"), Ok(vec!['a', 'a', 'b', 'c', 'c']));
+    /// ```
+    fn flat_map<U, Iter, F>(self, f: F) -> FlatMap<Self, F, O>
+    where
+        Self: Sized,
+        O: IntoIterator,
+        Iter: IntoIterator<Item = U>,
+        F: Fn(O::Item) -> Iter,
+    {
+        FlatMap(self, f, PhantomData)
+    }
+
     /// Parse one thing and then another thing, yielding only the output of the latter.
     ///
     /// The output type of this parser is `U`, the same as the second parser.
@@ -816,6 +1950,63 @@ pub trait Parser<I: Clone, O> {
         other.clone().ignore_then(self).then_ignore(other)
     }
 
+    /// Parse a pattern with leading and trailing trivia (such as whitespace and comments), yielding the outputs of all
+    /// three.
+    ///
+    /// Unlike [`TextParser::padded`](text::TextParser::padded) and [`Parser::padded_by`], which discard the
+    /// surrounding input, this retains it so that the original source can be reconstructed exactly from the syntax
+    /// tree (as is needed by formatters and refactoring tools). Use [`Parser::map_with_span`] on the trivia parsers to
+    /// capture their spans, or have them collect their text.
+    ///
+    /// When trivia appears between two nodes, something must decide which node owns it. The usual rule (and the one
+    /// that makes the most sense for comments) is that trailing trivia extends only to the end of the current line,
+    /// and everything after that is leading trivia of the following node. Because parsing is greedy, following this
+    /// rule is simply a matter of writing a `trailing` parser that stops at the first newline.
+    ///
+    /// The output type of this parser is `(LO, O, TO)`, the outputs of the leading trivia, the original parser and the
+    /// trailing trivia respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let comment = just::<_, _, Simple<char>>("//").then(take_until(just('\n').rewind()));
+    ///
+    /// // Leading trivia: any whitespace and comments, including newlines
+    /// let leading = comment
+    ///     .ignored()
+    ///     .or(filter(|c: &char| c.is_whitespace()).ignored())
+    ///     .repeated()
+    ///     .map_with_span(|_, span| span);
+    /// // Trailing trivia: whitespace and comments up to (and including) the end of the line
+    /// let trailing = comment
+    ///     .ignored()
+    ///     .or(one_of(" \t").ignored())
+    ///     .repeated()
+    ///     .then(just('\n').or_not())
+    ///     .map_with_span(|_, span| span);
+    ///
+    /// let stmt = text::ident().then_ignore(just(';')).with_trivia(leading, trailing);
+    ///
+    /// let src = "// first\nfoo; // trailing\n\n  bar;";
+    /// let stmts = stmt.repeated().then_ignore(end()).parse(src).unwrap();
+    /// assert_eq!(stmts[0], (0..9, "foo".to_string(), 13..26));
+    /// // The blank line and indentation are owned by the following statement
+    /// assert_eq!(stmts[1], (26..29, "bar".to_string(), 33..33));
+    /// ```
+    fn with_trivia<LO, TO, L, T>(self, leading: L, trailing: T) -> WithTrivia<Self, L, T, O, LO, TO>
+    where
+        Self: Sized,
+        L: Parser<I, LO, Error = Self::Error>,
+        T: Parser<I, TO, Error = Self::Error>,
+    {
+        Map(
+            Then(Then(leading, self), trailing),
+            |((l, o), t)| (l, o, t),
+            PhantomData,
+        )
+    }
+
     /// Parse the pattern surrounded by the given delimiters.
     ///
     /// The output type of this parser is `O`, the same as the original parser.
@@ -879,6 +2070,48 @@ pub trait Parser<I: Clone, O> {
         }
     }
 
+    /// Like [`Parser::delimited_by`], but distinguishes genuinely empty content (where the end delimiter immediately
+    /// follows the start delimiter) from content parsed by this parser.
+    ///
+    /// If the content is empty, this parser is not invoked at all, so it need not accept empty input. If the content
+    /// is not empty but fails to parse, the resulting error reports both this parser and the end delimiter as being
+    /// expected (e.g: "expected `x` or `)`").
+    ///
+    /// The output type of this parser is `Option<O>`: `None` if the content was empty, `Some` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let args = text::int::<_, Simple<char>>(10)
+    ///     .separated_by(just(','))
+    ///     .at_least(1)
+    ///     .delimited_by_or_empty(just('('), just(')'));
+    ///
+    /// // A call with no arguments
+    /// assert_eq!(args.parse("()"), Ok(None));
+    /// assert_eq!(args.parse("(1,2)"), Ok(Some(vec!["1".to_string(), "2".to_string()])));
+    /// // A missing argument is an error
+    /// assert!(args.parse("(,)").is_err());
+    /// ```
+    fn delimited_by_or_empty<U, V, L, R>(
+        self,
+        start: L,
+        end: R,
+    ) -> DelimitedByOrEmpty<Self, L, R, U, V>
+    where
+        Self: Sized,
+        L: Parser<I, U, Error = Self::Error>,
+        R: Parser<I, V, Error = Self::Error>,
+    {
+        DelimitedByOrEmpty {
+            item: self,
+            start,
+            end,
+            phantom: PhantomData,
+        }
+    }
+
     /// Parse one thing or, on failure, another thing.
     ///
     /// The output of both parsers must be of the same type, because either output can be produced.
@@ -891,6 +2124,10 @@ pub trait Parser<I: Clone, O> {
     /// used is left unspecified, and is not part of the crate's semver guarantees, although regressions in error
     /// quality should be reported in the issue tracker of the main repository.
     ///
+    /// If either parser recovers from errors (see [`Parser::recover_with`]), only the recovered errors of the branch
+    /// that is ultimately chosen are kept: errors produced by a rejected branch are discarded. A branch that succeeds
+    /// without recovering from any errors is preferred over one that required recovery.
+    ///
     /// Please note that long chains of [`Parser::or`] combinators have been known to result in poor compilation times.
     /// If you feel you are experiencing this, consider using [`choice`] instead.
     ///
@@ -917,12 +2154,50 @@ pub trait Parser<I: Clone, O> {
         Or(self, other)
     }
 
+    /// Parse one thing or, on failure, another thing, exactly like [`Parser::or`], but warn when both branches would
+    /// match at the same position.
+    ///
+    /// Because chumsky always takes the first branch that matches, an alternative that also matches is silently
+    /// shadowed. This is often a grammar bug (for example, `just("a").or(just("ab"))` can never produce `"ab"`). When
+    /// parsing with [`Parser::parse_recovery_verbose`], this combinator speculatively tries both branches (rewinding
+    /// the input afterwards, so the output is unaffected) and emits a warning containing the input position and the
+    /// number of inputs each branch would have consumed.
+    ///
+    /// This is purely a development aid: the check is only performed by [`Parser::parse_recovery_verbose`] in builds
+    /// with debug assertions enabled, so in all other cases this combinator behaves (and performs) exactly like
+    /// [`Parser::or`].
+    ///
+    /// The output type of this parser is `O`, the output of both parsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let keyword = text::keyword::<_, _, Simple<char>>("let").to("keyword");
+    /// let ident = text::ident().to("ident");
+    ///
+    /// // `let` is also a valid identifier, so this prints a warning
+    /// let token = keyword.or_warn_ambiguous(ident);
+    /// assert_eq!(token.parse_recovery_verbose("let"), (Some("keyword"), Vec::new()));
+    /// ```
+    fn or_warn_ambiguous<P>(self, other: P) -> OrWarnAmbiguous<Self, P>
+    where
+        Self: Sized,
+        P: Parser<I, O, Error = Self::Error>,
+    {
+        OrWarnAmbiguous(self, other)
+    }
+
     /// Apply a fallback recovery strategy to this parser should it fail.
     ///
     /// There is no silver bullet for error recovery, so this function allows you to specify one of several different
     /// strategies at the location of your choice. Prefer an error recovery strategy that more precisely mirrors valid
     /// syntax where possible to make error recovery more reliable.
     ///
+    /// Several strategies may be given as a tuple, such as `(nested_delimiters(...), skip_until(...))`. Each strategy
+    /// is attempted in turn, starting from the position at which the pattern failed, until one of them recovers. This
+    /// allows a precise strategy to be tried first, with a cruder one as a last resort.
+    ///
     /// Because chumsky is a [PEG](https://en.m.wikipedia.org/wiki/Parsing_expression_grammar) parser, which always
     /// take the first successful parsing route through a grammar, recovering from an error may cause the parser to
     /// erroneously miss alternative valid routes through the grammar that do not generate recoverable errors. If you
@@ -969,6 +2244,65 @@ pub trait Parser<I: Clone, O> {
         Recovery(self, strategy)
     }
 
+    /// Parse a pattern any number of times (including zero times), like [`Parser::repeated`], but recover from
+    /// failures of individual items with the given recovery strategy so that the items that follow are still parsed.
+    ///
+    /// Using [`Parser::recover_with`] on the items of a repetition has a problem: the repetition ends when an item
+    /// fails to parse, so the strategy is also used at the end of the repetition (such as at the closing delimiter of a
+    /// block) where it will usually skip input that should have been left for whatever follows. This combinator only
+    /// uses the strategy for items that fail *after* the position at which they started: an item that fails without
+    /// getting past its first token ends the repetition as normal. The errors that are recovered from are emitted, and
+    /// the output contains the fallback value produced by the strategy in place of each item that failed.
+    ///
+    /// Note that an item is considered to have started as soon as it has consumed any input, including whitespace or
+    /// other padding, so any padding before the end of the repetition must already have been consumed when the final
+    /// attempt is made. With [`TextParser::padded`](text::TextParser::padded), the trailing padding of each item
+    /// takes care of this (which is why its leading padding is harmless), but the pattern that precedes the
+    /// repetition must also consume its own trailing padding in case there are no items, as the opening delimiter does
+    /// in the example below.
+    ///
+    /// The output type of this parser is `Vec<O>`, where each item comes from the original parser or the strategy.
+    /// Like [`Parser::repeated`], the number of items can be constrained with [`Repeated::at_least`],
+    /// [`Repeated::at_most`] and [`Repeated::exactly`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Debug, PartialEq)]
+    /// enum Stmt {
+    ///     Expr(String),
+    ///     Error,
+    /// }
+    ///
+    /// let stmt = text::ident::<_, Simple<char>>()
+    ///     .map(Stmt::Expr)
+    ///     .then_ignore(just(';'))
+    ///     .padded();
+    ///
+    /// // A broken statement doesn't prevent the rest of the block from being parsed
+    /// let block = stmt
+    ///     .repeated_recovering(skip_until([';'], |_| Stmt::Error).consume_end())
+    ///     .delimited_by(just('{').padded(), just('}'));
+    ///
+    /// assert_eq!(block.parse_recovery("{ }"), (Some(Vec::new()), Vec::new()));
+    ///
+    /// let (stmts, errors) = block.parse_recovery("{ a; b c; d; }");
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(stmts, Some(vec![
+    ///     Stmt::Expr("a".to_string()),
+    ///     Stmt::Error,
+    ///     Stmt::Expr("d".to_string()),
+    /// ]));
+    /// ```
+    fn repeated_recovering<S>(self, strategy: S) -> Repeated<RecoverStarted<Self, S>>
+    where
+        Self: Sized,
+        S: Strategy<I, O, Self::Error>,
+    {
+        Repeated(RecoverStarted(self, strategy), 0, None)
+    }
+
     /// Attempt to parse something, but only if it exists.
     ///
     /// If parsing of the pattern is successful, the output is `Some(_)`. Otherwise, the output is `None`.
@@ -998,6 +2332,9 @@ pub trait Parser<I: Clone, O> {
 
     /// Parses a single token if, and only if, the pattern fails to parse.
     ///
+    /// If you want to check that the pattern does not parse *without* consuming any input, use
+    /// [`Parser::not_ahead`].
+    ///
     /// The output type of this parser is `I`.
     ///
     /// # Examples
@@ -1047,40 +2384,106 @@ pub trait Parser<I: Clone, O> {
     ///     ])),
     /// );
     /// ```
-    fn not(self) -> Not<Self, O>
+    fn not(self) -> Not<Self, O>
+    where
+        Self: Sized,
+    {
+        Not(self, PhantomData)
+    }
+
+    /// Parse a pattern any number of times (including zero times).
+    ///
+    /// Input is eagerly parsed. Be aware that the parser will accept no occurrences of the pattern too. Consider using
+    /// [`Repeated::at_least`] instead if it better suits your use-case.
+    ///
+    /// The output type of this parser is `Vec<O>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::Cheap};
+    /// let num = filter::<_, _, Cheap<char>>(|c: &char| c.is_ascii_digit())
+    ///     .repeated().at_least(1)
+    ///     .collect::<String>()
+    ///     .from_str()
+    ///     .unwrapped();
+    ///
+    /// let sum = num.then(just('+').ignore_then(num).repeated())
+    ///     .foldl(|a, b| a + b);
+    ///
+    /// assert_eq!(sum.parse("2+13+4+0+5"), Ok(24));
+    /// ```
+    fn repeated(self) -> Repeated<Self>
+    where
+        Self: Sized,
+    {
+        Repeated(self, 0, None)
+    }
+
+    /// Parse a pattern any number of times (including zero times), like [`Parser::repeated`], but output only the
+    /// number of times that the pattern was parsed rather than collecting the outputs.
+    ///
+    /// No collection is allocated, making this useful when only the number of repetitions matters. To bound the number
+    /// of repetitions, use [`Repeated::counted`] instead (i.e: `.repeated().at_least(1).counted()`).
+    ///
+    /// The output type of this parser is `usize`, the number of times the pattern was parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let blank_lines = text::newline::<_, Simple<char>>().counted();
+    ///
+    /// assert_eq!(blank_lines.then(text::ident()).parse("\n\n\nfoo"), Ok((3, "foo".to_string())));
+    /// assert_eq!(blank_lines.then(text::ident()).parse("foo"), Ok((0, "foo".to_string())));
+    /// ```
+    fn counted(self) -> Counted<Self, O>
     where
         Self: Sized,
     {
-        Not(self, PhantomData)
+        Counted(Repeated(self, 0, None), PhantomData)
     }
 
-    /// Parse a pattern any number of times (including zero times).
+    /// Parse a pattern any number of times, until an instance of it satisfies the given predicate, yielding the
+    /// preceding outputs and the terminating output separately.
     ///
-    /// Input is eagerly parsed. Be aware that the parser will accept no occurrences of the pattern too. Consider using
-    /// [`Repeated::at_least`] instead if it better suits your use-case.
+    /// This is useful for formats with an in-band terminator, such as a list of frames that ends with a special 'end'
+    /// frame. Unlike a lookahead terminator (see [`take_until`]), the terminator is parsed by the same pattern as the
+    /// other elements and only then classified.
     ///
-    /// The output type of this parser is `Vec<O>`.
+    /// If the pattern fails before a terminator has been found (for example, because the end of the input was
+    /// reached), parsing fails with the pattern's error. Consider using [`Parser::labelled`] to make this error
+    /// clearer.
+    ///
+    /// The output type of this parser is `(Vec<O>, O)`, the non-terminating outputs and the terminating output.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use chumsky::{prelude::*, error::Cheap};
-    /// let num = filter::<_, _, Cheap<char>>(|c: &char| c.is_ascii_digit())
-    ///     .repeated().at_least(1)
-    ///     .collect::<String>()
-    ///     .from_str()
-    ///     .unwrapped();
+    /// # use chumsky::prelude::*;
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Frame { Data(u8), End }
     ///
-    /// let sum = num.then(just('+').ignore_then(num).repeated())
-    ///     .foldl(|a, b| a + b);
+    /// let frame = filter::<_, _, Simple<u8>>(|b: &u8| *b != 0xFF)
+    ///     .map(Frame::Data)
+    ///     .or(just(0xFF).to(Frame::End))
+    ///     .labelled("frame");
+    /// let frames = frame.repeated_until_value(|frame| *frame == Frame::End);
     ///
-    /// assert_eq!(sum.parse("2+13+4+0+5"), Ok(24));
+    /// assert_eq!(
+    ///     frames.parse([1, 2, 0xFF].as_slice()),
+    ///     Ok((vec![Frame::Data(1), Frame::Data(2)], Frame::End)),
+    /// );
+    /// // A missing terminator is an error
+    /// let err = frames.parse([1, 2].as_slice()).unwrap_err().remove(0);
+    /// assert_eq!((err.span(), err.found(), err.label()), (2..2, None, Some("frame")));
     /// ```
-    fn repeated(self) -> Repeated<Self>
+    fn repeated_until_value<F>(self, is_terminator: F) -> RepeatedUntilValue<Self, F>
     where
         Self: Sized,
+        F: Fn(&O) -> bool,
     {
-        Repeated(self, 0, None)
+        RepeatedUntilValue(self, is_terminator)
     }
 
     /// Parse a pattern, separated by another, any number of times.
@@ -1146,6 +2549,303 @@ pub trait Parser<I: Clone, O> {
         Rewind(self)
     }
 
+    /// Succeed, without consuming any input, if and only if the pattern fails to parse. This is often called a
+    /// *negative lookahead*.
+    ///
+    /// Whether or not the pattern parses, the input stream is left in its original state. Any errors produced by the
+    /// pattern are discarded.
+    ///
+    /// This differs from [`Parser::not`], which consumes the single token that the pattern failed to parse. Use this
+    /// combinator when you want to check that something is *not* coming up next before continuing with another parser.
+    /// See [`Parser::rewind`] for the positive equivalent.
+    ///
+    /// The output type of this parser is `()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// // Any character within a string, except an unescaped quote
+    /// let escape = just::<_, _, Simple<char>>('\\').ignore_then(any());
+    /// let char_ = escape.or(just('"').not_ahead().ignore_then(any()));
+    /// let string = char_
+    ///     .repeated()
+    ///     .collect::<String>()
+    ///     .delimited_by(just('"'), just('"'));
+    ///
+    /// assert_eq!(string.parse(r#""a\"b""#), Ok("a\"b".to_string()));
+    /// assert!(string.parse(r#""abc"#).is_err());
+    /// ```
+    fn not_ahead(self) -> NotAhead<Self, O>
+    where
+        Self: Sized,
+    {
+        NotAhead(self, PhantomData)
+    }
+
+    /// Parse a pattern without consuming any input, whether or not it succeeds. This is often called a *positive
+    /// lookahead*.
+    ///
+    /// Unlike [`Parser::rewind`], which only rewinds the input after a successful parse, this parser always leaves the
+    /// input stream in its original state. If the pattern fails, its error is reported as though it occurred at the
+    /// position where the pattern began. This prevents a failed lookahead from taking priority over errors from
+    /// alternatives that actually consumed input.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Debug, PartialEq)]
+    /// enum Expr { Block(Vec<String>), Set(Vec<String>) }
+    ///
+    /// let ident = text::ident::<_, Simple<char>>().padded();
+    /// // Blocks contain statements ending in `;`, whereas sets contain comma-separated items
+    /// let block = ident
+    ///     .then_ignore(just(';'))
+    ///     .repeated()
+    ///     .delimited_by(just('{'), just('}'))
+    ///     .map(Expr::Block);
+    /// let set = ident
+    ///     .separated_by(just(','))
+    ///     .delimited_by(just('{'), just('}'))
+    ///     .map(Expr::Set);
+    ///
+    /// // Decide which to parse by looking at the token after the first identifier
+    /// let expr = just('{')
+    ///     .then(ident)
+    ///     .then(just(';'))
+    ///     .peek()
+    ///     .ignore_then(block)
+    ///     .or(set);
+    ///
+    /// assert_eq!(expr.parse("{a; b;}"), Ok(Expr::Block(vec!["a".to_string(), "b".to_string()])));
+    /// assert_eq!(expr.parse("{a, b}"), Ok(Expr::Set(vec!["a".to_string(), "b".to_string()])));
+    /// ```
+    fn peek(self) -> Peek<Self>
+    where
+        Self: Sized,
+    {
+        Peek(self)
+    }
+
+    /// Parse a pattern, but only if another pattern *also* parses at the same position. This is a *positive lookahead*
+    /// (or conjunction) of the two patterns: combined with [`Parser::not_ahead`], it can also express negative
+    /// lookahead, as in the example below.
+    ///
+    /// This parser first parses `self` and then, starting from the same position as `self`, tries `other`. Only the
+    /// input consumed by `self` is consumed: `other` is parsed as a lookahead and the stream is always returned to the
+    /// position at which `self` finished, regardless of how much (or how little) input `other` consumed. The output of
+    /// `other` and any errors that it recovered from are discarded. If `other` fails, its error is reported as though
+    /// it occurred at the position where the pattern began (see [`Parser::peek`]).
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let ident = text::ident::<_, Simple<char>>();
+    /// let reserved = choice((text::keyword("if"), text::keyword("else")));
+    ///
+    /// // Identifiers that are also reserved words, and those that are not
+    /// let keyword = ident.and_is(reserved.clone());
+    /// let name = ident.and_is(reserved.not_ahead());
+    ///
+    /// assert_eq!(keyword.parse("if"), Ok("if".to_string()));
+    /// assert!(keyword.parse("iffy").is_err());
+    /// assert_eq!(name.parse("iffy"), Ok("iffy".to_string()));
+    /// assert!(name.parse("else").is_err());
+    /// ```
+    fn and_is<U, P>(self, other: P) -> AndIs<Self, P, U>
+    where
+        Self: Sized,
+        P: Parser<I, U, Error = Self::Error>,
+    {
+        AndIs(self, other, PhantomData)
+    }
+
+    /// Limit how far this parser may backtrack: if it fails more than `n` tokens after the position at which it
+    /// started, the failure is *committed*.
+    ///
+    /// By default, chumsky will backtrack arbitrarily far when a pattern fails, trying any alternatives that remain
+    /// (via [`Parser::or`], [`choice`], [`Parser::or_not`], [`Parser::repeated`], etc.). This is flexible, but it can
+    /// make error messages less useful (the error from the most promising alternative is often replaced by a generic
+    /// one) and can cause the same input to be parsed many times over. A committed failure is not backtracked over:
+    /// enclosing alternatives are not attempted and the error is propagated as-is, much like a 'cut' in other parsing
+    /// frameworks. The worst-case amount of input that gets re-parsed after this parser fails is therefore `n` tokens.
+    ///
+    /// Commitment affects the nearest enclosing choice, such as the [`choice`] that this pattern is a branch of. A
+    /// chain of alternatives like `a.or(b).or(c)` is a single choice, just like `choice((a, b, c))`, so none of its
+    /// other branches are attempted.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let ident = text::ident::<_, Simple<char>>().padded();
+    ///
+    /// // Once we've seen `(x) =>`, we know that we're parsing a lambda and there's no sense trying anything else
+    /// let lambda = ident
+    ///     .delimited_by(just('('), just(')'))
+    ///     .then_ignore(just("=>").padded())
+    ///     .then(ident)
+    ///     .try_window(4)
+    ///     .map(|(arg, body)| format!("{} -> {}", arg, body));
+    /// let parens = ident.delimited_by(just('('), just(')'));
+    ///
+    /// let expr = lambda.or(parens).then_ignore(end());
+    ///
+    /// assert_eq!(expr.parse("(x) => y"), Ok("x -> y".to_string()));
+    /// assert_eq!(expr.parse("(x)"), Ok("x".to_string()));
+    /// // The lambda failed after its window, so `parens` is not attempted and the lambda's error is reported
+    /// assert_eq!(expr.parse("(x) => !").unwrap_err()[0].span(), 7..8);
+    /// ```
+    fn try_window(self, n: usize) -> TryWindow<Self>
+    where
+        Self: Sized,
+    {
+        TryWindow(self, n)
+    }
+
+    /// Commit to the current pattern once this parser has succeeded, much like a 'cut' in other parsing frameworks.
+    ///
+    /// After this parser succeeds, any subsequent failure in the same pattern is *committed* (see
+    /// [`Parser::try_window`]): rather than backtracking and trying other alternatives (via [`Parser::or`],
+    /// [`choice`], [`Parser::or_not`], [`Parser::repeated`], etc.), the enclosing parsers report the precise error
+    /// from this pattern. This is useful once the input has been unambiguously identified, such as after a keyword
+    /// that introduces a particular kind of statement.
+    ///
+    /// A cut lasts until the nearest enclosing point at which chumsky could backtrack (an alternative of
+    /// [`Parser::or`] or [`choice`], an item of [`Parser::repeated`], etc.). If the pattern containing the cut
+    /// succeeds, parsing continues as normal and later failures are not affected by it. A chain of alternatives like
+    /// `a.or(b).or(c)` is a single such point, just like `choice((a, b, c))`.
+    ///
+    /// Cuts do not disable error recovery: a strategy given to [`Parser::recover_with`] still attempts to recover
+    /// from a committed failure, and a successful recovery yields its output as usual. Place the recovery strategy
+    /// around the pattern that contains the cut so that it is given the committed error.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let ident = text::ident::<_, Simple<char>>().padded();
+    /// let value = text::int(10).padded();
+    ///
+    /// // Once we've seen `let`, this can only be a declaration
+    /// let decl = text::keyword("let")
+    ///     .cut()
+    ///     .ignore_then(ident)
+    ///     .then_ignore(just('='))
+    ///     .then(value)
+    ///     .map(|(name, val)| format!("let {} = {}", name, val));
+    /// let assign = ident
+    ///     .then_ignore(just('='))
+    ///     .then(value)
+    ///     .map(|(name, val)| format!("{} = {}", name, val));
+    ///
+    /// let stmt = decl.or(assign).then_ignore(end());
+    ///
+    /// assert_eq!(stmt.parse("let x = 5"), Ok("let x = 5".to_string()));
+    /// assert_eq!(stmt.parse("x = 5"), Ok("x = 5".to_string()));
+    /// // Without the cut, this would be parsed as an assignment to a variable named `let`
+    /// assert_eq!(stmt.parse("let = 5").unwrap_err()[0].span(), 4..5);
+    /// ```
+    fn cut(self) -> Cut<Self>
+    where
+        Self: Sized,
+    {
+        Cut(self)
+    }
+
+    /// Lift this parser so that it operates on a stream of optional tokens, where `None` represents a 'hole' in the
+    /// input.
+    ///
+    /// This is useful for resilient lexer → parser pipelines in which the lexer deliberately passes unrecognised input
+    /// through as a hole rather than giving up. `Some(tok)` is treated exactly as `tok` would be, while a hole never
+    /// matches any pattern (including [`just`], [`filter`], [`any`], etc.): to the original parser, it looks like the
+    /// end of the input. Errors produced by the original parser are converted with `map_err` (for
+    /// [`Simple`](error::Simple), this is usually `|e| e.map(Some)`), so an error caused by a hole is reported at the
+    /// hole's span.
+    ///
+    /// Recovery strategies used *outside* of this parser (such as [`Parser::recover_with`] with [`skip_until`]) see
+    /// the holes, and so can be used to skip past them.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let digits = filter::<_, _, Simple<char>>(char::is_ascii_digit)
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .collect::<String>()
+    ///     .over_options(|e| e.map(Some));
+    ///
+    /// let tokens = |s: &str| s.chars().map(|c| if c == '?' { None } else { Some(c) }).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(digits.then_ignore(end()).parse(tokens("123")), Ok("123".to_string()));
+    /// // The hole ends the sequence of digits
+    /// assert_eq!(digits.parse(tokens("12?3")), Ok("12".to_string()));
+    /// // Holes never match, so an error is reported at the hole
+    /// assert_eq!(digits.parse(tokens("?123")).unwrap_err()[0].span(), 0..1);
+    /// ```
+    fn over_options<E, F>(self, map_err: F) -> OverOptions<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Error) -> E,
+        E: Error<Option<I>, Span = <Self::Error as Error<I>>::Span>,
+    {
+        OverOptions(self, map_err)
+    }
+
+    /// Memoize the results of this parser, such that parsing it again at a position in the input where it has already
+    /// been parsed returns the previous result rather than parsing the input again.
+    ///
+    /// This is sometimes called 'packrat parsing', and is useful when a parser is shared between several alternatives
+    /// that begin at the same position (such as those of [`Parser::or`] or [`choice`]): without memoization, each
+    /// alternative would re-parse the input from scratch. In grammars with heavy backtracking, this can reduce
+    /// parsing time from exponential to linear, at the cost of the memory needed to hold the cached results.
+    ///
+    /// Clones of the memoized parser share the same cache. The cache is cleared whenever the parser is used to parse
+    /// a new input, so results never leak between calls to [`Parser::parse`] or similar.
+    ///
+    /// Note that cached results are returned without invoking the parser, so side effects (such as mutations to a
+    /// state attached with [`Stream::with_state`]) are not repeated. Memoization does not make left-recursive
+    /// parsers terminate.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let num = text::int::<_, Simple<char>>(10).padded().memoized();
+    ///
+    /// // Both alternatives begin by parsing a number, but the number is only parsed once
+    /// let expr = num.clone().then_ignore(just('+')).then(num.clone()).map(|(a, b)| format!("{} + {}", a, b))
+    ///     .or(num.clone().then_ignore(just('-')).then(num.clone()).map(|(a, b)| format!("{} - {}", a, b)))
+    ///     .or(num);
+    ///
+    /// assert_eq!(expr.parse("1 + 2"), Ok("1 + 2".to_string()));
+    /// assert_eq!(expr.parse("3 - 4"), Ok("3 - 4".to_string()));
+    /// assert_eq!(expr.parse("5"), Ok("5".to_string()));
+    /// ```
+    fn memoized(self) -> Memoized<Self, I, O, Self::Error>
+    where
+        Self: Sized,
+        O: Clone,
+        Self::Error: Clone,
+    {
+        Memoized(self, Rc::new(RefCell::new(Memo::default())))
+    }
+
     /// Box the parser, yielding a parser that performs parsing through dynamic dispatch.
     ///
     /// Boxing a parser might be useful for:
@@ -1171,6 +2871,39 @@ pub trait Parser<I: Clone, O> {
         BoxedParser(Rc::new(self))
     }
 
+    /// Box the parser such that it may be shared between threads, yielding a parser that performs parsing through
+    /// dynamic dispatch.
+    ///
+    /// This is like [`Parser::boxed`], except that the resulting [`ArcParser`] is [`Send`] and [`Sync`], so it can be
+    /// moved between threads or stored in a `static` (such as with `once_cell` or `lazy_static`). Cloning it remains
+    /// cheap, although it requires an atomic reference count update.
+    ///
+    /// Note that only parsers that are themselves [`Send`] and [`Sync`] may be boxed in this way. Some parsers, such
+    /// as [`BoxedParser`] and [`Recursive`](recursive::Recursive), are not.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let digits = text::digits::<_, Simple<char>>(10).boxed_sync();
+    ///
+    /// let handle = std::thread::spawn({
+    ///     let digits = digits.clone();
+    ///     move || digits.parse("123")
+    /// });
+    ///
+    /// assert_eq!(handle.join().unwrap(), Ok("123".to_string()));
+    /// assert_eq!(digits.parse("456"), Ok("456".to_string()));
+    /// ```
+    fn boxed_sync<'a>(self) -> ArcParser<'a, I, O, Self::Error>
+    where
+        Self: Sized + Send + Sync + 'a,
+    {
+        ArcParser(Arc::new(self))
+    }
+
     /// Attempt to convert the output of this parser into something else using Rust's [`FromStr`] trait.
     ///
     /// This is most useful when wanting to convert literal values into their corresponding Rust type, such as when
@@ -1200,9 +2933,49 @@ pub trait Parser<I: Clone, O> {
         self.map(|o| o.as_ref().parse())
     }
 
+    /// Convert the output of this parser into something else using Rust's [`FromStr`] trait, producing an error if
+    /// the conversion fails.
+    ///
+    /// Unlike [`Parser::from_str`] followed by [`Parser::unwrapped`], this never panics: if the output cannot be
+    /// converted, an error created with [`Error::custom`] (with the conversion error as its message) is produced,
+    /// spanning the input that this parser consumed. This error may be recovered from like any other.
+    ///
+    /// The output type of this parser is `U`, the type that the output, `O`, is converted into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::SimpleReason};
+    /// let float = filter::<_, _, Simple<char>>(|c: &char| c.is_ascii_alphanumeric() || *c == '.')
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .collect::<String>()
+    ///     .try_from_str::<f64>();
+    ///
+    /// assert_eq!(float.parse("3.14"), Ok(3.14));
+    ///
+    /// let errors = float.parse("3.x").unwrap_err();
+    /// assert_eq!(errors[0].span(), 0..3);
+    /// assert_eq!(errors[0].reason(), &SimpleReason::Custom("invalid float literal".to_string()));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn try_from_str<U>(
+        self,
+    ) -> TryMap<Self, fn(O, <Self::Error as Error<I>>::Span) -> Result<U, Self::Error>, O>
+    where
+        Self: Sized,
+        U: FromStr,
+        U::Err: fmt::Display,
+        O: AsRef<str>,
+    {
+        self.try_map(|o, span| o.as_ref().parse().map_err(|e| Self::Error::custom(span, e)))
+    }
+
     /// For parsers that produce a [`Result`] as their output, unwrap the result (panicking if an [`Err`] is
     /// encountered).
     ///
+    /// To produce a parser error instead of panicking, use [`Parser::try_unwrapped`].
+    ///
     /// In general, this method should be avoided except in cases where all possible that the parser might produce can
     /// by parsed using [`FromStr`] without producing an error.
     ///
@@ -1232,6 +3005,46 @@ pub trait Parser<I: Clone, O> {
     {
         Unwrapped(Location::caller(), self, PhantomData)
     }
+
+    /// For parsers that produce a [`Result`] or an [`Option`] as their output, unwrap it, producing an error if an
+    /// [`Err`] or [`None`] is encountered.
+    ///
+    /// This is the non-panicking counterpart to [`Parser::unwrapped`]. The error is created with [`Error::custom`],
+    /// spans the input consumed by the original parser, and has the [`Err`] value's [`Display`](fmt::Display) form as
+    /// its message (or a generic message, for [`None`]). Like the errors of [`Parser::try_map`], it may be recovered
+    /// from.
+    ///
+    /// The output type of this parser is the [`Ok`] or [`Some`] value (see [`Fallible`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::SimpleReason};
+    /// let byte = text::int::<_, Simple<char>>(10)
+    ///     .from_str::<u8>()
+    ///     .try_unwrapped();
+    ///
+    /// assert_eq!(byte.parse("255"), Ok(255));
+    ///
+    /// let errors = byte.parse("256").unwrap_err();
+    /// assert_eq!(errors[0].span(), 0..3);
+    /// assert_eq!(
+    ///     errors[0].reason(),
+    ///     &SimpleReason::Custom("number too large to fit in target type".to_string()),
+    /// );
+    ///
+    /// let digit = any::<_, Simple<char>>().map(|c: char| c.to_digit(10)).try_unwrapped();
+    ///
+    /// assert_eq!(digit.parse("7"), Ok(7));
+    /// assert!(digit.parse("x").is_err());
+    /// ```
+    fn try_unwrapped(self) -> TryUnwrapped<Self, O>
+    where
+        Self: Sized,
+        O: Fallible,
+    {
+        TryUnwrapped(self, PhantomData)
+    }
 }
 
 impl<'a, I: Clone, O, T: Parser<I, O> + ?Sized> Parser<I, O> for &'a T {
@@ -1407,6 +3220,58 @@ impl<'a, I: Clone, O, E: Error<I>> Parser<I, O> for BoxedParser<'a, I, O, E> {
     }
 }
 
+/// See [`Parser::boxed_sync`].
+///
+/// This type is a [`repr(transparent)`](https://doc.rust-lang.org/nomicon/other-reprs.html#reprtransparent) wrapper
+/// around an [`Arc`], such that it may be cheaply cloned and shared between threads.
+#[must_use]
+#[repr(transparent)]
+pub struct ArcParser<'a, I, O, E: Error<I>>(Arc<dyn Parser<I, O, Error = E> + Send + Sync + 'a>);
+
+impl<'a, I, O, E: Error<I>> Clone for ArcParser<'a, I, O, E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'a, I: Clone, O, E: Error<I>> Parser<I, O> for ArcParser<'a, I, O, E> {
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, Self::Error>,
+    ) -> PResult<I, O, Self::Error> {
+        #[allow(deprecated)]
+        debugger.invoke(&self.0, stream)
+    }
+
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<I, Self::Error>,
+    ) -> PResult<I, O, Self::Error> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<I, Self::Error>,
+    ) -> PResult<I, O, Self::Error> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+
+    fn boxed_sync<'b>(self) -> ArcParser<'b, I, O, Self::Error>
+    where
+        Self: Sized + Send + Sync + 'b,
+    {
+        // Avoid boxing twice.
+        self
+    }
+}
+
 /// Create a parser that selects one or more input patterns and map them to an output value.
 ///
 /// This is most useful when turning the tokens of a previous compilation pass (such as lexing) into data that can be
@@ -1435,6 +3300,9 @@ impl<'a, I: Clone, O, E: Error<I>> Parser<I, O> for BoxedParser<'a, I, O, E> {
 /// Internally, [`select!`] is a loose wrapper around [`filter_map`] and thinking of it as such might make it less
 /// confusing.
 ///
+/// If the input matches none of the patterns, an error is produced with [`Error::expected_input_found`], using the
+/// span of the input and an empty set of expected inputs. This works with any type implementing [`Error`].
+///
 /// # Examples
 ///
 /// ```
@@ -0,0 +1,384 @@
+//! Operator-precedence parsing using Pratt parsing (also known as precedence climbing).
+//!
+//! *“The ships hung in the sky in much the same way that bricks don't.”*
+//!
+//! Expression grammars with many levels of precedence can be written with [`Parser::foldl`] and [`Parser::foldr`],
+//! but each level needs its own parser and getting associativity right by hand is error-prone. [`pratt()`] instead
+//! builds a single expression parser from an atom parser and a set of prefix, postfix and infix operators, each with a
+//! *binding power*: operators with a higher binding power bind more tightly than those with a lower one.
+//!
+//! When a prefix or postfix operator has the same binding power as an infix operator, the prefix or postfix operator
+//! binds more tightly. Operators of the same kind are tried in the order that they were added.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chumsky::{prelude::*, pratt::pratt};
+//! #[derive(Debug, PartialEq)]
+//! enum Expr {
+//!     Num(u64),
+//!     Neg(Box<Expr>),
+//!     Fact(Box<Expr>),
+//!     Bin(Box<Expr>, char, Box<Expr>),
+//! }
+//!
+//! let bin = |l, op, r| Expr::Bin(Box::new(l), op, Box::new(r));
+//!
+//! let atom = text::int::<_, Simple<char>>(10).map(|s: String| Expr::Num(s.parse().unwrap()));
+//! let expr = pratt(atom.padded())
+//!     .infix_left(one_of("+-").padded(), 1, bin)
+//!     .infix_left(one_of("*/").padded(), 2, bin)
+//!     .prefix(just('-').padded(), 3, |_, e| Expr::Neg(Box::new(e)))
+//!     .postfix(just('!').padded(), 4, |e, _| Expr::Fact(Box::new(e)))
+//!     .then_ignore(end());
+//!
+//! use Expr::*;
+//! assert_eq!(
+//!     expr.parse("-1 * 2! - 3"),
+//!     Ok(Bin(
+//!         Box::new(Bin(Box::new(Neg(Box::new(Num(1)))), '*', Box::new(Fact(Box::new(Num(2)))))),
+//!         '-',
+//!         Box::new(Num(3)),
+//!     )),
+//! );
+//! ```
+
+use super::*;
+
+type Unary<'a, I, O, E> = BoxedParser<'a, I, Box<dyn FnOnce(O) -> O + 'a>, E>;
+type Binary<'a, I, O, E> = BoxedParser<'a, I, Box<dyn FnOnce(O, O) -> O + 'a>, E>;
+
+// Binding powers are scaled such that infix operators can be made left or right associative, and such that prefix
+// and postfix operators bind more tightly than infix operators with the same binding power.
+fn scale(binding_power: u16) -> u32 {
+    binding_power as u32 * 4
+}
+
+#[derive(Clone)]
+struct Operator<P> {
+    parser: P,
+    // How tightly the operator binds to the expression on its left, if any
+    left: u32,
+    // How tightly the operator binds to the expression on its right, if any
+    right: u32,
+}
+
+/// See [`pratt()`].
+#[must_use]
+pub struct Pratt<'a, I, O, A, E: Error<I>> {
+    atom: A,
+    prefix: Vec<Operator<Unary<'a, I, O, E>>>,
+    postfix: Vec<Operator<Unary<'a, I, O, E>>>,
+    infix: Vec<Operator<Binary<'a, I, O, E>>>,
+}
+
+impl<'a, I, O, A: Clone, E: Error<I>> Clone for Pratt<'a, I, O, A, E> {
+    fn clone(&self) -> Self {
+        Self {
+            atom: self.atom.clone(),
+            prefix: self.prefix.clone(),
+            postfix: self.postfix.clone(),
+            infix: self.infix.clone(),
+        }
+    }
+}
+
+impl<'a, I: Clone + 'a, O: 'a, A: Parser<I, O, Error = E>, E: Error<I> + 'a> Pratt<'a, I, O, A, E> {
+    fn binary<U: 'a, P, F>(op: P, f: F) -> Binary<'a, I, O, E>
+    where
+        P: Parser<I, U, Error = E> + 'a,
+        F: Fn(O, U, O) -> O + 'a,
+    {
+        let f = Rc::new(f);
+        op.map(move |op| {
+            let f = f.clone();
+            Box::new(move |lhs, rhs| f(lhs, op, rhs)) as Box<dyn FnOnce(O, O) -> O + 'a>
+        })
+        .boxed()
+    }
+
+    /// Add a left-associative infix operator, such that `a + b + c` is parsed as `(a + b) + c`.
+    ///
+    /// The function is given the left operand, the output of the operator and the right operand.
+    pub fn infix_left<U: 'a, P, F>(mut self, op: P, binding_power: u16, f: F) -> Self
+    where
+        P: Parser<I, U, Error = E> + 'a,
+        F: Fn(O, U, O) -> O + 'a,
+    {
+        self.infix.push(Operator {
+            parser: Self::binary(op, f),
+            left: scale(binding_power) + 1,
+            right: scale(binding_power) + 2,
+        });
+        self
+    }
+
+    /// Add a right-associative infix operator, such that `a ^ b ^ c` is parsed as `a ^ (b ^ c)`.
+    ///
+    /// The function is given the left operand, the output of the operator and the right operand.
+    pub fn infix_right<U: 'a, P, F>(mut self, op: P, binding_power: u16, f: F) -> Self
+    where
+        P: Parser<I, U, Error = E> + 'a,
+        F: Fn(O, U, O) -> O + 'a,
+    {
+        self.infix.push(Operator {
+            parser: Self::binary(op, f),
+            left: scale(binding_power) + 2,
+            right: scale(binding_power) + 1,
+        });
+        self
+    }
+
+    /// Add a prefix operator, such as negation in `-a`.
+    ///
+    /// The function is given the output of the operator and the operand.
+    pub fn prefix<U: 'a, P, F>(mut self, op: P, binding_power: u16, f: F) -> Self
+    where
+        P: Parser<I, U, Error = E> + 'a,
+        F: Fn(U, O) -> O + 'a,
+    {
+        let f = Rc::new(f);
+        self.prefix.push(Operator {
+            parser: op
+                .map(move |op| {
+                    let f = f.clone();
+                    Box::new(move |rhs| f(op, rhs)) as Box<dyn FnOnce(O) -> O + 'a>
+                })
+                .boxed(),
+            left: 0,
+            right: scale(binding_power) + 3,
+        });
+        self
+    }
+
+    /// Add a postfix operator, such as the factorial in `a!`.
+    ///
+    /// The function is given the operand and the output of the operator.
+    pub fn postfix<U: 'a, P, F>(mut self, op: P, binding_power: u16, f: F) -> Self
+    where
+        P: Parser<I, U, Error = E> + 'a,
+        F: Fn(O, U) -> O + 'a,
+    {
+        let f = Rc::new(f);
+        self.postfix.push(Operator {
+            parser: op
+                .map(move |op| {
+                    let f = f.clone();
+                    Box::new(move |lhs| f(lhs, op)) as Box<dyn FnOnce(O) -> O + 'a>
+                })
+                .boxed(),
+            left: scale(binding_power) + 3,
+            right: 0,
+        });
+        self
+    }
+}
+
+impl<'a, I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Pratt<'a, I, O, A, E> {
+    // Parse an expression containing only operators that bind at least as tightly as `min_bp`
+    fn parse_expr<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+        min_bp: u32,
+    ) -> PResult<I, O, E> {
+        let mut errors = Vec::new();
+        let mut alt = None;
+
+        let mut lhs = None;
+        for op in &self.prefix {
+            let before = stream.save();
+            let (mut op_errors, op_res) = stream.cut_scope(|stream| {
+                #[allow(deprecated)]
+                debugger.invoke(&op.parser, stream)
+            });
+            match op_res {
+                Ok((build, op_alt)) => {
+                    errors.append(&mut op_errors);
+                    alt = merge_alts(alt, op_alt);
+                    let (mut rhs_errors, rhs_res) = self.parse_expr(debugger, stream, op.right);
+                    errors.append(&mut rhs_errors);
+                    match rhs_res {
+                        Ok((rhs, rhs_alt)) => {
+                            alt = merge_alts(alt, rhs_alt);
+                            lhs = Some(build(rhs));
+                            break;
+                        }
+                        Err(err) => return (errors, Err(err.max(alt))),
+                    }
+                }
+                Err(err) if err.committed => return (op_errors, Err(err.uncommit())),
+                Err(err) => {
+                    stream.revert(before);
+                    alt = merge_alts(alt, Some(err));
+                }
+            }
+        }
+
+        let mut lhs = match lhs {
+            Some(lhs) => lhs,
+            None => {
+                #[allow(deprecated)]
+                let (mut atom_errors, atom_res) = debugger.invoke(&self.atom, stream);
+                errors.append(&mut atom_errors);
+                match atom_res {
+                    Ok((atom, atom_alt)) => {
+                        alt = merge_alts(alt, atom_alt);
+                        atom
+                    }
+                    Err(err) => return (errors, Err(err.max(alt))),
+                }
+            }
+        };
+
+        'operators: loop {
+            for op in self.postfix.iter().filter(|op| op.left >= min_bp) {
+                let before = stream.save();
+                let (mut op_errors, op_res) = stream.cut_scope(|stream| {
+                    #[allow(deprecated)]
+                    debugger.invoke(&op.parser, stream)
+                });
+                match op_res {
+                    Ok((build, op_alt)) => {
+                        errors.append(&mut op_errors);
+                        alt = merge_alts(alt, op_alt);
+                        lhs = build(lhs);
+                        continue 'operators;
+                    }
+                    Err(err) if err.committed => {
+                        errors.append(&mut op_errors);
+                        return (errors, Err(err.uncommit()));
+                    }
+                    Err(err) => {
+                        stream.revert(before);
+                        alt = merge_alts(alt, Some(err));
+                    }
+                }
+            }
+
+            for op in self.infix.iter().filter(|op| op.left >= min_bp) {
+                let before = stream.save();
+                let (mut op_errors, op_res) = stream.cut_scope(|stream| {
+                    #[allow(deprecated)]
+                    debugger.invoke(&op.parser, stream)
+                });
+                let (build, op_alt) = match op_res {
+                    Ok(out) => out,
+                    Err(err) if err.committed => {
+                        errors.append(&mut op_errors);
+                        return (errors, Err(err.uncommit()));
+                    }
+                    Err(err) => {
+                        stream.revert(before);
+                        alt = merge_alts(alt, Some(err));
+                        continue;
+                    }
+                };
+
+                let (mut rhs_errors, rhs_res) = self.parse_expr(debugger, stream, op.right);
+                match rhs_res {
+                    Ok((rhs, rhs_alt)) => {
+                        errors.append(&mut op_errors);
+                        errors.append(&mut rhs_errors);
+                        alt = merge_alts(alt, merge_alts(op_alt, rhs_alt));
+                        lhs = build(lhs, rhs);
+                        continue 'operators;
+                    }
+                    Err(err) if err.committed => {
+                        errors.append(&mut op_errors);
+                        errors.append(&mut rhs_errors);
+                        return (errors, Err(err.uncommit()));
+                    }
+                    // Like `Parser::repeated`, an operator without a right operand is not consumed
+                    Err(err) => {
+                        stream.revert(before);
+                        alt = merge_alts(alt, Some(err));
+                    }
+                }
+            }
+
+            break (errors, Ok((lhs, alt)));
+        }
+    }
+}
+
+impl<'a, I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Parser<I, O>
+    for Pratt<'a, I, O, A, E>
+{
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        self.parse_expr(debugger, stream, 0)
+    }
+
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// Create an expression parser from a parser for its atoms (such as literals, identifiers and parenthesised
+/// expressions), to which operators may then be added with [`Pratt::infix_left`], [`Pratt::infix_right`],
+/// [`Pratt::prefix`] and [`Pratt::postfix`].
+///
+/// See the [module-level documentation](self) for details and an example.
+///
+/// The output type of this parser is `O`, the same as the atom parser.
+pub fn pratt<'a, I: Clone, O, A>(atom: A) -> Pratt<'a, I, O, A, A::Error>
+where
+    A: Parser<I, O>,
+{
+    Pratt {
+        atom,
+        prefix: Vec::new(),
+        postfix: Vec::new(),
+        infix: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use error::Simple;
+    use text::TextParser;
+
+    // Render expressions as fully-parenthesised strings so that associativity is easy to check
+    fn expr() -> impl Parser<char, String, Error = Simple<char>> {
+        let atom = text::int::<_, Simple<char>>(10).padded();
+        let bin = |l, op, r| format!("({} {} {})", l, op, r);
+        pratt(atom)
+            .infix_left(one_of("+-").padded(), 1, bin)
+            .infix_left(one_of("*/").padded(), 2, bin)
+            .prefix(just('-').padded(), 3, |op, e| format!("({}{})", op, e))
+            .infix_right(just('^').padded(), 4, bin)
+            .then_ignore(end())
+    }
+
+    #[test]
+    fn pratt_associativity() {
+        assert_eq!(
+            expr().parse("1 + 2 * 3 - 4"),
+            Ok("((1 + (2 * 3)) - 4)".into())
+        );
+        assert_eq!(expr().parse("1 - 2 - 3"), Ok("((1 - 2) - 3)".into()));
+        assert_eq!(expr().parse("2 ^ 3 ^ 4"), Ok("(2 ^ (3 ^ 4))".into()));
+        assert_eq!(expr().parse("-2^2"), Ok("(-(2 ^ 2))".into()));
+        assert_eq!(expr().parse("-2 * 3"), Ok("((-2) * 3)".into()));
+    }
+
+    #[test]
+    fn pratt_missing_operand() {
+        let errors = expr().parse("1 + 2 *").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 7..7);
+    }
+}
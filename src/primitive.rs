@@ -14,7 +14,7 @@
 //! - [`end`]: parses the end of input (i.e: if there any more inputs, this parse fails)
 
 use super::*;
-use core::panic::Location;
+use core::{ops::RangeBounds, panic::Location};
 
 /// See [`custom`].
 #[must_use]
@@ -60,6 +60,81 @@ pub fn custom<F, E>(f: F) -> Custom<F, E> {
     Custom(f, PhantomData)
 }
 
+/// See [`from_fn`].
+#[must_use]
+pub struct FromFn<F, E>(F, PhantomData<E>);
+
+impl<F: Copy, E> Copy for FromFn<F, E> {}
+impl<F: Clone, E> Clone for FromFn<F, E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, F: Fn(&mut StreamOf<I, E>) -> Result<O, E>, E: Error<I>> Parser<I, O>
+    for FromFn<F, E>
+{
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        _debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        match (self.0)(stream) {
+            Ok(out) => (Vec::new(), Ok((out, None))),
+            Err(err) => (Vec::new(), Err(Located::at(stream.save(), err))),
+        }
+    }
+
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// A parser primitive that consumes tokens from the input stream by hand, using a function.
+///
+/// The function is given the input [`Stream`], which it may consume tokens from using [`Stream::next_token`] and
+/// [`Stream::peek_token`], and move around within using [`Stream::save`] and [`Stream::revert`]. It returns either an
+/// output or an error. Errors are located at the position that the stream was left at, so the stream position
+/// determines how the error is prioritised against those of other alternatives.
+///
+/// This is a simpler alternative to [`custom`] for parsers that do not need to interact with error recovery.
+///
+/// The output type of this parser is `O`, the [`Ok`] type of the function's result.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, primitive::from_fn};
+/// // A run-length encoded block: a count, followed by the token to be repeated
+/// let rle = from_fn::<_, _, _, Simple<char>>(|stream| {
+///     let start = stream.save();
+///     match (stream.next_token(), stream.next_token()) {
+///         (Some(n), Some(c)) if n.is_ascii_digit() => {
+///             Ok(std::iter::repeat(c).take(n.to_digit(10).unwrap() as usize).collect::<String>())
+///         }
+///         (n, c) => {
+///             let span = stream.span_since(start);
+///             Err(Simple::expected_input_found(span, None, c.or(n)))
+///         }
+///     }
+/// });
+///
+/// assert_eq!(rle.repeated().parse("3a2b"), Ok(vec!["aaa".to_string(), "bb".to_string()]));
+/// assert!(rle.parse("a3").is_err());
+/// ```
+pub fn from_fn<I: Clone, O, F: Fn(&mut StreamOf<I, E>) -> Result<O, E>, E: Error<I>>(
+    f: F,
+) -> FromFn<F, E> {
+    FromFn(f, PhantomData)
+}
+
 /// See [`end`].
 #[must_use]
 pub struct End<E>(PhantomData<E>);
@@ -299,17 +374,25 @@ impl<I: Clone + PartialEq, C: OrderedContainer<I> + Clone, E: Error<I>> Parser<I
         _debugger: &mut D,
         stream: &mut StreamOf<I, E>,
     ) -> PResult<I, C, E> {
+        let mut first = None;
         for expected in self.0.get_iter() {
             match stream.next() {
-                (_, _, Some(tok)) if tok == expected => {}
+                (_, span, Some(tok)) if tok == expected => {
+                    first.get_or_insert(span);
+                }
                 (at, span, found) => {
+                    // The error covers the part of the sequence that was matched, along with the offending input
+                    let span = match first {
+                        Some(first) => E::Span::new(span.context(), first.start()..span.end()),
+                        None => span,
+                    };
                     return (
                         Vec::new(),
                         Err(Located::at(
                             at,
                             E::expected_input_found(span, Some(Some(expected)), found),
                         )),
-                    )
+                    );
                 }
             }
         }
@@ -329,6 +412,10 @@ impl<I: Clone + PartialEq, C: OrderedContainer<I> + Clone, E: Error<I>> Parser<I
 
 /// A parser that accepts only the given input.
 ///
+/// The input may be a single token or an ordered sequence of tokens, such as a [`&str`](str), an array or a [`Vec`].
+/// If a sequence only partially matches, the error reports the token that was expected in place of the input that was
+/// found, with a span that covers both the matched part of the sequence and that input.
+///
 /// The output type of this parser is `C`, the input or sequence that was provided.
 ///
 /// # Examples
@@ -344,6 +431,20 @@ impl<I: Clone + PartialEq, C: OrderedContainer<I> + Clone, E: Error<I>> Parser<I
 /// // This fails because the parser expects an end to the input after the '?'
 /// assert!(question.then(end()).parse("?!").is_err());
 /// ```
+///
+/// Sequences of inputs are matched in their entirety:
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let r#let = just::<_, _, Simple<char>>("let");
+///
+/// assert_eq!(r#let.parse("let"), Ok("let"));
+///
+/// let errors = r#let.parse("lex").unwrap_err();
+/// assert_eq!(errors[0].expected().collect::<Vec<_>>(), vec![&Some('t')]);
+/// assert_eq!(errors[0].found(), Some(&'x'));
+/// assert_eq!(errors[0].span(), 0..3);
+/// ```
 pub fn just<I, C: OrderedContainer<I>, E: Error<I>>(inputs: C) -> Just<I, C, E> {
     Just(inputs, PhantomData)
 }
@@ -515,13 +616,83 @@ impl<I: Clone, E: Error<I>> Parser<I, ()> for Empty<E> {
     }
 }
 
-/// A parser that parses no inputs.
+/// A parser that parses no inputs and always succeeds.
 ///
 /// The output type of this parser is `()`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let num = just::<_, _, Simple<char>>('+')
+///     .ignored()
+///     .or(empty())
+///     .ignore_then(text::int(10));
+///
+/// assert_eq!(num.parse("+42"), Ok("42".to_string()));
+/// assert_eq!(num.parse("42"), Ok("42".to_string()));
+/// ```
 pub fn empty<E>() -> Empty<E> {
     Empty(PhantomData)
 }
 
+/// See [`value`].
+#[must_use]
+pub struct Value<O, E>(O, PhantomData<E>);
+
+impl<O: Copy, E> Copy for Value<O, E> {}
+impl<O: Clone, E> Clone for Value<O, E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O: Clone, E: Error<I>> Parser<I, O> for Value<O, E> {
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        _debugger: &mut D,
+        _: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        (Vec::new(), Ok((self.0.clone(), None)))
+    }
+
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// A parser that parses no inputs and always succeeds, producing a clone of the given value.
+///
+/// This is useful as a default branch of [`Parser::or`] or [`choice`], or for epsilon productions. It is equivalent
+/// to `empty().to(x)`, but doesn't require a type annotation for the input.
+///
+/// The output type of this parser is `O`, the type of the given value.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Visibility { Public, Private }
+///
+/// let visibility = text::keyword::<_, _, Simple<char>>("pub")
+///     .to(Visibility::Public)
+///     .or(value(Visibility::Private));
+///
+/// assert_eq!(visibility.parse("pub"), Ok(Visibility::Public));
+/// assert_eq!(visibility.parse(""), Ok(Visibility::Private));
+/// ```
+pub fn value<O: Clone, E>(x: O) -> Value<O, E> {
+    Value(x, PhantomData)
+}
+
 /// See [`none_of`].
 #[must_use]
 pub struct NoneOf<I, C, E>(C, PhantomData<(I, E)>);
@@ -586,6 +757,142 @@ pub fn none_of<I, C: Container<I>, E: Error<I>>(inputs: C) -> NoneOf<I, C, E> {
     NoneOf(inputs, PhantomData)
 }
 
+/// See [`one_of_range`].
+#[must_use]
+pub struct OneOfRange<I, R, E>(R, PhantomData<(I, E)>);
+
+impl<I, R: Copy, E> Copy for OneOfRange<I, R, E> {}
+impl<I, R: Clone, E> Clone for OneOfRange<I, R, E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone + PartialOrd, R: RangeBounds<I>, E: Error<I>> Parser<I, I> for OneOfRange<I, R, E> {
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        _debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, I, E> {
+        match stream.next() {
+            (_, _, Some(tok)) if self.0.contains(&tok) => (Vec::new(), Ok((tok, None))),
+            (at, span, found) => (
+                Vec::new(),
+                Err(Located::at(
+                    at,
+                    E::expected_range_found(
+                        span,
+                        (self.0.start_bound().cloned(), self.0.end_bound().cloned()),
+                        found,
+                    ),
+                )),
+            ),
+        }
+    }
+
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, I, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, I, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// A parser that accepts any input within a range, such as `'a'..='z'`.
+///
+/// Unlike [`one_of`], the range does not need to be turned into a collection of every input that it contains: any
+/// [`RangeBounds`] may be used, including open-ended ranges like `b'0'..`.
+///
+/// When the input is not within the range, the error describes the range that was expected (see
+/// [`Error::expected_range_found`]) rather than listing every input within it.
+///
+/// The output type of this parser is `I`, the input that was found.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let lowercase = one_of_range::<_, _, Simple<char>>('a'..='z')
+///     .repeated().at_least(1)
+///     .collect::<String>();
+///
+/// assert_eq!(lowercase.parse("hello"), Ok("hello".to_string()));
+///
+/// let err = &lowercase.parse("Hello").unwrap_err()[0];
+/// assert_eq!(err.span(), 0..1);
+/// assert_eq!(err.to_string(), "found \"H\" but expected \"a\"..=\"z\"");
+/// ```
+pub fn one_of_range<I, R: RangeBounds<I>, E: Error<I>>(range: R) -> OneOfRange<I, R, E> {
+    OneOfRange(range, PhantomData)
+}
+
+/// See [`none_of_range`].
+#[must_use]
+pub struct NoneOfRange<I, R, E>(R, PhantomData<(I, E)>);
+
+impl<I, R: Copy, E> Copy for NoneOfRange<I, R, E> {}
+impl<I, R: Clone, E> Clone for NoneOfRange<I, R, E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone + PartialOrd, R: RangeBounds<I>, E: Error<I>> Parser<I, I> for NoneOfRange<I, R, E> {
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        _debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, I, E> {
+        match stream.next() {
+            (_, _, Some(tok)) if !self.0.contains(&tok) => (Vec::new(), Ok((tok, None))),
+            (at, span, found) => (
+                Vec::new(),
+                Err(Located::at(
+                    at,
+                    E::expected_input_found(span, Vec::new(), found),
+                )),
+            ),
+        }
+    }
+
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, I, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, I, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// A parser that accepts any input that is *not* within a range.
+///
+/// See [`one_of_range`] for the kinds of range that may be used.
+///
+/// The output type of this parser is `I`, the input that was found.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// // Anything but the control characters below a space
+/// let printable = none_of_range::<_, _, Simple<u8>>(..b' ')
+///     .repeated()
+///     .then_ignore(end());
+///
+/// assert_eq!(printable.parse(&b"hi!"[..]), Ok(b"hi!".to_vec()));
+/// assert!(printable.parse(&b"hi\n"[..]).is_err());
+/// ```
+pub fn none_of_range<I, R: RangeBounds<I>, E: Error<I>>(range: R) -> NoneOfRange<I, R, E> {
+    NoneOfRange(range, PhantomData)
+}
+
 /// See [`take_until`].
 #[must_use]
 #[derive(Copy, Clone)]
@@ -610,6 +917,7 @@ impl<I: Clone, O, A: Parser<I, O>> Parser<I, (Vec<I>, O)> for TakeUntil<A> {
                 (errors, Ok((out, a_alt))) => {
                     break (errors, Ok(((outputs, out), merge_alts(alt, a_alt))))
                 }
+                (errors, Err(err)) if err.committed => break (errors, Err(err.uncommit())),
                 (errors, Err(err)) => (errors, err),
             };
 
@@ -725,6 +1033,8 @@ impl<I: Clone, F: Fn(&I) -> bool, E: Error<I>> Parser<I, I> for Filter<F, E> {
 
 /// A parser that accepts only inputs that match the given predicate.
 ///
+/// The error produced when the predicate fails does not say what was expected. Use [`filter_labelled`] to describe it.
+///
 /// The output type of this parser is `I`, the input that was found.
 ///
 /// # Examples
@@ -743,6 +1053,34 @@ pub fn filter<I, F: Fn(&I) -> bool, E>(f: F) -> Filter<F, E> {
     Filter(f, PhantomData)
 }
 
+/// A parser that accepts only inputs that match the given predicate, like [`filter`], labelling its error with a
+/// description of what was expected.
+///
+/// This is useful for classes of input that have no natural list of tokens to enumerate in an error, such as
+/// hexadecimal digits. It is equivalent to `filter(f).labelled(label)`, so when it appears within other labelled
+/// patterns, the labels are combined as described for [`Parser::labelled`].
+///
+/// The output type of this parser is `I`, the input that was found.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let hex_digit = filter_labelled::<_, _, Simple<char>, _>(char::is_ascii_hexdigit, "hexadecimal digit");
+///
+/// assert_eq!(hex_digit.parse("f"), Ok('f'));
+///
+/// let errors = hex_digit.parse("g").unwrap_err();
+/// assert_eq!(errors[0].label(), Some("hexadecimal digit"));
+/// assert_eq!(errors[0].found(), Some(&'g'));
+/// ```
+pub fn filter_labelled<I, F: Fn(&I) -> bool, E: Error<I>, L: Into<E::Label> + Clone>(
+    f: F,
+    label: L,
+) -> Label<Filter<F, E>, L> {
+    Label(filter(f), label)
+}
+
 /// See [`filter_map`].
 #[must_use]
 pub struct FilterMap<F, E>(F, PhantomData<E>);
@@ -790,9 +1128,14 @@ impl<I: Clone, O, F: Fn(E::Span, I) -> Result<O, E>, E: Error<I>> Parser<I, O> f
 ///
 /// This function allows integration with custom error types to allow for custom parser errors.
 ///
+/// The function is given the span of the input as well as the input itself, so it can both build an output that
+/// carries its source location (such as the `(Token, Span)` pairs produced by a lexer) and produce an error at the
+/// precise location of the input. If the function returns `Err`, that error is produced (at the position of the
+/// input) instead.
+///
 /// Before using this function, consider whether the [`select`] macro would serve you better.
 ///
-/// The output type of this parser is `I`, the input that was found.
+/// The output type of this parser is `O`, the output of the function.
 ///
 /// # Examples
 ///
@@ -806,11 +1149,137 @@ impl<I: Clone, O, F: Fn(E::Span, I) -> Result<O, E>, E: Error<I>> Parser<I, O> f
 /// assert_eq!(numeral.parse("3"), Ok(3));
 /// assert_eq!(numeral.parse("7"), Ok(7));
 /// assert_eq!(numeral.parse("f"), Err(vec![Simple::custom(0..1, "'f' is not a digit")]));
+///
+/// // Tokens that remember where they came from
+/// let spanned_digit = filter_map(|span, c: char| match c.to_digit(10) {
+///     Some(x) => Ok((x, span)),
+///     None => Err(Simple::custom(span, format!("'{}' is not a digit", c))),
+/// });
+///
+/// assert_eq!(spanned_digit.repeated().parse("42"), Ok(vec![(4, 0..1), (2, 1..2)]));
 /// ```
 pub fn filter_map<I, O, F: Fn(E::Span, I) -> Result<O, E>, E: Error<I>>(f: F) -> FilterMap<F, E> {
     FilterMap(f, PhantomData)
 }
 
+/// See [`filter_map_with_state`].
+#[must_use]
+pub struct FilterMapWithState<F, E, St>(F, PhantomData<(E, St)>);
+
+impl<F: Copy, E, St> Copy for FilterMapWithState<F, E, St> {}
+impl<F: Clone, E, St> Clone for FilterMapWithState<F, E, St> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<I: Clone, O, St: core::any::Any, F: Fn(E::Span, I, &mut St) -> Result<O, E>, E: Error<I>>
+    Parser<I, O> for FilterMapWithState<F, E, St>
+{
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        _debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let (at, span, tok) = stream.next();
+        match tok.map(|tok| (self.0)(span.clone(), tok, stream.state())) {
+            Some(Ok(tok)) => (Vec::new(), Ok((tok, None))),
+            Some(Err(err)) => (Vec::new(), Err(Located::at(at, err))),
+            None => (
+                Vec::new(),
+                Err(Located::at(
+                    at,
+                    E::expected_input_found(span, Vec::new(), None),
+                )),
+            ),
+        }
+    }
+
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// A parser that accepts an input and tests it against the given fallible function, which may also consult and
+/// update the user-defined state attached to the stream with [`Stream::with_state`].
+///
+/// This is the token-level counterpart of [`Parser::try_map_with_state`], and makes context-sensitive classification
+/// of tokens possible in a single pass. A classic example is C's 'typedef problem', in which whether an identifier is
+/// a type name depends on the declarations that came before it.
+///
+/// Note that changes made to the state are not undone if the parser is later backtracked over, and that the state
+/// type `St` must be `'static` (see [`Stream::with_state`]).
+///
+/// The output type of this parser is `O`, the output of the function.
+///
+/// # Panics
+///
+/// Parsing will panic if no state was attached to the stream, or if the attached state is not of type `St` (such as
+/// when another stateful combinator in the same parser expects a different type).
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, Stream};
+/// use std::collections::HashSet;
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// enum Token { Typedef, Ident(String), Semi }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Class { Type(String), Var(String) }
+///
+/// // Classify identifiers according to whether they have been declared as types
+/// let name = filter_map_with_state(|span, tok, types: &mut HashSet<String>| match tok {
+///     Token::Ident(name) if types.contains(&name) => Ok(Class::Type(name)),
+///     Token::Ident(name) => Ok(Class::Var(name)),
+///     tok => Err(Simple::expected_input_found(span, None, Some(tok))),
+/// });
+///
+/// let typedef = just(Token::Typedef)
+///     .ignore_then(name)
+///     .try_map_with_state(|class, span, types: &mut HashSet<String>| match class {
+///         Class::Var(name) => {
+///             types.insert(name.clone());
+///             Ok(Class::Type(name))
+///         }
+///         Class::Type(name) => Err(Simple::custom(span, format!("`{}` is already a type", name))),
+///     });
+///
+/// let stmts = typedef.or(name).then_ignore(just(Token::Semi)).repeated();
+///
+/// let tokens = vec![
+///     Token::Ident("foo".to_string()), Token::Semi,
+///     Token::Typedef, Token::Ident("foo".to_string()), Token::Semi,
+///     Token::Ident("foo".to_string()), Token::Semi,
+/// ];
+/// let mut types = HashSet::<String>::new();
+/// let stream = Stream::from_iter(7..7, tokens.into_iter().enumerate().map(|(i, t)| (t, i..i + 1)));
+/// assert_eq!(
+///     stmts.parse(stream.with_state(&mut types)),
+///     Ok(vec![
+///         Class::Var("foo".to_string()),
+///         Class::Type("foo".to_string()),
+///         Class::Type("foo".to_string()),
+///     ]),
+/// );
+/// ```
+pub fn filter_map_with_state<I, O, St, F, E>(f: F) -> FilterMapWithState<F, E, St>
+where
+    St: core::any::Any,
+    F: Fn(E::Span, I, &mut St) -> Result<O, E>,
+    E: Error<I>,
+{
+    FilterMapWithState(f, PhantomData)
+}
+
 /// See [`any`].
 pub type Any<I, E> = Filter<fn(&I) -> bool, E>;
 
@@ -929,6 +1398,9 @@ impl<I: Clone, O, E: Error<I>, A: Parser<I, O, Error = E>, const N: usize> Parse
     ) -> PResult<I, O, Self::Error> {
         let Choice(parsers, _) = self;
         let mut alt = None;
+        // A committed failure ends the whole chain of alternatives that this is part of (see `Or`)
+        let chained = core::mem::take(&mut stream.chained);
+        let uncommit = |err: Located<I, E>| if chained { err } else { err.uncommit() };
 
         for parser in parsers {
             match stream.try_parse(|stream| {
@@ -936,6 +1408,7 @@ impl<I: Clone, O, E: Error<I>, A: Parser<I, O, Error = E>, const N: usize> Parse
                 debugger.invoke(parser, stream)
             }) {
                 (errors, Ok(out)) => return (errors, Ok(out)),
+                (errors, Err(err)) if err.committed => return (errors, Err(uncommit(err))),
                 (_, Err(a_alt)) => {
                     alt = merge_alts(alt.take(), Some(a_alt));
                 }
@@ -962,6 +1435,10 @@ impl<I: Clone, O, E: Error<I>, A: Parser<I, O, Error = E>, const N: usize> Parse
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
+
+    fn is_choice(&self) -> bool {
+        true
+    }
 }
 
 impl<I: Clone, O, E: Error<I>, A: Parser<I, O, Error = E>> Parser<I, O> for Choice<Vec<A>, E> {
@@ -974,6 +1451,9 @@ impl<I: Clone, O, E: Error<I>, A: Parser<I, O, Error = E>> Parser<I, O> for Choi
     ) -> PResult<I, O, Self::Error> {
         let Choice(parsers, _) = self;
         let mut alt = None;
+        // A committed failure ends the whole chain of alternatives that this is part of (see `Or`)
+        let chained = core::mem::take(&mut stream.chained);
+        let uncommit = |err: Located<I, E>| if chained { err } else { err.uncommit() };
 
         for parser in parsers {
             match stream.try_parse(|stream| {
@@ -981,6 +1461,7 @@ impl<I: Clone, O, E: Error<I>, A: Parser<I, O, Error = E>> Parser<I, O> for Choi
                 debugger.invoke(parser, stream)
             }) {
                 (errors, Ok(out)) => return (errors, Ok(out)),
+                (errors, Err(err)) if err.committed => return (errors, Err(uncommit(err))),
                 (_, Err(a_alt)) => {
                     alt = merge_alts(alt.take(), Some(a_alt));
                 }
@@ -1007,6 +1488,10 @@ impl<I: Clone, O, E: Error<I>, A: Parser<I, O, Error = E>> Parser<I, O> for Choi
         #[allow(deprecated)]
         self.parse_inner(d, s)
     }
+
+    fn is_choice(&self) -> bool {
+        true
+    }
 }
 
 macro_rules! impl_for_tuple {
@@ -1027,12 +1512,15 @@ macro_rules! impl_for_tuple {
             ) -> PResult<I, O, Self::Error> {
                 let Choice(($($X,)*), _) = self;
                 let mut alt = None;
+                let chained = core::mem::take(&mut stream.chained);
+                let uncommit = |err: Located<I, E>| if chained { err } else { err.uncommit() };
                 $(
                     match stream.try_parse(|stream| {
                         #[allow(deprecated)]
                         debugger.invoke($X, stream)
                     }) {
                         (errors, Ok(out)) => return (errors, Ok(out)),
+                        (errors, Err(err)) if err.committed => return (errors, Err(uncommit(err))),
                         (errors, Err(a_alt)) => {
                             alt = merge_alts(alt.take(), Some(a_alt));
                         },
@@ -1057,6 +1545,10 @@ macro_rules! impl_for_tuple {
                 #[allow(deprecated)]
                 self.parse_inner(d, s)
             }
+
+            fn is_choice(&self) -> bool {
+                true
+            }
         }
     };
 }
@@ -1140,3 +1632,116 @@ impl_for_tuple!(A_ B_ C_ D_ E_ F_ G_ H_ I_ J_ K_ L_ M_ N_ O_ P_ Q_ S_ T_ U_ V_ W
 pub fn choice<T, E>(parsers: T) -> Choice<T, E> {
     Choice(parsers, PhantomData)
 }
+
+/// See [`group`].
+#[must_use]
+pub struct Group<T, E>(pub(crate) T, pub(crate) PhantomData<E>);
+
+impl<T: Copy, E> Copy for Group<T, E> {}
+impl<T: Clone, E> Clone for Group<T, E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+macro_rules! impl_group_for_tuple {
+    () => {};
+    ($head:ident $ohead:ident $($X:ident $O:ident)*) => {
+        impl_group_for_tuple!($($X $O)*);
+        impl_group_for_tuple!(~ $head $ohead $($X $O)*);
+    };
+    (~ $($X:ident $O:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<I: Clone, E: Error<I>, $($X: Parser<I, $O, Error = E>, $O),*> Parser<I, ($($O,)*)> for Group<($($X,)*), E> {
+            type Error = E;
+
+            fn parse_inner<D: Debugger>(
+                &self,
+                debugger: &mut D,
+                stream: &mut StreamOf<I, Self::Error>,
+            ) -> PResult<I, ($($O,)*), Self::Error> {
+                let Group(($($X,)*), _) = self;
+                let mut errors = Vec::new();
+                let mut alt = None;
+                $(
+                    let $O = match {
+                        #[allow(deprecated)]
+                        debugger.invoke($X, stream)
+                    } {
+                        (mut a_errors, Ok((out, a_alt))) => {
+                            errors.append(&mut a_errors);
+                            alt = merge_alts(alt.take(), a_alt);
+                            out
+                        },
+                        (mut a_errors, Err(err)) => {
+                            errors.append(&mut a_errors);
+                            return (errors, Err(err.max(alt)));
+                        },
+                    };
+                )*
+                (errors, Ok((($($O,)*), alt)))
+            }
+
+            fn parse_inner_verbose(
+                &self,
+                d: &mut Verbose,
+                s: &mut StreamOf<I, Self::Error>,
+            ) -> PResult<I, ($($O,)*), Self::Error> {
+                #[allow(deprecated)]
+                self.parse_inner(d, s)
+            }
+            fn parse_inner_silent(
+                &self,
+                d: &mut Silent,
+                s: &mut StreamOf<I, Self::Error>,
+            ) -> PResult<I, ($($O,)*), Self::Error> {
+                #[allow(deprecated)]
+                self.parse_inner(d, s)
+            }
+        }
+    };
+}
+
+impl_group_for_tuple!(A_ OA B_ OB C_ OC D_ OD E_ OE F_ OF G_ OG H_ OH I_ OI J_ OJ K_ OK L_ OL);
+
+/// Parse using a tuple of many parsers in sequence, producing a flat tuple of their outputs.
+///
+/// This is equivalent to a chain of [`Parser::then`] calls, but the output is a flat tuple like `(A, B, C)` rather than
+/// a nested one like `((A, B), C)`, which makes it easier to destructure. Tuples of up to 12 parsers are supported. All
+/// of the parsers must have the same input and error types.
+///
+/// The output type of this parser is a tuple of the outputs of the inner parsers.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// #[derive(Debug, PartialEq)]
+/// struct Field {
+///     name: String,
+///     ty: String,
+///     public: bool,
+/// }
+///
+/// let ident = text::ident::<_, Simple<char>>().padded();
+///
+/// let field = group((
+///     text::keyword("pub").padded().or_not(),
+///     ident,
+///     just(':'),
+///     ident,
+/// ))
+///     .map(|(public, name, _, ty)| Field { name, ty, public: public.is_some() });
+///
+/// assert_eq!(
+///     field.parse("pub x: i32"),
+///     Ok(Field { name: "x".to_string(), ty: "i32".to_string(), public: true }),
+/// );
+/// assert_eq!(
+///     field.parse("y: bool"),
+///     Ok(Field { name: "y".to_string(), ty: "bool".to_string(), public: false }),
+/// );
+/// ```
+pub fn group<T, E>(parsers: T) -> Group<T, E> {
+    Group(parsers, PhantomData)
+}
@@ -0,0 +1,125 @@
+//! Tools for measuring the performance of parsers.
+//!
+//! *“Time is an illusion. Lunchtime doubly so.”*
+//!
+//! When the `profile` feature is enabled, every parser named with [`Parser::debug`] records how many times it was
+//! invoked, how many of those invocations failed, and how many inputs it consumed. These counters are kept in a
+//! thread-local registry that can be inspected after parsing with [`entries`] or [`table`].
+//!
+//! This is useful for finding hot parsers and for spotting patterns that are being parsed repeatedly due to
+//! backtracking. When the feature is disabled, no counters are recorded and there is no runtime cost.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chumsky::{prelude::*, profile};
+//! let digit = filter::<_, _, Simple<char>>(|c: &char| c.is_ascii_digit()).debug("digit");
+//! let number = digit.repeated().at_least(1).debug("number");
+//!
+//! profile::reset();
+//! number.parse("123").unwrap();
+//!
+//! let entries = profile::entries();
+//! let digit = entries.iter().find(|e| e.name == "digit").unwrap();
+//! assert_eq!(digit.invocations, 4);
+//! assert_eq!(digit.failures, 1);
+//! assert_eq!(digit.inputs_consumed, 3);
+//! ```
+
+use super::*;
+use std::{cell::RefCell, collections::HashMap, string::ToString};
+
+/// The counters recorded for a single named parser.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileEntry {
+    /// The name given to the parser with [`Parser::debug`].
+    pub name: String,
+    /// The location in the source code at which the parser was named.
+    pub location: Location<'static>,
+    /// The number of times the parser was invoked.
+    pub invocations: usize,
+    /// The number of invocations that failed.
+    pub failures: usize,
+    /// The total number of inputs consumed by successful invocations.
+    pub inputs_consumed: usize,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<HashMap<(String, Location<'static>), ProfileEntry>> = RefCell::new(HashMap::new());
+}
+
+pub(crate) fn record(
+    name: &dyn fmt::Display,
+    location: Location<'static>,
+    inputs_consumed: usize,
+    failed: bool,
+) {
+    REGISTRY.with(|registry| {
+        let name = name.to_string();
+        let mut registry = registry.borrow_mut();
+        let entry = registry
+            .entry((name.clone(), location))
+            .or_insert_with(|| ProfileEntry {
+                name,
+                location,
+                invocations: 0,
+                failures: 0,
+                inputs_consumed: 0,
+            });
+        entry.invocations += 1;
+        if failed {
+            entry.failures += 1;
+        } else {
+            entry.inputs_consumed += inputs_consumed;
+        }
+    });
+}
+
+/// Clear all counters recorded on the current thread.
+pub fn reset() {
+    REGISTRY.with(|registry| registry.borrow_mut().clear());
+}
+
+/// Get the counters recorded on the current thread, sorted by the number of invocations (most invoked first).
+pub fn entries() -> Vec<ProfileEntry> {
+    let mut entries =
+        REGISTRY.with(|registry| registry.borrow().values().cloned().collect::<Vec<_>>());
+    entries.sort_by(|a, b| {
+        b.invocations
+            .cmp(&a.invocations)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    entries
+}
+
+/// Format the counters recorded on the current thread as a table, sorted by the number of invocations (most invoked
+/// first).
+pub fn table() -> String {
+    let entries = entries();
+    let name_width = entries
+        .iter()
+        .map(|e| e.name.len())
+        .chain(Some("parser".len()))
+        .max()
+        .unwrap_or(0);
+    let mut table = format!(
+        "{:<width$}  {:>11}  {:>15}  {:>8}  location\n",
+        "parser",
+        "invocations",
+        "inputs consumed",
+        "failures",
+        width = name_width,
+    );
+    for e in entries {
+        table += &format!(
+            "{:<width$}  {:>11}  {:>15}  {:>8}  {}\n",
+            e.name,
+            e.invocations,
+            e.inputs_consumed,
+            e.failures,
+            e.location,
+            width = name_width,
+        );
+    }
+    table
+}
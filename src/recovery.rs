@@ -24,6 +24,7 @@ pub struct SkipThenRetryUntil<I, const N: usize>(
     pub(crate) [I; N],
     pub(crate) bool,
     pub(crate) bool,
+    pub(crate) bool,
 );
 
 impl<I, const N: usize> SkipThenRetryUntil<I, N> {
@@ -32,7 +33,7 @@ impl<I, const N: usize> SkipThenRetryUntil<I, N> {
     /// This is useful when the input being searched for also appears at the beginning of the pattern that failed to
     /// parse.
     pub fn skip_start(self) -> Self {
-        Self(self.0, self.1, true)
+        Self(self.0, self.1, true, self.3)
     }
 
     /// Alters this recovery strategy so that the synchronisation token will be consumed during recovery.
@@ -40,7 +41,15 @@ impl<I, const N: usize> SkipThenRetryUntil<I, N> {
     /// This is useful when the input being searched for is a delimiter of a prior pattern rather than the start of a
     /// new pattern and hence is no longer important once recovery has occurred.
     pub fn consume_end(self) -> Self {
-        Self(self.0, true, self.2)
+        Self(self.0, true, self.2, self.3)
+    }
+
+    /// Alters this recovery strategy so that the span of the input skipped during recovery is attached to the
+    /// emitted error via [`Error::with_related_span`].
+    ///
+    /// This is useful for editor integrations that wish to highlight the region of input that was ignored.
+    pub fn record_skipped(self) -> Self {
+        Self(self.0, self.1, self.2, true)
     }
 }
 
@@ -55,6 +64,7 @@ impl<I: Clone + PartialEq, O, E: Error<I>, const N: usize> Strategy<I, O, E>
         debugger: &mut D,
         stream: &mut StreamOf<I, P::Error>,
     ) -> PResult<I, O, P::Error> {
+        let pre_state = stream.save();
         let _ = stream.next();
         if self.2 {
             let _ = stream.next();
@@ -70,10 +80,16 @@ impl<I: Clone + PartialEq, O, E: Error<I>, const N: usize> Strategy<I, O, E>
             ) {
                 break (a_errors, Err(a_err));
             }
+            let retry_state = stream.save();
             #[allow(deprecated)]
             let (mut errors, res) = debugger.invoke(&parser, stream);
             if let Ok(out) = res {
-                errors.push(a_err);
+                if self.3 {
+                    let skipped = stream.span_between(pre_state, retry_state);
+                    errors.push(a_err.map(|e| e.with_related_span(skipped)));
+                } else {
+                    errors.push(a_err);
+                }
                 break (errors, Ok(out));
             }
         }
@@ -83,12 +99,116 @@ impl<I: Clone + PartialEq, O, E: Error<I>, const N: usize> Strategy<I, O, E>
 /// A recovery mode that simply skips to the next input on parser failure and tries again, until reaching one of
 /// several inputs.
 ///
-/// Also see [`SkipThenRetryUntil::consume_end`].
+/// Also see [`SkipThenRetryUntil::consume_end`] and [`SkipThenRetryUntil::record_skipped`].
 ///
 /// This strategy is very 'stupid' and can result in very poor error generation in some languages. Place this strategy
 /// after others as a last resort, and be careful about over-using it.
 pub fn skip_then_retry_until<I, const N: usize>(until: [I; N]) -> SkipThenRetryUntil<I, N> {
-    SkipThenRetryUntil(until, false, false)
+    SkipThenRetryUntil(until, false, false, false)
+}
+
+/// See [`skip_then_retry_at_indent`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct SkipThenRetryAtIndent(pub(crate) ());
+
+impl<C: text::Character, O, E: Error<C>> Strategy<C, O, E> for SkipThenRetryAtIndent {
+    fn recover<D: Debugger, P: Parser<C, O, Error = E>>(
+        &self,
+        a_errors: Vec<Located<C, P::Error>>,
+        a_err: Located<C, P::Error>,
+        parser: P,
+        debugger: &mut D,
+        stream: &mut StreamOf<C, P::Error>,
+    ) -> PResult<C, O, P::Error> {
+        let newline = C::from_ascii(b'\n');
+        let pre_state = stream.save();
+
+        // Find the start of the line on which the failed pattern begins
+        let mut line_start = pre_state;
+        while line_start > 0 {
+            stream.revert(line_start - 1);
+            if stream.next().2 == Some(newline) {
+                break;
+            }
+            line_start -= 1;
+        }
+        stream.revert(line_start);
+        let level = indentation(stream);
+
+        stream.revert(pre_state);
+        loop {
+            // Skip to the start of the next line
+            loop {
+                match stream.next().2 {
+                    Some(c) if c == newline => break,
+                    Some(_) => {}
+                    None => return (a_errors, Err(a_err)),
+                }
+            }
+
+            let indent = indentation(stream);
+            let content_start = stream.save();
+            match stream.next().2 {
+                // Blank lines don't tell us anything about the structure of the input
+                Some(c) if c == newline => stream.revert(content_start),
+                // A dedent means that there are no more siblings to resynchronise with
+                Some(_) if indent < level => return (a_errors, Err(a_err)),
+                Some(_) if indent == level => {
+                    stream.revert(content_start);
+                    #[allow(deprecated)]
+                    let (mut errors, res) = debugger.invoke(&parser, stream);
+                    if let Ok(out) = res {
+                        errors.push(a_err);
+                        break (errors, Ok(out));
+                    }
+                    stream.revert(content_start);
+                }
+                Some(_) => stream.revert(content_start),
+                None => return (a_errors, Err(a_err)),
+            }
+        }
+    }
+}
+
+// Consume the inline whitespace at the current position of the stream, returning its length
+fn indentation<C: text::Character>(stream: &mut Stream<C, impl Span>) -> usize {
+    let mut indent = 0;
+    while stream.skip_if(|c| c.is_inline_whitespace()) {
+        indent += 1;
+    }
+    indent
+}
+
+/// A recovery strategy for layout-sensitive (i.e: indentation-based) languages that skips to the next line with the
+/// same indentation as the line on which the failed pattern began, and then tries again.
+///
+/// In languages like Python or Haskell, the line with the same indentation as a malformed statement is usually the
+/// start of the next sibling statement within the same block. Blank lines and more deeply indented lines (such as the
+/// body of the malformed statement) are skipped. If a line with a lower indentation (i.e: the end of the enclosing
+/// block) or the end of the input is found first, recovery fails rather than consuming the rest of the block.
+///
+/// Indentation is measured as the number of inline whitespace characters at the start of a line. The pattern is
+/// retried from the first non-whitespace character of the line.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let stmt = text::ident::<_, Simple<char>>()
+///     .then_ignore(just(" = "))
+///     .then_ignore(text::int(10))
+///     .then_ignore(just('\n'))
+///     .recover_with(skip_then_retry_at_indent());
+///
+/// let src = "a = 1\nb = (\n    2\n  )\nc = 3\n";
+/// let (stmts, errors) = stmt.repeated().then_ignore(end()).parse_recovery(src);
+/// assert_eq!(errors.len(), 1);
+/// // `b`, including the lines indented beneath it, was skipped
+/// assert_eq!(stmts, Some(vec!["a".to_string(), "c".to_string()]));
+/// ```
+pub fn skip_then_retry_at_indent() -> SkipThenRetryAtIndent {
+    SkipThenRetryAtIndent(())
 }
 
 /// See [`skip_until`].
@@ -99,6 +219,7 @@ pub struct SkipUntil<I, F, const N: usize>(
     pub(crate) F,
     pub(crate) bool,
     pub(crate) bool,
+    pub(crate) bool,
 );
 
 impl<I, F, const N: usize> SkipUntil<I, F, N> {
@@ -107,7 +228,7 @@ impl<I, F, const N: usize> SkipUntil<I, F, N> {
     /// This is useful when the input being searched for also appears at the beginning of the pattern that failed to
     /// parse.
     pub fn skip_start(self) -> Self {
-        Self(self.0, self.1, self.2, true)
+        Self(self.0, self.1, self.2, true, self.4)
     }
 
     /// Alters this recovery strategy so that the synchronisation token will be consumed during recovery.
@@ -115,7 +236,15 @@ impl<I, F, const N: usize> SkipUntil<I, F, N> {
     /// This is useful when the input being searched for is a delimiter of a prior pattern rather than the start of a
     /// new pattern and hence is no longer important once recovery has occurred.
     pub fn consume_end(self) -> Self {
-        Self(self.0, self.1, true, self.3)
+        Self(self.0, self.1, true, self.3, self.4)
+    }
+
+    /// Alters this recovery strategy so that the span of the input skipped during recovery is attached to the
+    /// emitted error via [`Error::with_related_span`].
+    ///
+    /// This is useful for editor integrations that wish to highlight the region of input that was ignored.
+    pub fn record_skipped(self) -> Self {
+        Self(self.0, self.1, self.2, self.3, true)
     }
 }
 
@@ -134,8 +263,7 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
         if self.3 {
             let _ = stream.next();
         }
-        a_errors.push(a_err);
-        loop {
+        let end = loop {
             match stream.attempt(|stream| {
                 let (at, span, tok) = stream.next();
                 match tok.map(|tok| self.0.contains(&tok)) {
@@ -144,20 +272,31 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
                     None => (true, Err((at, span))),
                 }
             }) {
-                Ok(true) => break (a_errors, Ok(((self.1)(stream.span_since(pre_state)), None))),
+                Ok(true) => break Ok(()),
                 Ok(false) => {}
-                Err(_) if stream.save() > pre_state => {
-                    break (a_errors, Ok(((self.1)(stream.span_since(pre_state)), None)))
-                }
-                Err((at, span)) => {
-                    break (
-                        a_errors,
-                        Err(Located::at(
-                            at,
-                            E::expected_input_found(span, self.0.iter().cloned().map(Some), None),
-                        )),
-                    )
+                Err(_) if stream.save() > pre_state => break Ok(()),
+                Err(end) => break Err(end),
+            }
+        };
+        match end {
+            Ok(()) => {
+                let span = stream.span_since(pre_state);
+                if self.4 {
+                    a_errors.push(a_err.map(|e| e.with_related_span(span.clone())));
+                } else {
+                    a_errors.push(a_err);
                 }
+                (a_errors, Ok(((self.1)(span), None)))
+            }
+            Err((at, span)) => {
+                a_errors.push(a_err);
+                (
+                    a_errors,
+                    Err(Located::at(
+                        at,
+                        E::expected_input_found(span, self.0.iter().cloned().map(Some), None),
+                    )),
+                )
             }
         }
     }
@@ -165,12 +304,43 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
 
 /// A recovery mode that skips input until one of several inputs is found.
 ///
-/// Also see [`SkipUntil::consume_end`].
+/// Unlike [`skip_then_retry_until`], the failed pattern is not attempted again: once one of the inputs is found, the
+/// parser instead produces the output of `fallback`, which is given the span of the skipped input. The input that was
+/// found is left for the next pattern to parse, unless [`SkipUntil::consume_end`] is used. This is sometimes called
+/// 'panic mode' recovery.
+///
+/// Also see [`SkipUntil::consume_end`] and [`SkipUntil::record_skipped`].
 ///
 /// This strategy is very 'stupid' and can result in very poor error generation in some languages. Place this strategy
 /// after others as a last resort, and be careful about over-using it.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// #[derive(Debug, PartialEq)]
+/// enum Stmt {
+///     Expr(String),
+///     Error,
+/// }
+///
+/// // On failure, discard everything up to and including the next semicolon
+/// let stmt = text::ident::<_, Simple<char>>()
+///     .map(Stmt::Expr)
+///     .then_ignore(just(';'))
+///     .padded()
+///     .recover_with(skip_until([';'], |_| Stmt::Error).consume_end());
+///
+/// let (stmts, errors) = stmt.repeated().then_ignore(end()).parse_recovery("a; b c; d;");
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(stmts, Some(vec![
+///     Stmt::Expr("a".to_string()),
+///     Stmt::Error,
+///     Stmt::Expr("d".to_string()),
+/// ]));
+/// ```
 pub fn skip_until<I, F, const N: usize>(until: [I; N], fallback: F) -> SkipUntil<I, F, N> {
-    SkipUntil(until, fallback, false, false)
+    SkipUntil(until, fallback, false, false, false)
 }
 
 /// See [`nested_delimiters`].
@@ -280,6 +450,9 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
 
         if let Some(e) = error {
             a_errors.push(e);
+            if recovered {
+                stream.count_errors(1);
+            }
         }
 
         if recovered {
@@ -299,7 +472,37 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
 /// example, you might want to also specify `[('[', ']'), ('{', '}')]` when recovering a parenthesised expression as
 /// this can aid in detecting delimiter mismatches.
 ///
-/// A function that generates a fallback output on recovery is also required.
+/// A function that generates a fallback output on recovery is also required. It is given the span of the input that
+/// was skipped, from the start delimiter to the end delimiter inclusive, so that the fallback output can record where
+/// the error occurred. If the span isn't needed, it can be ignored with a closure like `|_| Expr::Error`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// #[derive(Debug, PartialEq)]
+/// enum Expr {
+///     Int(String),
+///     List(Vec<Expr>),
+///     Error(std::ops::Range<usize>),
+/// }
+///
+/// let expr = recursive::<_, _, _, _, Simple<char>>(|expr| expr
+///     .separated_by(just(','))
+///     .delimited_by(just('['), just(']'))
+///     .map(Expr::List)
+///     .recover_with(nested_delimiters('[', ']', [], Expr::Error))
+///     .or(text::int(10).map(Expr::Int))
+///     .padded());
+///
+/// let (ast, errors) = expr.parse_recovery("[1, [2 3], 4]");
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(ast, Some(Expr::List(vec![
+///     Expr::Int("1".to_string()),
+///     Expr::Error(4..9),
+///     Expr::Int("4".to_string()),
+/// ])));
+/// ```
 pub fn nested_delimiters<I: PartialEq, F, const N: usize>(
     start: I,
     end: I,
@@ -313,6 +516,416 @@ pub fn nested_delimiters<I: PartialEq, F, const N: usize>(
     NestedDelimiters(start, end, others, fallback)
 }
 
+/// See [`nested_delimiters_any`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct NestedDelimitersAny<I, F, const N: usize>(pub(crate) [(I, I); N], pub(crate) F);
+
+impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize> Strategy<I, O, E>
+    for NestedDelimitersAny<I, F, N>
+{
+    fn recover<D: Debugger, P: Parser<I, O, Error = E>>(
+        &self,
+        mut a_errors: Vec<Located<I, P::Error>>,
+        a_err: Located<I, P::Error>,
+        _parser: P,
+        _debugger: &mut D,
+        stream: &mut StreamOf<I, P::Error>,
+    ) -> PResult<I, O, P::Error> {
+        // The delimiter pairs of the sections that are currently open, innermost last, with the spans of their starts
+        let mut open: Vec<(&(I, I), E::Span)> = Vec::new();
+        let mut error = None;
+        let pre_state = stream.save();
+        let recovered = loop {
+            let (at, span, tok) = stream.next();
+            let tok = match tok {
+                Some(tok) => tok,
+                None => {
+                    if let Some(((start, end), start_span)) = open.pop() {
+                        error.get_or_insert_with(|| {
+                            Located::at(
+                                at,
+                                P::Error::unclosed_delimiter(
+                                    start_span,
+                                    start.clone(),
+                                    span,
+                                    end.clone(),
+                                    None,
+                                ),
+                            )
+                        });
+                    }
+                    break false;
+                }
+            };
+
+            if let Some(pair) = self.0.iter().find(|(start, _)| *start == tok) {
+                open.push((pair, span));
+            } else if open.is_empty() {
+                // A non-delimiter input before anything else is not a valid recovery pattern
+                break false;
+            } else if self.0.iter().any(|(_, end)| *end == tok) {
+                // An end delimiter closes the innermost section that it matches: any sections inside that one were
+                // never closed. An end delimiter that matches no open section probably belongs to an enclosing pattern,
+                // so it is not a valid recovery pattern.
+                let idx = match open.iter().rposition(|((_, end), _)| *end == tok) {
+                    Some(idx) => idx,
+                    None => break false,
+                };
+                if idx + 1 < open.len() {
+                    let ((start, end), start_span) = open.last().unwrap();
+                    error.get_or_insert_with(|| {
+                        Located::at(
+                            at,
+                            P::Error::unclosed_delimiter(
+                                start_span.clone(),
+                                start.clone(),
+                                span,
+                                end.clone(),
+                                Some(tok),
+                            ),
+                        )
+                    });
+                }
+                open.truncate(idx);
+                if open.is_empty() {
+                    break true;
+                }
+            }
+        };
+
+        if recovered {
+            // Mismatched delimiters are only reported if recovery succeeds, since otherwise the enclosing pattern may
+            // encounter them again
+            if let Some(e) = error {
+                a_errors.push(e);
+            }
+            if !matches!(a_errors.last(), Some(e) if e.at <= a_err.at) {
+                a_errors.push(a_err);
+            }
+            (a_errors, Ok(((self.1)(stream.span_since(pre_state)), None)))
+        } else {
+            (a_errors, Err(a_err))
+        }
+    }
+}
+
+/// A recovery strategy that searches for a start delimiter and its matching end delimiter, respecting nesting, where
+/// the delimiters may be any of several pairs.
+///
+/// This is like [`nested_delimiters`], but the pattern being recovered may start with the start delimiter of any of
+/// the given pairs, and all of the pairs are balanced against one another: recovery succeeds once the section opened by
+/// the first delimiter has been closed by its own end delimiter. This allows a single strategy to recover from errors
+/// in any kind of delimited section, such as the mixture of `()`, `[]` and `{}` found in C-like languages.
+///
+/// An end delimiter that doesn't match the innermost open section closes the innermost section that it *does* match,
+/// and any sections within that one are reported as unclosed. An end delimiter that doesn't match any open section
+/// probably belongs to an enclosing pattern, so recovery fails (allowing the enclosing pattern to recover instead).
+///
+/// A function that generates a fallback output on recovery is also required. Like [`nested_delimiters`], it is given
+/// the span of the input that was skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// #[derive(Debug, PartialEq)]
+/// enum Expr {
+///     Int(String),
+///     Group(Vec<Expr>),
+///     Error,
+/// }
+///
+/// let expr = recursive::<_, _, _, _, Simple<char>>(|expr| {
+///     let items = expr.separated_by(just(','));
+///     items.clone()
+///         .delimited_by(just('('), just(')'))
+///         .or(items.clone().delimited_by(just('['), just(']')))
+///         .or(items.delimited_by(just('{'), just('}')))
+///         .map(Expr::Group)
+///         .recover_with(nested_delimiters_any([('(', ')'), ('[', ']'), ('{', '}')], |_| Expr::Error))
+///         .or(text::int(10).map(Expr::Int))
+///         .padded()
+/// });
+///
+/// let (ast, errors) = expr.parse_recovery("{(1 2), [3 4], 5}");
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(ast, Some(Expr::Group(vec![Expr::Error, Expr::Error, Expr::Int("5".to_string())])));
+/// ```
+pub fn nested_delimiters_any<I: PartialEq, F, const N: usize>(
+    pairs: [(I, I); N],
+    fallback: F,
+) -> NestedDelimitersAny<I, F, N> {
+    assert!(
+        pairs.iter().all(|(start, end)| start != end),
+        "Start and end delimiters cannot be the same when using `NestedDelimitersAny`"
+    );
+    NestedDelimitersAny(pairs, fallback)
+}
+
+/// See [`incomplete`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct Incomplete<F>(pub(crate) F);
+
+impl<I: Clone, O, F: Fn(E::Span) -> O, E: Error<I>> Strategy<I, O, E> for Incomplete<F> {
+    fn recover<D: Debugger, P: Parser<I, O, Error = E>>(
+        &self,
+        mut a_errors: Vec<Located<I, P::Error>>,
+        a_err: Located<I, P::Error>,
+        _parser: P,
+        _debugger: &mut D,
+        stream: &mut StreamOf<I, P::Error>,
+    ) -> PResult<I, O, P::Error> {
+        let pre_state = stream.save();
+        stream.revert(a_err.at);
+        if stream.next().2.is_some() {
+            // The pattern failed for some reason other than running out of input, so it's not incomplete
+            stream.revert(pre_state);
+            return (a_errors, Err(a_err));
+        }
+        stream.revert(a_err.at);
+        let span = stream.span_since(pre_state);
+        a_errors.push(a_err);
+        (a_errors, Ok(((self.0)(span), None)))
+    }
+}
+
+/// A recovery strategy that applies only when a pattern fails because the input ended before it was complete, such as
+/// an `if` with no body while the user is still typing it.
+///
+/// The error is still emitted, but the parser instead produces the output of `fallback`, which is given the span of
+/// the truncated pattern (from its start up to the end of the input). This allows an enclosing pattern to produce a
+/// partial AST node in which the missing part is replaced with a placeholder, so tooling such as a language server can
+/// tell exactly which node is incomplete and continue to provide completion or hover information at the cursor.
+///
+/// If the pattern fails for any other reason, this strategy does not recover.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// #[derive(Debug, PartialEq)]
+/// enum Expr {
+///     Num(String),
+///     If(Box<Expr>, Box<Expr>),
+///     Incomplete(std::ops::Range<usize>),
+/// }
+///
+/// let expr = recursive::<_, _, _, _, Simple<char>>(|expr| {
+///     let num = text::int(10).padded().map(Expr::Num);
+///     let body = expr
+///         .delimited_by(just('{'), just('}'))
+///         .padded()
+///         .recover_with(incomplete(Expr::Incomplete));
+///     text::keyword("if")
+///         .ignore_then(num.clone())
+///         .then(body)
+///         .map(|(cond, body)| Expr::If(Box::new(cond), Box::new(body)))
+///         .or(num)
+/// })
+/// .then_ignore(end());
+///
+/// let (ast, errors) = expr.parse_recovery("if 1 { 2");
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(
+///     ast,
+///     Some(Expr::If(Box::new(Expr::Num("1".to_string())), Box::new(Expr::Incomplete(5..8)))),
+/// );
+///
+/// // Input that is wrong, rather than incomplete, is not recovered
+/// assert!(expr.parse_recovery("if 1 { 2 ]").0.is_none());
+/// ```
+pub fn incomplete<F>(fallback: F) -> Incomplete<F> {
+    Incomplete(fallback)
+}
+
+/// See [`via_parser`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct ViaParser<A>(pub(crate) A);
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, E: Error<I>> Strategy<I, O, E> for ViaParser<A> {
+    fn recover<D: Debugger, P: Parser<I, O, Error = E>>(
+        &self,
+        mut a_errors: Vec<Located<I, P::Error>>,
+        a_err: Located<I, P::Error>,
+        _parser: P,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, P::Error>,
+    ) -> PResult<I, O, P::Error> {
+        let pre_state = stream.save();
+        #[allow(deprecated)]
+        let (mut b_errors, res) = debugger.invoke(&self.0, stream);
+        match res {
+            Ok(out) => {
+                a_errors.append(&mut b_errors);
+                a_errors.push(a_err);
+                (a_errors, Ok(out))
+            }
+            Err(_) => {
+                stream.revert(pre_state);
+                (a_errors, Err(a_err))
+            }
+        }
+    }
+}
+
+/// A recovery strategy that attempts to parse the failed pattern with another parser instead, such as a looser
+/// version of the original pattern.
+///
+/// The fallback parser begins at the same position as the failed pattern. If it succeeds, the error is still emitted,
+/// but its output is used in place of the output of the failed pattern. If it fails, this strategy does not recover.
+///
+/// The fallback parser must produce the same output and error types as the parser being recovered.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// #[derive(Debug, PartialEq)]
+/// enum Expr {
+///     Call(String, Vec<String>),
+///     Error(String),
+/// }
+///
+/// let call = text::ident::<_, Simple<char>>()
+///     .then(text::ident().padded().separated_by(just(',')).delimited_by(just('('), just(')')))
+///     .map(|(name, args)| Expr::Call(name, args));
+/// // If a call is malformed, salvage everything up to the end of the line
+/// let salvage = filter(|c: &char| *c != '\n')
+///     .repeated()
+///     .at_least(1)
+///     .collect::<String>()
+///     .map(Expr::Error);
+/// let expr = call.recover_with(via_parser(salvage));
+///
+/// assert_eq!(expr.parse("f(a, b)"), Ok(Expr::Call("f".to_string(), vec!["a".to_string(), "b".to_string()])));
+///
+/// let (ast, errors) = expr.parse_recovery("f(a b)");
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(ast, Some(Expr::Error("f(a b)".to_string())));
+/// ```
+pub fn via_parser<A>(parser: A) -> ViaParser<A> {
+    ViaParser(parser)
+}
+
+/// See [`insert_missing`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct InsertMissing<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I: Clone, O, F: Fn() -> O, E: Error<I>> Strategy<I, O, E> for InsertMissing<I, F> {
+    fn recover<D: Debugger, P: Parser<I, O, Error = E>>(
+        &self,
+        _a_errors: Vec<Located<I, P::Error>>,
+        _a_err: Located<I, P::Error>,
+        _parser: P,
+        _debugger: &mut D,
+        stream: &mut StreamOf<I, P::Error>,
+    ) -> PResult<I, O, P::Error> {
+        // No input is consumed, so any errors produced while attempting the pattern will be produced again by
+        // whatever parses the input next
+        let at = stream.save();
+        let (span, found) = stream.attempt(|stream| {
+            let (_, span, found) = stream.next();
+            (false, (span, found))
+        });
+        let error = E::expected_input_found(span, Some(Some(self.0.clone())), found);
+        (alloc::vec![Located::at(at, error)], Ok(((self.1)(), None)))
+    }
+}
+
+/// A recovery strategy that assumes that a missing input (such as a closing delimiter) was present.
+///
+/// An error is emitted stating that the input was expected, but no input is consumed: the parser instead produces
+/// the output of `make_output` as if the input had been parsed, and parsing continues from the same position. This
+/// should generally be applied to the parser for the missing input itself, rather than to a larger pattern.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let int = text::int::<_, Simple<char>>(10).padded();
+/// let sum = int
+///     .separated_by(just('+'))
+///     .at_least(1)
+///     .delimited_by(just('('), just(')').recover_with(insert_missing(')', || ')')));
+///
+/// assert_eq!(sum.parse("(1 + 2)"), Ok(vec!["1".to_string(), "2".to_string()]));
+///
+/// let (ast, errors) = sum.then_ignore(end()).parse_recovery("(1 + 2");
+/// assert_eq!(ast, Some(vec!["1".to_string(), "2".to_string()]));
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].expected().collect::<Vec<_>>(), vec![&Some(')')]);
+/// ```
+pub fn insert_missing<I, F>(input: I, make_output: F) -> InsertMissing<I, F> {
+    InsertMissing(input, make_output)
+}
+
+macro_rules! impl_strategy_for_tuple {
+    () => {};
+    ($head:ident $($X:ident)*) => {
+        impl_strategy_for_tuple!($($X)*);
+        impl_strategy_for_tuple!(~ $head $($X)*);
+    };
+    (~ $($X:ident)*) => {
+        /// A tuple of strategies attempts each strategy in turn, stopping at the first one that recovers.
+        #[allow(non_snake_case)]
+        impl<I: Clone, O, E: Error<I>, $($X: Strategy<I, O, E>),*> Strategy<I, O, E> for ($($X,)*) {
+            fn recover<D: Debugger, P: Parser<I, O, Error = E>>(
+                &self,
+                a_errors: Vec<Located<I, P::Error>>,
+                a_err: Located<I, P::Error>,
+                parser: P,
+                debugger: &mut D,
+                stream: &mut StreamOf<I, P::Error>,
+            ) -> PResult<I, O, P::Error> {
+                let ($($X,)*) = self;
+                let pre_state = stream.save();
+                let (mut a_errors, mut a_err) = (a_errors, a_err);
+                $(
+                    match $X.recover(a_errors, a_err, &parser, debugger, stream) {
+                        (errors, Ok(out)) => return (errors, Ok(out)),
+                        (errors, Err(err)) => {
+                            // Each strategy starts from where the pattern failed, regardless of what the last one did
+                            stream.revert(pre_state);
+                            a_errors = errors;
+                            a_err = err;
+                        }
+                    }
+                )*
+                (a_errors, Err(a_err))
+            }
+        }
+    };
+}
+
+impl_strategy_for_tuple!(A_ B_ C_ D_ E_ F_ G_ H_);
+
+// Recover from an error using the given strategy, counting the recovered error towards the stream's error limit
+fn recover<
+    I: Clone,
+    O,
+    S: Strategy<I, O, E>,
+    P: Parser<I, O, Error = E>,
+    D: Debugger,
+    E: Error<I>,
+>(
+    strategy: &S,
+    a_errors: Vec<Located<I, E>>,
+    a_err: Located<I, E>,
+    parser: P,
+    debugger: &mut D,
+    stream: &mut StreamOf<I, E>,
+) -> PResult<I, O, E> {
+    let res = strategy.recover(a_errors, a_err, parser, debugger, stream);
+    if res.1.is_ok() {
+        stream.count_errors(1);
+    }
+    res
+}
+
 /// A parser that includes a fallback recovery strategy should parsing result in an error.
 #[must_use]
 #[derive(Copy, Clone)]
@@ -333,7 +946,49 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, S: Strategy<I, O, E>, E: Error<I>>
             debugger.invoke(&self.0, stream)
         }) {
             (a_errors, Ok(a_out)) => (a_errors, Ok(a_out)),
-            (a_errors, Err(a_err)) => self.1.recover(a_errors, a_err, &self.0, debugger, stream),
+            // Once the error limit has been reached, parsing halts at the next error
+            (a_errors, Err(a_err)) if !stream.can_recover() => (a_errors, Err(a_err)),
+            (a_errors, Err(a_err)) => recover(&self.1, a_errors, a_err, &self.0, debugger, stream),
+        }
+    }
+
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// See [`Parser::repeated_recovering`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct RecoverStarted<A, S>(pub(crate) A, pub(crate) S);
+
+impl<I: Clone, O, A: Parser<I, O, Error = E>, S: Strategy<I, O, E>, E: Error<I>> Parser<I, O>
+    for RecoverStarted<A, S>
+{
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let before = stream.save();
+        match stream.try_parse(|stream| {
+            #[allow(deprecated)]
+            debugger.invoke(&self.0, stream)
+        }) {
+            (a_errors, Ok(a_out)) => (a_errors, Ok(a_out)),
+            // A pattern that fails where it started was never begun (it's likely the end of the repetition), so there
+            // is nothing to recover
+            (a_errors, Err(a_err)) if a_err.at <= before || !stream.can_recover() => {
+                (a_errors, Err(a_err))
+            }
+            (a_errors, Err(a_err)) => recover(&self.1, a_errors, a_err, &self.0, debugger, stream),
         }
     }
 
@@ -373,4 +1028,296 @@ mod tests {
             assert_eq!(errors.len(), 1)
         }
     }
+
+    #[test]
+    fn recover_with_skip_then_retry_until_record_skipped() {
+        let parser = just::<_, _, Simple<_>>('a')
+            .recover_with(skip_then_retry_until([',']).record_skipped())
+            .separated_by(just(','));
+
+        let (result, errors) = parser.parse_recovery("a,a,23a,a");
+        assert_eq!(result, Some(vec!['a', 'a', 'a', 'a']));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].related_spans(), &[4..6]);
+    }
+
+    #[test]
+    fn recover_with_skip_until_record_skipped() {
+        let parser = just::<_, _, Simple<_>>('a')
+            .recover_with(skip_until([';'], |_| '?').record_skipped())
+            .then_ignore(just(';'));
+
+        let (result, errors) = parser.parse_recovery("bcd;");
+        assert_eq!(result, Some('?'));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].related_spans(), &[0..3]);
+    }
+
+    #[test]
+    fn recover_with_incomplete() {
+        let parser = just::<_, _, Simple<_>>('a')
+            .then(just('b').recover_with(incomplete(|_| '?')))
+            .then_ignore(end());
+
+        assert_eq!(parser.parse_recovery("ab"), (Some(('a', 'b')), Vec::new()));
+
+        let (result, errors) = parser.parse_recovery("a");
+        assert_eq!(result, Some(('a', '?')));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 1..1);
+
+        let (result, errors) = parser.parse_recovery("ac");
+        assert_eq!(result, None);
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn recover_with_skip_then_retry_at_indent() {
+        let stmt = text::ident::<_, Simple<char>>()
+            .then_ignore(just(" = "))
+            .then_ignore(text::int(10))
+            .then_ignore(just('\n'))
+            .recover_with(skip_then_retry_at_indent());
+        let block = text::ident::<_, Simple<char>>()
+            .then_ignore(just(":\n"))
+            .then(just("    ").ignore_then(stmt).repeated());
+        let blocks = block.repeated().then_ignore(end());
+
+        // The malformed statement recovers at the next sibling statement rather than consuming the whole block
+        let src = "x:\n    a = !\n\n        2\n    b = 1\n    c = 2\ny:\n    d = 3\n";
+        let (out, errors) = blocks.parse_recovery(src);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            out,
+            Some(vec![
+                ("x".to_string(), vec!["b".to_string(), "c".to_string()]),
+                ("y".to_string(), vec!["d".to_string()]),
+            ]),
+        );
+
+        // Reaching the end of the block without finding a sibling causes recovery to fail
+        let src = "x:\n    a = !\ny:\n    d = 3\n";
+        assert_eq!(blocks.parse_recovery(src).0, None);
+    }
+
+    #[test]
+    fn recover_with_tuple_falls_through() {
+        #[derive(Debug, PartialEq)]
+        enum Stmt {
+            Int(String),
+            Error(&'static str),
+        }
+
+        let stmt = text::int::<_, Simple<char>>(10)
+            .map(Stmt::Int)
+            .delimited_by(just('('), just(')'))
+            .padded()
+            .recover_with((
+                nested_delimiters('(', ')', [], |_| Stmt::Error("nested")),
+                skip_until([')'], |_| Stmt::Error("skipped")).consume_end(),
+            ));
+
+        let (stmts, errors) = stmt
+            .repeated()
+            .then_ignore(end())
+            .parse_recovery("(1) (x) 2) (3)");
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            stmts,
+            Some(vec![
+                Stmt::Int("1".to_string()),
+                Stmt::Error("nested"),
+                Stmt::Error("skipped"),
+                Stmt::Int("3".to_string()),
+            ]),
+        );
+    }
+
+    #[test]
+    fn recover_via_parser() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Expr {
+            Int(String),
+            Error,
+        }
+
+        let soup = none_of::<_, _, Simple<char>>([';'])
+            .repeated()
+            .at_least(1)
+            .to(Expr::Error);
+        let stmt = text::int(10)
+            .map(Expr::Int)
+            .then_ignore(end().or(just(';').ignored().rewind()))
+            .recover_with(via_parser(soup))
+            .then_ignore(just(';'))
+            .padded();
+
+        let (stmts, errors) = stmt
+            .repeated()
+            .then_ignore(end())
+            .parse_recovery("1; 2 3; 4;");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            stmts,
+            Some(vec![
+                Expr::Int("1".to_string()),
+                Expr::Error,
+                Expr::Int("4".to_string()),
+            ]),
+        );
+    }
+
+    #[test]
+    fn recover_with_insert_missing_consumes_nothing() {
+        let parser = just::<_, _, Simple<char>>(';')
+            .recover_with(insert_missing(';', || ';'))
+            .then(text::ident())
+            .then_ignore(end());
+
+        let (out, errors) = parser.parse_recovery("foo");
+        assert_eq!(out, Some((';', "foo".to_string())));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 0..1);
+        assert_eq!(errors[0].found(), Some(&'f'));
+    }
+
+    #[test]
+    fn repeated_recovering_stops_at_unstarted_item() {
+        #[derive(Debug, PartialEq)]
+        enum Stmt {
+            Expr(String),
+            Error,
+        }
+
+        let stmt = text::ident::<_, Simple<char>>()
+            .map(Stmt::Expr)
+            .then_ignore(just(';'))
+            .padded();
+        let block = stmt
+            .repeated_recovering(skip_until([';'], |_| Stmt::Error).consume_end())
+            .delimited_by(just('{'), just('}'));
+
+        let (stmts, errors) = block.parse_recovery("{a; b c; d;}");
+        assert_eq!(
+            stmts,
+            Some(vec![
+                Stmt::Expr("a".to_string()),
+                Stmt::Error,
+                Stmt::Expr("d".to_string()),
+            ])
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 5..6);
+
+        // Recovering from the closing delimiter would skip to the end of the input instead
+        let (stmts, errors) = stmt
+            .recover_with(skip_until([';'], |_| Stmt::Error).consume_end())
+            .repeated()
+            .delimited_by(just('{'), just('}'))
+            .parse_recovery("{a; b;}");
+        assert_eq!(stmts, None);
+        assert!(!errors.is_empty());
+    }
+
+    #[test]
+    fn recover_with_nested_delimiters_any_mismatched() {
+        #[derive(Debug, PartialEq)]
+        enum Expr {
+            Int(String),
+            Group(Vec<Expr>),
+            Error,
+        }
+
+        let expr = recursive::<_, _, _, _, Simple<char>>(|expr| {
+            let items = expr.separated_by(just(','));
+            items
+                .clone()
+                .delimited_by(just('('), just(')'))
+                .or(items.delimited_by(just('['), just(']')))
+                .map(Expr::Group)
+                .recover_with(nested_delimiters_any([('(', ')'), ('[', ']')], |_| {
+                    Expr::Error
+                }))
+                .or(text::int(10).map(Expr::Int))
+                .padded()
+        });
+
+        // The `]` closes the list, leaving the parenthesised group unclosed
+        let (ast, errors) = expr.parse_recovery("([1, (2], 3)");
+        assert_eq!(
+            ast,
+            Some(Expr::Group(vec![Expr::Error, Expr::Int("3".to_string())]))
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span(), 7..8);
+
+        // A pattern that doesn't start with a delimiter isn't recovered
+        assert!(expr.parse_recovery("(1 2").0.is_none());
+    }
+
+    #[test]
+    fn error_limit_counts_emitted_errors() {
+        let item = just::<_, _, Simple<char>>('a')
+            .or(just('x').validate(|c, span, emit| {
+                emit(Simple::custom(span, "x is deprecated"));
+                c
+            }))
+            .recover_with(skip_then_retry_until([',']))
+            .separated_by(just(','))
+            .then_ignore(end());
+
+        let (items, errors) = item.parse_recovery("x,x,1a,a");
+        assert_eq!(items, Some(vec!['x', 'x', 'a', 'a']));
+        assert_eq!(errors.len(), 3);
+
+        // Errors emitted by `validate` count towards the limit, and the error that halts parsing is kept
+        let (items, errors) = item.parse_recovery_with_limit("x,x,1a,a", 3);
+        assert_eq!(items, None);
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[2].span(), 4..5);
+    }
+
+    #[test]
+    fn error_limit_ignores_backtracked_errors() {
+        let a = just::<_, _, Simple<char>>('a').recover_with(skip_then_retry_until([]));
+        let parser = a.then(just('!')).or(a.then(just('?'))).then_ignore(end());
+
+        let (out, errors) = parser.parse_recovery_with_limit("1a?", 2);
+        assert_eq!(out, Some(('a', '?')));
+        assert_eq!(errors.len(), 1);
+
+        let parser = choice((a.then(just('!')), a.then(just('?')))).then_ignore(end());
+
+        let (out, errors) = parser.parse_recovery_with_limit("1a?", 2);
+        assert_eq!(out, Some(('a', '?')));
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn error_limit_of_zero_reports_one_error() {
+        let parser = just::<_, _, Simple<char>>('a').validate(|c, span, emit| {
+            emit(Simple::custom(span, "not allowed"));
+            c
+        });
+
+        let (out, errors) = parser.parse_recovery_with_limit("a", 0);
+        assert_eq!(out, Some('a'));
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn error_limit_ignores_backtracked_zero_width_errors() {
+        let a = just::<_, _, Simple<char>>('a').recover_with(insert_missing('a', || 'a'));
+        let parser = a.then(just('!')).or(a.then(just('?'))).then_ignore(end());
+
+        let (out, errors) = parser.parse_recovery_with_limit("?", 2);
+        assert_eq!(out, Some(('a', '?')));
+        assert_eq!(errors.len(), 1);
+
+        let parser = choice((a.then(just('!')), a.then(just('?')))).then_ignore(end());
+
+        let (out, errors) = parser.parse_recovery_with_limit("?", 2);
+        assert_eq!(out, Some(('a', '?')));
+        assert_eq!(errors.len(), 1);
+    }
 }
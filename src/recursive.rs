@@ -29,9 +29,12 @@ impl<T> OnceCell<T> {
         }
     }
     pub fn get(&self) -> Option<core::cell::Ref<T>> {
-        Some(core::cell::Ref::map(self.0.borrow(), |x| {
-            x.as_ref().unwrap()
-        }))
+        let inner = self.0.borrow();
+        if inner.is_some() {
+            Some(core::cell::Ref::map(inner, |x| x.as_ref().unwrap()))
+        } else {
+            None
+        }
     }
 }
 
@@ -102,6 +105,14 @@ impl<'a, I: Clone, O, E: Error<I>> Recursive<'a, I, O, E> {
     }
 
     /// Defines the parser after declaring it, allowing it to be used for parsing.
+    ///
+    /// Parsers declared with [`Recursive::declare`] may refer to one-another before either is defined, allowing
+    /// mutually-recursive parsers to be expressed.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the parser has already been defined. Using a declared parser for parsing before it has
+    /// been defined will also panic.
     pub fn define<P: Parser<I, O, Error = E> + 'a>(&mut self, parser: P) {
         self.cell()
             .set(Box::new(parser))
@@ -142,7 +153,9 @@ impl<'a, I: Clone, O, E: Error<I>> Parser<I, O> for Recursive<'a, I, O, E> {
             debugger.invoke(
                 self.cell()
                     .get()
-                    .expect("Recursive parser used before being defined")
+                    .expect(
+                        "Recursive parser used before being defined. Call `Recursive::define` before parsing.",
+                    )
                     .as_ref(),
                 stream,
             )
@@ -224,3 +237,52 @@ pub fn recursive<
     })));
     parser
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::Simple, primitive::just, text::TextParser};
+
+    #[derive(Debug, PartialEq)]
+    enum Value {
+        Num(u32),
+        List(Vec<Value>),
+    }
+
+    #[test]
+    fn mutually_recursive() {
+        let mut value = Recursive::<_, _, Simple<char>>::declare();
+        let mut list = Recursive::<_, _, Simple<char>>::declare();
+
+        value.define(
+            text::int(10)
+                .map(|s: String| Value::Num(s.parse().unwrap()))
+                .or(list.clone().map(Value::List))
+                .padded(),
+        );
+        list.define(
+            value
+                .clone()
+                .separated_by(just(','))
+                .delimited_by(just('['), just(']')),
+        );
+
+        assert_eq!(
+            value.parse("[1, [2, []], 3]"),
+            Ok(Value::List(vec![
+                Value::Num(1),
+                Value::List(vec![Value::Num(2), Value::List(vec![])]),
+                Value::Num(3),
+            ])),
+        );
+        assert_eq!(list.parse("[4]"), Ok(vec![Value::Num(4)]));
+        assert!(value.parse("[1, 2").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Recursive parser used before being defined")]
+    fn parse_before_define() {
+        let value = Recursive::<char, char, Simple<char>>::declare();
+        let _ = value.parse("a");
+    }
+}
@@ -4,7 +4,8 @@
 //!
 //! You can use the [`Span`] trait to connect up chumsky to your compiler's knowledge of the input source.
 
-use core::ops::Range;
+use alloc::vec::Vec;
+use core::{fmt, ops::Range};
 
 /// A trait that describes a span over a particular range of inputs.
 ///
@@ -43,6 +44,33 @@ pub trait Span: Clone {
 
     /// Return the end offset of the span.
     fn end(&self) -> Self::Offset;
+
+    /// Produce the smallest span that covers all of the given spans, or `None` if no spans were given.
+    ///
+    /// The context of the resulting span is taken from the first span. If you wish to retain the individual spans
+    /// rather than only their covering span, use [`MultiSpan`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::span::Span;
+    /// use std::ops::Range;
+    ///
+    /// assert_eq!(Range::join_all(vec![4..6, 1..2, 9..12]), Some(1..12));
+    /// assert_eq!(Range::<usize>::join_all(Vec::new()), None);
+    /// ```
+    fn join_all<Iter: IntoIterator<Item = Self>>(spans: Iter) -> Option<Self>
+    where
+        Self: Sized,
+        Self::Offset: Ord,
+    {
+        let mut spans = spans.into_iter();
+        let first = spans.next()?;
+        let (start, end) = spans.fold((first.start(), first.end()), |(start, end), span| {
+            (start.min(span.start()), end.max(span.end()))
+        });
+        Some(Self::new(first.context(), start..end))
+    }
 }
 
 impl<T: Clone + Ord> Span for Range<T> {
@@ -78,3 +106,222 @@ impl<C: Clone, T: Clone> Span for (C, Range<T>) {
         self.1.end.clone()
     }
 }
+
+/// Produce the smallest span that covers both of the given spans.
+///
+/// The context of the resulting span is taken from `a`. This is a convenience for the two-span case of
+/// [`Span::join_all`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::span::union;
+/// assert_eq!(union(4..6, 1..2), 1..6);
+/// assert_eq!(union(("a.txt", 3..5), ("a.txt", 8..9)), ("a.txt", 3..9));
+/// ```
+pub fn union<S: Span>(a: S, b: S) -> S
+where
+    S::Offset: Ord,
+{
+    let start = a.start().min(b.start());
+    let end = a.end().max(b.end());
+    S::new(a.context(), start..end)
+}
+
+/// A 1-based line and column position within some source text.
+///
+/// Because [`LineCol`] is ordered, [`Range<LineCol>`] implements [`Span`]. Streams that produce such spans can be
+/// created with [`Stream::from_str_linecol`](crate::Stream::from_str_linecol), which is useful for tools that report
+/// positions in the conventional `file:line:col` form without needing to convert offsets after parsing.
+///
+/// Positions display as `line:col`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::span::LineCol;
+/// let pos = LineCol::new(3, 14);
+///
+/// assert_eq!(pos.to_string(), "3:14");
+/// assert!(pos < LineCol::new(4, 1));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The column number, starting at 1.
+    pub col: usize,
+}
+
+impl LineCol {
+    /// Create a new position from a line and column number.
+    pub fn new(line: usize, col: usize) -> Self {
+        Self { line, col }
+    }
+
+    /// The position of the first character of a source text.
+    pub fn start() -> Self {
+        Self::new(1, 1)
+    }
+
+    /// Returns the position immediately following the given character, if that character appears at this position.
+    ///
+    /// A newline moves to the start of the next line. A tab moves to the next tab stop, where tab stops occur every
+    /// `tab_width` columns. Every other character (including `\r` and multi-byte characters) occupies one column.
+    pub(crate) fn advance(self, c: char, tab_width: usize) -> Self {
+        match c {
+            '\n' => Self::new(self.line + 1, 1),
+            '\t' => {
+                let tab_width = tab_width.max(1);
+                Self::new(
+                    self.line,
+                    (self.col - 1) / tab_width * tab_width + tab_width + 1,
+                )
+            }
+            _ => Self::new(self.line, self.col + 1),
+        }
+    }
+}
+
+impl fmt::Display for LineCol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.col)
+    }
+}
+
+/// Find the line and column of a byte offset into some source text, with tab stops every `tab_width` columns.
+///
+/// This is useful for rendering diagnostics from errors whose spans are byte offsets, such as those produced by
+/// [`Parser::parse_str`](crate::Parser::parse_str). Columns count characters rather than bytes, so multi-byte
+/// characters occupy a single column. If `offset` lies within a multi-byte character, the position of that character
+/// is returned. If `offset` lies beyond the end of the text, the position immediately following the text is returned.
+///
+/// See [`LineCol`] for details of how characters are counted.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::span::{line_col, LineCol};
+/// let src = "let x;\n\tlet é = 1;";
+///
+/// assert_eq!(line_col(src, 4, 4), LineCol::new(1, 5));
+/// // The tab advances to the next tab stop
+/// assert_eq!(line_col(src, 12, 4), LineCol::new(2, 9));
+/// // 'é' is two bytes long, but occupies a single column
+/// assert_eq!(line_col(src, 13, 4), LineCol::new(2, 9));
+/// assert_eq!(line_col(src, 14, 4), LineCol::new(2, 10));
+/// assert_eq!(line_col(src, 14, 8).to_string(), "2:14");
+/// ```
+pub fn line_col(src: &str, offset: usize, tab_width: usize) -> LineCol {
+    src.char_indices()
+        .take_while(|(i, c)| i + c.len_utf8() <= offset)
+        .fold(LineCol::start(), |pos, (_, c)| pos.advance(c, tab_width))
+}
+
+/// A collection of spans that together describe a single, possibly discontinuous, region of the input.
+///
+/// This is useful for constructs that are assembled from several non-contiguous pieces, such as a declaration that is
+/// split in two. The individual pieces can be attached to an error with
+/// [`Error::with_related_spans`](crate::Error::with_related_spans), allowing
+/// exactly the relevant parts of the input to be highlighted.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::span::MultiSpan;
+/// let span = MultiSpan::new(vec![8..10, 0..3]);
+///
+/// assert_eq!(span.pieces(), &[0..3, 8..10]);
+/// assert_eq!(span.cover(), Some(0..10));
+///
+/// // Errors can refer to every piece of the region
+/// # use chumsky::prelude::*;
+/// let error = Simple::<char>::custom(span.cover().unwrap(), "conflicting declaration")
+///     .with_related_spans(span);
+/// assert_eq!(error.related_spans(), &[0..3, 8..10]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MultiSpan<S> {
+    pieces: Vec<S>,
+}
+
+impl<S: Span> MultiSpan<S> {
+    /// Create a new multi-span from its pieces.
+    ///
+    /// The pieces are sorted by their start offset.
+    pub fn new<Iter: IntoIterator<Item = S>>(pieces: Iter) -> Self
+    where
+        S::Offset: Ord,
+    {
+        let mut pieces = pieces.into_iter().collect::<Vec<_>>();
+        pieces.sort_by_key(|piece| piece.start());
+        Self { pieces }
+    }
+
+    /// Returns the individual pieces of this multi-span.
+    pub fn pieces(&self) -> &[S] {
+        &self.pieces
+    }
+
+    /// Returns the smallest span that covers all of the pieces of this multi-span, or `None` if it has no pieces.
+    ///
+    /// See [`Span::join_all`].
+    pub fn cover(&self) -> Option<S>
+    where
+        S::Offset: Ord,
+    {
+        S::join_all(self.pieces.iter().cloned())
+    }
+}
+
+impl<S> IntoIterator for MultiSpan<S> {
+    type Item = S;
+    type IntoIter = alloc::vec::IntoIter<S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.pieces.into_iter()
+    }
+}
+
+/// A value with an attached span.
+///
+/// Most AST nodes need to know where in the source they came from. This type provides a convenient way to pair a
+/// value with its span, and is produced by [`Parser::spanned`](crate::Parser::spanned) and
+/// [`Parser::spanned_map`](crate::Parser::spanned_map).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Spanned<T, S = Range<usize>> {
+    /// The value.
+    pub node: T,
+    /// The span of the input that the value was parsed from.
+    pub span: S,
+}
+
+impl<T, S> Spanned<T, S> {
+    /// Create a new value with the given span.
+    pub fn new(node: T, span: S) -> Self {
+        Self { node, span }
+    }
+
+    /// Get a reference to the inner value.
+    pub fn node(&self) -> &T {
+        &self.node
+    }
+
+    /// Get a reference to the span of the value.
+    pub fn span(&self) -> &S {
+        &self.span
+    }
+
+    /// Map the inner value, preserving the span.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Spanned<U, S> {
+        Spanned {
+            node: f(self.node),
+            span: self.span,
+        }
+    }
+
+    /// Split this value into its inner value and its span.
+    pub fn into_parts(self) -> (T, S) {
+        (self.node, self.span)
+    }
+}
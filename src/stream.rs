@@ -7,6 +7,8 @@
 
 use super::*;
 use alloc::vec;
+use core::any::Any;
+use span::LineCol;
 
 trait StreamExtend<T>: Iterator<Item = T> {
     /// Extend the vector with input. The actual amount can be more or less than `n`, but must be at least 1 (0 implies
@@ -32,6 +34,12 @@ pub enum Flat<I, Iter> {
 
 /// A type that represents a stream of input tokens. Unlike [`Iterator`], this type supports backtracking and a few
 /// other features required by the crate.
+///
+/// When writing a parser by hand (with [`custom`] or [`from_fn`]), tokens may be consumed with [`Stream::next`] and
+/// inspected with [`Stream::peek`]. [`Stream::save`] and [`Stream::revert`] allow backtracking to an earlier position,
+/// and [`Stream::attempt`] and [`Stream::try_parse`] wrap this pattern up for parsers that may fail part-way through.
+/// [`Stream::span_since`] gives the span of the tokens consumed since a saved position. These methods are a stable part
+/// of the public API.
 #[allow(deprecated)]
 pub struct Stream<
     'a,
@@ -43,6 +51,20 @@ pub struct Stream<
     pub(crate) eoi: S,
     pub(crate) offset: usize,
     pub(crate) buffer: Vec<(I, S)>,
+    pub(crate) state: Option<&'a mut dyn Any>,
+    // Identifies this stream, so that caches (see `Parser::memoized`) are not shared between parses
+    pub(crate) session: Rc<()>,
+    // If set, the end of input span is not known up-front and is instead provided by the iterator once exhausted
+    pub(crate) pending_eoi: Option<Rc<RefCell<Option<S>>>>,
+    // Whether a `Parser::cut` has succeeded within the current cut scope (see `Stream::cut_scope`)
+    pub(crate) cut: bool,
+    // Whether the parser about to be invoked is the first alternative of a `Parser::or`, and so continues its chain of
+    // alternatives (see `Parser::is_choice`)
+    pub(crate) chained: bool,
+    // The maximum number of errors that may be reported (see `Stream::with_error_limit`)
+    pub(crate) error_limit: Option<usize>,
+    // The offsets at which errors counting towards the error limit were emitted, so that backtracking can discard them
+    pub(crate) emitted: Vec<usize>,
     pub(crate) iter: Iter,
 }
 
@@ -61,10 +83,56 @@ impl<'a, I, S: Span, Iter: Iterator<Item = (I, S)>> Stream<'a, I, S, Iter> {
             eoi,
             offset: 0,
             buffer: Vec::new(),
+            state: None,
+            session: Rc::new(()),
+            pending_eoi: None,
+            cut: false,
+            chained: false,
+            error_limit: None,
+            emitted: Vec::new(),
             iter,
         }
     }
 
+    /// Attach a mutable user-defined state to this stream.
+    ///
+    /// Stateful combinators such as [`Parser::try_map_with_state`] may access and mutate this state while parsing.
+    /// This is useful for context-sensitive parsing, such as checking identifiers against a symbol table.
+    ///
+    /// The state's type is erased (it is stored as a `&mut dyn Any`), which has two consequences:
+    ///
+    /// - `St` must be `'static`, so the state cannot hold borrowed data such as `&str` slices of the source or
+    ///   references to other values. Own the data instead (for example, with `String` rather than `&str`), or store
+    ///   indices or spans that refer back to the source.
+    /// - The state's type is not checked against the stateful combinators at compile time (see below).
+    ///
+    /// Note that the state is shared by all branches of the parser, including those that are later backtracked: any
+    /// changes made by a branch that ultimately fails to parse are *not* undone.
+    ///
+    /// # Panics
+    ///
+    /// The type of the state is only checked once a stateful combinator accesses it, so parsing will panic if any
+    /// stateful combinator in the parser expects a state of a type other than `St`.
+    pub fn with_state<St: Any>(mut self, state: &'a mut St) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Limit the number of errors that may be reported while parsing this stream.
+    ///
+    /// Once `max_errors - 1` errors have been emitted (by recovering via [`Parser::recover_with`] and similar, or by
+    /// parsers such as [`Parser::validate`]), no further recovery is attempted: parsing halts at the next error instead
+    /// of producing a flood of cascading errors, much like the error limit of many compilers.
+    /// [`Parser::parse_recovery`] then reports at most `max_errors` errors, the last of which is the error that halted
+    /// parsing. At least one error is always reported, so a limit of zero behaves like a limit of one. See also
+    /// [`Parser::parse_recovery_with_limit`].
+    ///
+    /// Errors emitted by branches that are later backtracked over do not count towards the limit.
+    pub fn with_error_limit(mut self, max_errors: usize) -> Self {
+        self.error_limit = Some(max_errors);
+        self
+    }
+
     /// Eagerly evaluate the token stream, returning an iterator over the tokens in it (but without modifying the
     /// stream's state so that it can still be used for parsing).
     ///
@@ -180,17 +248,85 @@ impl<'a, I: Clone, S: Span> Stream<'a, I, S> {
         self.offset
     }
 
-    pub(crate) fn save(&self) -> usize {
+    pub(crate) fn state<St: Any>(&mut self) -> &mut St {
+        self.state
+            .as_mut()
+            .expect("A stateful parser was used, but no state was attached to the stream. Use `Stream::with_state`.")
+            .downcast_mut()
+            .unwrap_or_else(|| {
+                panic!(
+                    "The state attached to the stream is not of the type expected by a stateful parser, `{}`.",
+                    core::any::type_name::<St>(),
+                )
+            })
+    }
+
+    /// Save the current position of the stream, such that it may later be returned to with [`Stream::revert`].
+    ///
+    /// Positions are the number of tokens consumed since the start of the stream.
+    pub fn save(&self) -> usize {
         self.offset
     }
-    pub(crate) fn revert(&mut self, offset: usize) {
+
+    /// Return the stream to a position previously produced by [`Stream::save`].
+    ///
+    /// Every token pulled from the underlying iterator is retained for the lifetime of the stream, so it is always
+    /// valid to revert to any position saved earlier from the *same* stream, even after many tokens have been consumed
+    /// since. Positions from another stream, or positions that the stream has not yet reached, must not be used.
+    ///
+    /// Reverting does not undo anything other than the position. In particular, if a parser invoked since the position
+    /// was saved recovered from errors, those errors are not forgotten: a hand-written parser that backtracks past
+    /// them must discard them itself, or they will be reported against input that is parsed again afterwards.
+    pub fn revert(&mut self, offset: usize) {
         self.offset = offset;
+        // Errors emitted after this position belong to a branch that is being abandoned
+        while matches!(self.emitted.last(), Some(&at) if at > offset) {
+            self.emitted.pop();
+        }
+    }
+
+    /// Look at the next token in the stream without consuming it, or `None` if the end of the stream has been reached.
+    pub fn peek_token(&mut self) -> Option<&I> {
+        let offset = self.offset;
+        self.pull_until(offset).map(|(tok, _)| tok)
+    }
+
+    /// Look at the next token in the stream and its span without consuming it, or `None` if the end of the stream has
+    /// been reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::Stream;
+    /// # use std::ops::Range;
+    /// let stream: &mut Stream<char, Range<usize>> = &mut Stream::from("ab");
+    ///
+    /// assert_eq!(stream.peek(), Some((0..1, &'a')));
+    /// // Peeking does not consume the token
+    /// assert_eq!(stream.peek(), Some((0..1, &'a')));
+    /// ```
+    pub fn peek(&mut self) -> Option<(S, &I)> {
+        let offset = self.offset;
+        self.pull_until(offset)
+            .map(|(tok, span)| (span.clone(), tok))
+    }
+
+    /// Consume the next token in the stream, or return `None` if the end of the stream has been reached.
+    pub fn next_token(&mut self) -> Option<I> {
+        self.next().2
     }
 
     fn pull_until(&mut self, offset: usize) -> Option<&(I, S)> {
         let additional = offset.saturating_sub(self.buffer.len()) + 1024;
         #[allow(deprecated)]
         (&mut &mut self.iter as &mut dyn StreamExtend<_>).extend(&mut self.buffer, additional);
+        if offset >= self.buffer.len() {
+            if let Some(eoi) = self.pending_eoi.take() {
+                if let Some(eoi) = eoi.borrow_mut().take() {
+                    self.eoi = eoi;
+                }
+            }
+        }
         self.buffer.get(offset)
     }
 
@@ -205,7 +341,27 @@ impl<'a, I: Clone, S: Span> Stream<'a, I, S> {
         }
     }
 
-    pub(crate) fn next(&mut self) -> (usize, S, Option<I>) {
+    /// Consume the next token in the stream.
+    ///
+    /// This returns the position of the token (as would have been returned by [`Stream::save`] beforehand), its span,
+    /// and the token itself. If the end of the stream has been reached, the position is left unchanged, the span is
+    /// that of the end of input, and the token is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::Stream;
+    /// # use std::ops::Range;
+    /// let stream: &mut Stream<char, Range<usize>> = &mut Stream::from("ab");
+    ///
+    /// assert_eq!(stream.next(), (0, 0..1, Some('a')));
+    /// assert_eq!(stream.next(), (1, 1..2, Some('b')));
+    /// assert_eq!(stream.next(), (2, 2..2, None));
+    /// // The end of input may be found any number of times
+    /// assert_eq!(stream.next(), (2, 2..2, None));
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> (usize, S, Option<I>) {
         match self.pull_until(self.offset).cloned() {
             Some((out, span)) => {
                 self.offset += 1;
@@ -215,11 +371,16 @@ impl<'a, I: Clone, S: Span> Stream<'a, I, S> {
         }
     }
 
-    pub(crate) fn span_since(&mut self, start_offset: usize) -> S {
+    /// Produce the span covering the tokens consumed since the given position, as produced by [`Stream::save`].
+    pub fn span_since(&mut self, start_offset: usize) -> S {
+        self.span_between(start_offset, self.offset)
+    }
+
+    pub(crate) fn span_between(&mut self, start_offset: usize, end_offset: usize) -> S {
         debug_assert!(
-            start_offset <= self.offset,
+            start_offset <= end_offset,
             "{} > {}",
-            self.offset,
+            end_offset,
             start_offset
         );
         let start = self
@@ -228,31 +389,281 @@ impl<'a, I: Clone, S: Span> Stream<'a, I, S> {
             .map(|(_, s)| s.start())
             .unwrap_or_else(|| self.eoi.start());
         let end = self
-            .pull_until(self.offset.saturating_sub(1).max(start_offset))
+            .pull_until(end_offset.saturating_sub(1).max(start_offset))
             .as_ref()
             .map(|(_, s)| s.end())
             .unwrap_or_else(|| self.eoi.end());
         S::new(self.eoi.context(), start..end)
     }
 
-    pub(crate) fn attempt<R, F: FnOnce(&mut Self) -> (bool, R)>(&mut self, f: F) -> R {
+    /// Run a function that may consume tokens from the stream, reverting to the current position afterwards unless the
+    /// function returns `true` alongside its output.
+    ///
+    /// See [`Stream::revert`] for the caveats of backtracking.
+    pub fn attempt<R, F: FnOnce(&mut Self) -> (bool, R)>(&mut self, f: F) -> R {
         let old_offset = self.offset;
+        let old_emitted = self.emitted.len();
         let (commit, out) = f(self);
         if !commit {
-            self.offset = old_offset;
+            self.revert(old_offset);
+            // Errors emitted without consuming input aren't discarded by `revert`
+            self.emitted.truncate(old_emitted);
         }
         out
     }
 
-    pub(crate) fn try_parse<O, E, F: FnOnce(&mut Self) -> PResult<I, O, E>>(
+    /// Like [`Stream::attempt`], but keeps the tokens consumed only if the function's parse result is successful.
+    ///
+    /// Errors that were recovered from are returned even if the parse was unsuccessful and the stream was reverted, so
+    /// the caller must decide whether to keep them (see [`Stream::revert`]). Like the backtracking combinators, a
+    /// failure that follows a successful [`Parser::cut`] within the function is committed.
+    pub fn try_parse<O, E, F: FnOnce(&mut Self) -> PResult<I, O, E>>(
         &mut self,
         f: F,
     ) -> PResult<I, O, E> {
         self.attempt(move |stream| {
-            let out = f(stream);
+            let out = stream.cut_scope(f);
             (out.1.is_ok(), out)
         })
     }
+
+    // Whether another error may be recovered from while leaving room for the error that halts parsing
+    pub(crate) fn can_recover(&self) -> bool {
+        !matches!(self.error_limit, Some(limit) if self.emitted.len() + 1 >= limit)
+    }
+
+    // Count errors emitted at the current position towards the error limit
+    pub(crate) fn count_errors(&mut self, n: usize) {
+        if self.error_limit.is_some() {
+            let len = self.emitted.len();
+            self.emitted.resize(len + n, self.offset);
+        }
+    }
+
+    // Run a function within its own cut scope: if the function fails after a `Parser::cut` within it has succeeded,
+    // the failure is committed. Cuts never outlive the scope in which they were made.
+    pub(crate) fn cut_scope<O, E, F: FnOnce(&mut Self) -> PResult<I, O, E>>(
+        &mut self,
+        f: F,
+    ) -> PResult<I, O, E> {
+        let outer = core::mem::replace(&mut self.cut, false);
+        let (errors, mut res) = f(self);
+        if let Err(err) = &mut res {
+            err.committed |= self.cut;
+        }
+        self.cut = outer;
+        (errors, res)
+    }
+}
+
+impl<'a> Stream<'a, char, Range<LineCol>, Box<dyn Iterator<Item = (char, Range<LineCol>)> + 'a>> {
+    /// Create a new stream of characters whose spans are 1-based line and column positions (see [`LineCol`]), rather
+    /// than character offsets.
+    ///
+    /// Lines are terminated by `\n`, so both `\n` and `\r\n` line endings are handled uniformly: a `\r` before a
+    /// newline is treated like any other character at the end of the line. Columns are counted in characters (i.e:
+    /// Unicode scalar values), not bytes, so a multi-byte character occupies a single column. Tabs are one column wide.
+    /// To use wider tab stops, see [`Stream::from_str_linecol_with_tab_width`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, span::LineCol, Stream};
+    /// let word = filter::<_, _, Simple<char, _>>(|c: &char| c.is_alphabetic())
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .collect::<String>()
+    ///     .map_with_span(|word, span| (word, span))
+    ///     .padded();
+    ///
+    /// let (name, span) = word
+    ///     .parse(Stream::from_str_linecol("\r\n  héllo"))
+    ///     .unwrap();
+    /// assert_eq!(name, "héllo");
+    /// assert_eq!(span, LineCol::new(2, 3)..LineCol::new(2, 8));
+    ///
+    /// let errors = word.then(just('!')).parse(Stream::from_str_linecol("foo\nbar")).unwrap_err();
+    /// assert_eq!(errors[0].span().start.to_string(), "2:1");
+    /// ```
+    pub fn from_str_linecol(s: &'a str) -> Self {
+        Self::from_str_linecol_with_tab_width(s, 1)
+    }
+
+    /// Like [`Stream::from_str_linecol`], but with tab stops every `tab_width` columns. A tab advances the column to
+    /// the next tab stop. A `tab_width` of `0` is treated as `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, span::LineCol, Stream};
+    /// // Find the position of the first `x`
+    /// let x = none_of::<_, _, Simple<char, _>>('x')
+    ///     .repeated()
+    ///     .ignore_then(just('x').map_with_span(|_, span: std::ops::Range<LineCol>| span.start));
+    ///
+    /// assert_eq!(x.parse(Stream::from_str_linecol_with_tab_width("\tx", 4)), Ok(LineCol::new(1, 5)));
+    /// assert_eq!(x.parse(Stream::from_str_linecol_with_tab_width("ab\tx", 4)), Ok(LineCol::new(1, 5)));
+    /// assert_eq!(x.parse(Stream::from_str_linecol_with_tab_width("abcd\tx", 4)), Ok(LineCol::new(1, 9)));
+    /// ```
+    pub fn from_str_linecol_with_tab_width(s: &'a str, tab_width: usize) -> Self {
+        let end = s
+            .chars()
+            .fold(LineCol::start(), |pos, c| pos.advance(c, tab_width));
+        let mut pos = LineCol::start();
+        Self::from_iter(
+            end..end,
+            Box::new(s.chars().map(move |c| {
+                let start = pos;
+                pos = pos.advance(c, tab_width);
+                // Newlines have no width of their own, but still deserve a non-empty span
+                let end = if c == '\n' {
+                    LineCol::new(start.line, start.col + 1)
+                } else {
+                    pos
+                };
+                (c, start..end)
+            })),
+        )
+    }
+}
+
+impl<'a, I: 'a> Stream<'a, I, Range<usize>, Box<dyn Iterator<Item = (I, Range<usize>)> + 'a>> {
+    /// Create a new stream from an iterator of tokens that have no spans of their own, such as the output of a
+    /// separate lexing pass. Each token is given a span equal to its index (i.e: `i..i + 1`), and the end of input has
+    /// the span `len..len`, pointing just past the last token.
+    ///
+    /// Slices, arrays and [`Vec`]s of tokens may also be converted into streams with the same spans using [`From`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, Stream};
+    /// #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    /// enum Token { Num(u64), Plus }
+    ///
+    /// let num = select! { Token::Num(x) => x };
+    /// let sum = num
+    ///     .then(just(Token::Plus).ignore_then(num))
+    ///     .map(|(a, b)| a + b);
+    ///
+    /// let tokens = vec![Token::Num(1), Token::Plus, Token::Num(2)];
+    /// assert_eq!(sum.parse(Stream::from_iter_indexed(tokens)), Ok(3));
+    ///
+    /// // Errors at the end of input point past the last token
+    /// let tokens = vec![Token::Num(1), Token::Plus];
+    /// let errors = sum.then_ignore(end::<Simple<Token>>()).parse(Stream::from_iter_indexed(tokens)).unwrap_err();
+    /// assert_eq!(errors[0].span(), 2..2);
+    /// ```
+    pub fn from_iter_indexed<J>(tokens: J) -> Self
+    where
+        J: IntoIterator<Item = I>,
+        J::IntoIter: ExactSizeIterator + 'a,
+    {
+        let tokens = tokens.into_iter();
+        let len = ExactSizeIterator::len(&tokens);
+        Self::from_iter(
+            len..len,
+            Box::new(tokens.enumerate().map(|(i, x)| (x, i..i + 1))),
+        )
+    }
+}
+
+impl<'a> Stream<'a, char, Range<usize>, Box<dyn Iterator<Item = (char, Range<usize>)> + 'a>> {
+    /// Create a new stream of characters whose spans are UTF-8 byte offsets into the string, rather than character
+    /// indices.
+    ///
+    /// This means that spans line up with the original source, so they may be used to slice it directly or passed on
+    /// to tools (such as editors) that expect byte offsets. The span of a multi-byte character, such as `é`, covers
+    /// all of its bytes. See also [`Parser::parse_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, Stream};
+    /// let word = text::ident::<_, Simple<char>>().padded().map_with_span(|_, span| span);
+    /// let pair = just('é').map_with_span(|_, span| span).then(word);
+    ///
+    /// assert_eq!(pair.parse(Stream::from_str_with_byte_spans("é foo")), Ok((0..2, 2..6)));
+    /// // In comparison, the `&str` impl uses character indices
+    /// assert_eq!(pair.parse("é foo"), Ok((0..1, 1..5)));
+    /// ```
+    pub fn from_str_with_byte_spans(s: &'a str) -> Self {
+        Self::from_iter(
+            s.len()..s.len(),
+            Box::new(s.char_indices().map(|(i, c)| (c, i..i + c.len_utf8()))),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Stream<'a, u8, Range<usize>, Box<dyn Iterator<Item = (u8, Range<usize>)> + 'a>> {
+    /// Create a new stream of bytes that are read lazily from a reader, with spans that are byte offsets.
+    ///
+    /// Bytes are only read once the parser requires them (along with a limited number of bytes beyond them, since the
+    /// stream buffers its input in batches), so a parser that stops early (such as one that only parses the header of
+    /// a file) does not read the rest of the input. Each byte is read with a separate call to the reader (see
+    /// [`std::io::Read::bytes`]), so consider wrapping the reader in a [`std::io::BufReader`] if it does not buffer its
+    /// input itself.
+    ///
+    /// Note that this does not bound the amount of memory used while parsing: because parsers may backtrack to any
+    /// earlier position, every byte read so far is kept until parsing has finished, just as with any other stream.
+    /// Parsers and recovery strategies that look far ahead (such as [`take_until`] or [`nested_delimiters`]) cause more
+    /// of the input to be read.
+    ///
+    /// Interrupted reads are retried. If any other I/O error occurs, the stream ends and the error is stored in
+    /// `error`. A parse error (or even a successful parse) of a stream that ended early like this is not meaningful,
+    /// so `error` should always be checked after parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, Stream, bytes};
+    /// use std::io::Cursor;
+    ///
+    /// // A large file that begins with a PNG header
+    /// let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+    /// data.resize(1 << 20, 0);
+    /// let mut file = Cursor::new(data);
+    ///
+    /// let magic = bytes::tag::<Simple<u8>>(b"\x89PNG");
+    /// let mut error = None;
+    /// let res = magic.parse(Stream::from_reader(&mut file, &mut error));
+    /// assert!(error.is_none());
+    /// assert!(res.is_ok());
+    /// // Only the start of the file was read
+    /// assert!(file.position() < 1 << 20);
+    /// ```
+    pub fn from_reader<R: std::io::Read + 'a>(
+        reader: R,
+        error: &'a mut Option<std::io::Error>,
+    ) -> Self {
+        // The end of input is only known once the reader is exhausted
+        let eoi = Rc::new(RefCell::new(None));
+        let end = eoi.clone();
+        let mut bytes = std::io::Read::bytes(reader);
+        let mut pos = 0;
+        let mut stream = Self::from_iter(
+            0..0,
+            Box::new(
+                core::iter::from_fn(move || match bytes.next() {
+                    Some(Ok(b)) => {
+                        pos += 1;
+                        Some((b, pos - 1..pos))
+                    }
+                    res => {
+                        if let Some(Err(e)) = res {
+                            *error = Some(e);
+                        }
+                        *end.borrow_mut() = Some(pos..pos);
+                        None
+                    }
+                })
+                .fuse(),
+            ),
+        );
+        stream.pending_eoi = Some(eoi);
+        stream
+    }
 }
 
 impl<'a> From<&'a str>
@@ -286,6 +697,8 @@ impl<'a> From<String>
 impl<'a, T: Clone> From<&'a [T]>
     for Stream<'a, T, Range<usize>, Box<dyn Iterator<Item = (T, Range<usize>)> + 'a>>
 {
+    /// Each token is given a span equal to its index (i.e: `i..i + 1`), and the end of input has the span `len..len`.
+    /// See also [`Stream::from_iter_indexed`].
     fn from(s: &'a [T]) -> Self {
         let len = s.len();
         Self::from_iter(
@@ -10,6 +10,7 @@
 //! parsing of text.
 
 use super::*;
+use alloc::{borrow::Cow, format};
 use core::iter::FromIterator;
 
 /// The type of a parser that accepts (and ignores) any number of whitespace characters.
@@ -60,6 +61,115 @@ impl<C: Character, O, A: Parser<C, O, Error = E>, E: Error<C>> Parser<C, O> for
     }
 }
 
+/// See [`string_literal`].
+#[must_use]
+pub struct StringLiteral<'a, P, E>(&'a str, char, P, PhantomData<E>);
+
+impl<'a, P: Copy, E> Copy for StringLiteral<'a, P, E> {}
+impl<'a, P: Clone, E> Clone for StringLiteral<'a, P, E> {
+    fn clone(&self) -> Self {
+        Self(self.0, self.1, self.2.clone(), PhantomData)
+    }
+}
+
+impl<'a, P: Parser<char, char, Error = E>, E: Error<char, Span = Range<usize>>>
+    Parser<char, Cow<'a, str>> for StringLiteral<'a, P, E>
+{
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<char, E>,
+    ) -> PResult<char, Cow<'a, str>, E> {
+        let (at, span, tok) = stream.next();
+        if tok != Some(self.1) {
+            return (
+                Vec::new(),
+                Err(Located::at(
+                    at,
+                    E::expected_input_found(span, Some(Some(self.1)), tok),
+                )),
+            );
+        }
+
+        let mut errors = Vec::new();
+        let content_start = span.end;
+        let mut content_end = content_start;
+        // Only allocate once we find an escape sequence
+        let mut owned: Option<String> = None;
+        loop {
+            if let (mut e_errors, Ok((c, _))) = stream.try_parse(|stream| {
+                #[allow(deprecated)]
+                debugger.invoke(&self.2, stream)
+            }) {
+                errors.append(&mut e_errors);
+                owned
+                    .get_or_insert_with(|| String::from(self.slice(content_start..content_end)))
+                    .push(c);
+                continue;
+            }
+
+            match stream.next() {
+                (_, _, Some(c)) if c == self.1 => break,
+                (_, span, Some(c)) => match &mut owned {
+                    Some(owned) => owned.push(c),
+                    // Only keep borrowing while the token's span really is the character's bytes within the source
+                    None if span.start == content_end
+                        && self.slice(span.clone()).chars().eq(Some(c)) =>
+                    {
+                        content_end = span.end
+                    }
+                    None => {
+                        let mut s = String::from(self.slice(content_start..content_end));
+                        s.push(c);
+                        owned = Some(s);
+                    }
+                },
+                (at, span, None) => {
+                    return (
+                        errors,
+                        Err(Located::at(
+                            at,
+                            E::expected_input_found(span, Some(Some(self.1)), None),
+                        )),
+                    )
+                }
+            }
+        }
+
+        let out = match owned {
+            Some(owned) => Cow::Owned(owned),
+            None => Cow::Borrowed(self.slice(content_start..content_end)),
+        };
+        (errors, Ok((out, None)))
+    }
+
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<char, E>,
+    ) -> PResult<char, Cow<'a, str>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<char, E>,
+    ) -> PResult<char, Cow<'a, str>, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+impl<'a, P, E> StringLiteral<'a, P, E> {
+    // The part of the source covered by a span, or nothing if the span isn't a valid byte range of the source
+    fn slice(&self, range: Range<usize>) -> &'a str {
+        self.0.get(range).unwrap_or_default()
+    }
+}
+
 mod private {
     pub trait Sealed {}
 
@@ -98,6 +208,12 @@ pub trait Character: private::Sealed + Copy + PartialEq {
 
     /// Returns this character as a [`char`].
     fn to_char(&self) -> char;
+
+    /// Returns true if the two strings are equal when compared case-insensitively.
+    ///
+    /// Only ASCII characters are case-folded unless the `unicode-case` feature is enabled, in which case [`char`]
+    /// strings are compared using their full Unicode lowercase mapping.
+    fn str_eq_ignore_case(a: &Self::Str, b: &Self::Str) -> bool;
 }
 
 impl Character for u8 {
@@ -122,6 +238,9 @@ impl Character for u8 {
     fn to_char(&self) -> char {
         *self as char
     }
+    fn str_eq_ignore_case(a: &Self::Str, b: &Self::Str) -> bool {
+        a.eq_ignore_ascii_case(b)
+    }
 }
 
 impl Character for char {
@@ -146,6 +265,16 @@ impl Character for char {
     fn to_char(&self) -> char {
         *self
     }
+    #[cfg(not(feature = "unicode-case"))]
+    fn str_eq_ignore_case(a: &Self::Str, b: &Self::Str) -> bool {
+        a.eq_ignore_ascii_case(b)
+    }
+    #[cfg(feature = "unicode-case")]
+    fn str_eq_ignore_case(a: &Self::Str, b: &Self::Str) -> bool {
+        a.chars()
+            .flat_map(char::to_lowercase)
+            .eq(b.chars().flat_map(char::to_lowercase))
+    }
 }
 
 /// A trait containing text-specific functionality that extends the [`Parser`] trait.
@@ -198,6 +327,37 @@ pub fn whitespace<'a, C: Character + 'a, E: Error<C> + 'a>(
     filter(|c: &C| c.is_whitespace()).ignored().repeated()
 }
 
+/// A parser that accepts (and ignores) any number of whitespace characters that do not end a line (such as spaces and
+/// tabs).
+///
+/// This is useful for line-aware grammars, where line breaks are significant and should be parsed with [`newline`].
+///
+/// This parser is a `Parser::Repeated` and so methods such as `at_least()` can be called on it.
+///
+/// The output type of this parser is `Vec<()>`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// // A line is a sequence of words separated by spaces or tabs
+/// let line = text::ident::<_, Simple<char>>()
+///     .padded_by(text::inline_whitespace())
+///     .repeated();
+/// let lines = line.separated_by(text::newline());
+///
+/// assert_eq!(lines.parse("a b\r\n c\t"), Ok(vec![
+///     vec!["a".to_string(), "b".to_string()],
+///     vec!["c".to_string()],
+/// ]));
+/// ```
+pub fn inline_whitespace<'a, C: Character + 'a, E: Error<C> + 'a>(
+) -> Repeated<impl Parser<C, (), Error = E> + Copy + 'a> {
+    filter(|c: &C| c.is_inline_whitespace())
+        .ignored()
+        .repeated()
+}
+
 /// A parser that accepts (and ignores) any newline characters or character sequences.
 ///
 /// The output type of this parser is `()`.
@@ -228,6 +388,10 @@ pub fn whitespace<'a, C: Character + 'a, E: Error<C> + 'a>(
 /// assert_eq!(newline.parse("\u{0085}"), Ok(()));
 /// assert_eq!(newline.parse("\u{2028}"), Ok(()));
 /// assert_eq!(newline.parse("\u{2029}"), Ok(()));
+///
+/// // `\r\n` is a single newline, not two
+/// let newlines = text::newline::<u8, Simple<u8>>().map_with_span(|_, span| span).repeated();
+/// assert_eq!(newlines.parse(b"\r\n\n" as &[u8]), Ok(vec![0..2, 2..3]));
 /// ```
 #[must_use]
 pub fn newline<'a, C: Character + 'a, E: Error<C> + 'a>(
@@ -288,7 +452,9 @@ pub fn digits<C: Character, E: Error<C>>(
 /// The output type of this parser is [`Character::Collection`] (i.e: [`String`] when `C` is [`char`], and [`Vec<u8>`]
 /// when `C` is [`u8`]).
 ///
-/// The `radix` parameter functions identically to [`char::is_digit`]. If in doubt, choose `10`.
+/// The `radix` parameter functions identically to [`char::is_digit`]. If in doubt, choose `10`. Digits that are not
+/// valid in the radix (such as `8` in octal) are not accepted, and nor is an empty sequence of digits. Any prefix that
+/// indicates the radix (such as `0x`) is not handled by this parser. To accept a sign too, use [`signed_int`].
 ///
 /// # Examples
 ///
@@ -322,6 +488,118 @@ pub fn int<C: Character, E: Error<C>>(
         .or(just(C::digit_zero()).map(|c| core::iter::once(c).collect()))
 }
 
+/// Like [`int`], but also accepts an optional leading `+` or `-` sign.
+///
+/// Only the digits are subject to `radix`: any prefix such as `0x` should be parsed separately, before this parser.
+///
+/// The output type of this parser is [`Character::Collection`] (i.e: [`String`] when `C` is [`char`], and [`Vec<u8>`]
+/// when `C` is [`u8`]), including the sign if one was present. This is the form expected by functions such as
+/// [`i64::from_str_radix`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let dec = text::signed_int::<_, Simple<char>>(10).then_ignore(end());
+///
+/// assert_eq!(dec.parse("42"), Ok("42".to_string()));
+/// assert_eq!(dec.parse("-42"), Ok("-42".to_string()));
+/// assert_eq!(dec.parse("+0"), Ok("+0".to_string()));
+/// assert!(dec.parse("-").is_err());
+/// assert!(dec.parse("--1").is_err());
+///
+/// let hex = just::<_, _, Simple<char>>("0x")
+///     .ignore_then(text::int(16))
+///     .try_map(|s: String, span| {
+///         i64::from_str_radix(&s, 16).map_err(|e| Simple::custom(span, e))
+///     });
+///
+/// assert_eq!(hex.parse("0xff"), Ok(255));
+/// ```
+#[must_use]
+pub fn signed_int<C: Character, E: Error<C>>(
+    radix: u32,
+) -> impl Parser<C, C::Collection, Error = E> + Copy {
+    filter(|c: &C| matches!(c.to_char(), '+' | '-'))
+        .or_not()
+        .chain::<C, _, _>(int(radix))
+        .collect()
+}
+
+/// A parser that accepts a decimal floating-point number.
+///
+/// A number is made up of an optional sign (`+` or `-`), an integer part, an optional fractional part (a `.` followed
+/// by at least one digit) and an optional exponent (`e` or `E`, an optional sign, and at least one digit). The integer
+/// part may be omitted if a fractional part is present, as in `.5`: use [`float_with`] to require it.
+///
+/// A `.` that is not followed by a digit is never consumed, so a bare `.` is rejected and `1..2` is parsed as `1`,
+/// leaving the `..` range operator for the parser that follows. Likewise, an `e` that is not followed by an exponent
+/// is left alone. Neither `inf` nor `NaN` are accepted.
+///
+/// The output type of this parser is [`Character::Collection`] (i.e: [`String`] when `C` is [`char`], and [`Vec<u8>`]
+/// when `C` is [`u8`]), containing the number exactly as written. Use [`Parser::try_from_str`] to turn it into an
+/// [`f64`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let float = text::float::<_, Simple<char>>().then_ignore(end());
+///
+/// assert_eq!(float.parse("1"), Ok("1".to_string()));
+/// assert_eq!(float.parse("1.5"), Ok("1.5".to_string()));
+/// assert_eq!(float.parse(".5"), Ok(".5".to_string()));
+/// assert_eq!(float.parse("1e10"), Ok("1e10".to_string()));
+/// assert_eq!(float.parse("-2.3E-4"), Ok("-2.3E-4".to_string()));
+/// assert!(float.parse(".").is_err());
+/// assert!(float.parse("1.").is_err());
+///
+/// // The `..` of a range is not mistaken for a fractional part
+/// let range = text::float::<_, Simple<char>>()
+///     .then_ignore(just(".."))
+///     .then(text::float())
+///     .then_ignore(end());
+/// assert_eq!(range.parse("1..2.5"), Ok(("1".to_string(), "2.5".to_string())));
+///
+/// let value = text::float::<_, Simple<char>>().try_from_str::<f64>();
+/// assert_eq!(value.parse("-2.5e3"), Ok(-2500.0));
+/// ```
+#[must_use]
+pub fn float<C: Character, E: Error<C>>() -> impl Parser<C, C::Collection, Error = E> + Copy {
+    float_with(false)
+}
+
+/// Like [`float`], but `require_leading_digit` determines whether a digit is required before the `.` (such that `.5`
+/// is rejected and `0.5` must be written instead).
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let float = text::float_with::<_, Simple<char>>(true).then_ignore(end());
+///
+/// assert_eq!(float.parse("0.5"), Ok("0.5".to_string()));
+/// assert!(float.parse(".5").is_err());
+/// ```
+#[must_use]
+pub fn float_with<C: Character, E: Error<C>>(
+    require_leading_digit: bool,
+) -> impl Parser<C, C::Collection, Error = E> + Copy {
+    let sign = filter(|c: &C| matches!(c.to_char(), '+' | '-')).or_not();
+    let frac = just(C::from_ascii(b'.')).chain::<C, _, _>(digits(10));
+    let exp = filter(|c: &C| matches!(c.to_char(), 'e' | 'E'))
+        .chain::<C, _, _>(sign)
+        .chain::<C, _, _>(digits(10));
+
+    let with_int = digits(10).chain::<C, _, _>(frac.or_not());
+    let without_int = filter(move |c: &C| !require_leading_digit && c.to_char() == '.')
+        .chain::<C, _, _>(digits(10));
+
+    sign.chain::<C, _, _>(with_int.or(without_int))
+        .chain::<C, _, _>(exp.or_not())
+        .collect()
+}
+
 /// A parser that accepts a C-style identifier.
 ///
 /// The output type of this parser is [`Character::Collection`] (i.e: [`String`] when `C` is [`char`], and [`Vec<u8>`]
@@ -332,15 +610,81 @@ pub fn int<C: Character, E: Error<C>>(
 #[must_use]
 pub fn ident<C: Character, E: Error<C>>() -> impl Parser<C, C::Collection, Error = E> + Copy + Clone
 {
-    filter(|c: &C| c.to_char().is_ascii_alphabetic() || c.to_char() == '_')
+    ident_with(
+        |c| c.is_ascii_alphabetic() || c == '_',
+        |c| c.is_ascii_alphanumeric() || c == '_',
+    )
+}
+
+/// A parser that accepts an identifier made up of Unicode alphabetic and alphanumeric characters.
+///
+/// The output type of this parser is [`Character::Collection`] (i.e: [`String`] when `C` is [`char`], and [`Vec<u8>`]
+/// when `C` is [`u8`]).
+///
+/// An identifier is defined as an alphabetic character (see [`char::is_alphabetic`]) or an underscore followed by any
+/// number of alphanumeric characters (see [`char::is_alphanumeric`]) or underscores. This approximates the
+/// `XID_Start`/`XID_Continue` rules used by many languages. If only ASCII identifiers are needed, [`ident`] is faster.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let ident = text::unicode_ident::<_, Simple<char>>().then_ignore(end());
+///
+/// assert_eq!(ident.parse("größe"), Ok("größe".to_string()));
+/// assert_eq!(ident.parse("_λ2"), Ok("_λ2".to_string()));
+/// assert!(ident.parse("2λ").is_err());
+/// ```
+#[must_use]
+pub fn unicode_ident<C: Character, E: Error<C>>() -> impl Parser<C, C::Collection, Error = E> + Copy
+{
+    ident_with(
+        |c| c.is_alphabetic() || c == '_',
+        |c| c.is_alphanumeric() || c == '_',
+    )
+}
+
+/// A parser that accepts an identifier with a custom set of characters.
+///
+/// The output type of this parser is [`Character::Collection`] (i.e: [`String`] when `C` is [`char`], and [`Vec<u8>`]
+/// when `C` is [`u8`]).
+///
+/// An identifier is defined as a character that satisfies `start`, followed by as many characters that satisfy `cont`
+/// as possible.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// // Lisp-style identifiers, which may contain dashes
+/// let ident = text::ident_with::<_, _, _, Simple<char>>(
+///     |c| c.is_ascii_alphabetic(),
+///     |c| c.is_ascii_alphanumeric() || c == '-',
+/// );
+///
+/// assert_eq!(ident.parse("list-length"), Ok("list-length".to_string()));
+/// assert!(ident.parse("-list").is_err());
+/// ```
+#[must_use]
+pub fn ident_with<C, S, F, E>(start: S, cont: F) -> impl Parser<C, C::Collection, Error = E> + Copy
+where
+    C: Character,
+    S: Fn(char) -> bool + Copy,
+    F: Fn(char) -> bool + Copy,
+    E: Error<C>,
+{
+    filter(move |c: &C| start(c.to_char()))
         .map(Some)
-        .chain::<C, Vec<_>, _>(
-            filter(|c: &C| c.to_char().is_ascii_alphanumeric() || c.to_char() == '_').repeated(),
-        )
+        .chain::<C, Vec<_>, _>(filter(move |c: &C| cont(c.to_char())).repeated())
         .collect()
 }
 
-/// Like [`ident`], but only accepts an exact identifier while ignoring trailing identifier characters.
+/// Like [`ident`], but only accepts an exact identifier, rejecting identifiers that merely start with it.
+///
+/// Unlike [`just`], which would accept the start of `define` as the keyword `def`, the whole identifier is parsed
+/// before being compared to the keyword. The error produced when the keyword is not found does not mention the
+/// keyword: use [`Parser::labelled`] (or [`keyword_ci`], which labels its errors) if the error needs to say which
+/// keyword was expected.
 ///
 /// The output type of this parser is `()`.
 ///
@@ -356,6 +700,14 @@ pub fn ident<C: Character, E: Error<C>>() -> impl Parser<C, C::Collection, Error
 /// assert_eq!(def.parse("def(foo, bar)"), Ok(()));
 /// // 'def' was found, but only as part of a larger identifier, so this fails to parse
 /// assert!(def.parse("define").is_err());
+/// // ...whereas `just` happily accepts a prefix of the identifier
+/// assert_eq!(just::<_, _, Simple<char>>("def").parse("define"), Ok("def"));
+///
+/// // Label the keyword to produce a more useful error
+/// let def = text::keyword::<_, _, Simple<char>>("def").labelled("def");
+/// let errors = def.parse("define").unwrap_err();
+/// assert_eq!(errors[0].span(), 0..6);
+/// assert_eq!(errors[0].label(), Some("def"));
 /// ```
 #[must_use]
 pub fn keyword<'a, C: Character + 'a, S: AsRef<C::Str> + 'a + Clone, E: Error<C> + 'a>(
@@ -371,9 +723,355 @@ pub fn keyword<'a, C: Character + 'a, S: AsRef<C::Str> + 'a + Clone, E: Error<C>
     })
 }
 
+/// Like [`keyword`], but matches the keyword case-insensitively.
+///
+/// The whole identifier is compared at once, so inputs with mixed case (such as `SeLeCt`) are accepted while
+/// identifiers that merely start with the keyword (such as `selection`) are still rejected. By default, only ASCII
+/// characters are case-folded. Enable the `unicode-case` feature to compare [`char`] input using Unicode case rules.
+///
+/// If the keyword is not found, the error is labelled with the keyword.
+///
+/// The output type of this parser is `S`, the canonical form of the keyword as given to this function.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let select = text::keyword_ci::<_, _, Simple<char>>("SELECT");
+///
+/// assert_eq!(select.parse("SELECT"), Ok("SELECT"));
+/// assert_eq!(select.parse("select"), Ok("SELECT"));
+/// assert_eq!(select.parse("SeLeCt *"), Ok("SELECT"));
+/// // 'select' was found, but only as part of a larger identifier, so this fails to parse
+/// assert!(select.parse("selection").is_err());
+/// assert_eq!(select.parse("insert").unwrap_err()[0].label(), Some("SELECT"));
+/// ```
+#[must_use]
+pub fn keyword_ci<'a, C, S, E>(keyword: S) -> impl Parser<C, S, Error = E> + Clone + 'a
+where
+    C: Character + 'a,
+    S: AsRef<C::Str> + Into<E::Label> + Clone + 'a,
+    E: Error<C> + 'a,
+{
+    ident().try_map(move |s: C::Collection, span| {
+        if C::str_eq_ignore_case(s.as_ref(), keyword.as_ref()) {
+            Ok(keyword.clone())
+        } else {
+            Err(E::expected_input_found(span, None, None).with_label(keyword.clone().into()))
+        }
+    })
+}
+
+/// See [`just_ignore_case`].
+#[must_use]
+pub struct JustIgnoreCase<C, S, E>(S, PhantomData<(C, E)>);
+
+impl<C, S: Copy, E> Copy for JustIgnoreCase<C, S, E> {}
+impl<C, S: Clone, E> Clone for JustIgnoreCase<C, S, E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<C: Character, S: OrderedContainer<C>, E: Error<C>> Parser<C, C::Collection>
+    for JustIgnoreCase<C, S, E>
+{
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        _debugger: &mut D,
+        stream: &mut StreamOf<C, E>,
+    ) -> PResult<C, C::Collection, E> {
+        let mut first = None;
+        let mut matched = Vec::new();
+        for expected in self.0.get_iter() {
+            match stream.next() {
+                (_, span, Some(tok)) if tok.to_char().eq_ignore_ascii_case(&expected.to_char()) => {
+                    first.get_or_insert(span);
+                    matched.push(tok);
+                }
+                (at, span, found) => {
+                    // The error covers the part of the sequence that was matched, along with the offending input
+                    let span = match first {
+                        Some(first) => E::Span::new(span.context(), first.start()..span.end()),
+                        None => span,
+                    };
+                    return (
+                        Vec::new(),
+                        Err(Located::at(
+                            at,
+                            E::expected_input_found(span, Some(Some(expected)), found),
+                        )),
+                    );
+                }
+            }
+        }
+
+        (Vec::new(), Ok((matched.into_iter().collect(), None)))
+    }
+
+    fn parse_inner_verbose(
+        &self,
+        d: &mut Verbose,
+        s: &mut StreamOf<C, E>,
+    ) -> PResult<C, C::Collection, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(
+        &self,
+        d: &mut Silent,
+        s: &mut StreamOf<C, E>,
+    ) -> PResult<C, C::Collection, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// Like [`just`], but compares each character case-insensitively.
+///
+/// Only ASCII letters are case-folded (as if by [`char::eq_ignore_ascii_case`]): non-ASCII characters must match
+/// exactly, even when the `unicode-case` feature is enabled. Unlike [`keyword_ci`], this parser does not check that
+/// the input ends after the pattern, so it will also match a prefix of a longer identifier.
+///
+/// If the input does not match, the error expects the character of the pattern at which parsing failed, in the case
+/// in which it appears in the pattern.
+///
+/// The output type of this parser is `C::Collection` (i.e: [`String`] for [`char`] input), containing the characters
+/// that were actually matched. Their original case is preserved, so use [`Parser::to`] if only the canonical form is
+/// needed.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let select = text::just_ignore_case::<_, _, Simple<char>>("select");
+///
+/// assert_eq!(select.parse("select"), Ok("select".to_string()));
+/// assert_eq!(select.parse("SELECT"), Ok("SELECT".to_string()));
+/// assert_eq!(select.parse("SeLeCt *"), Ok("SeLeCt".to_string()));
+/// // No check is made for the end of the identifier
+/// assert_eq!(select.parse("selection"), Ok("select".to_string()));
+///
+/// let errors = select.parse("seXect").unwrap_err();
+/// assert_eq!(errors[0].span(), 0..3);
+/// assert_eq!(errors[0].found(), Some(&'X'));
+/// ```
+pub fn just_ignore_case<C: Character, S: OrderedContainer<C>, E: Error<C>>(
+    pattern: S,
+) -> JustIgnoreCase<C, S, E> {
+    JustIgnoreCase(pattern, PhantomData)
+}
+
+/// A parser that accepts a string literal delimited by `quote`, yielding its contents.
+///
+/// Where possible, the output borrows directly from `source` ([`Cow::Borrowed`]) to avoid allocating. If the literal
+/// contains any escape sequences, a new [`String`] is built instead ([`Cow::Owned`]).
+///
+/// At each position within the literal, the `escape` parser is attempted first. If it succeeds, the character it
+/// produces is added to the output in place of the input it consumed. Otherwise, any character other than `quote` is
+/// accepted verbatim.
+///
+/// Borrowing is only possible when the spans of the input stream are *byte* offsets into `source`. Note that the
+/// default conversion from [`&str`] into a [`Stream`] uses character indices, so you should construct the stream with
+/// [`Stream::from_iter`] as shown below. If the spans don't match the bytes of `source`, a new [`String`] is built
+/// from the input instead.
+///
+/// The output type of this parser is [`Cow<'a, str>`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, Stream};
+/// use std::borrow::Cow;
+///
+/// let src = r#""hello" "a\"b""#;
+///
+/// let escape = just::<_, _, Simple<char>>('\\').ignore_then(one_of("\\\""));
+/// let string = text::string_literal(src, '"', escape).padded();
+/// let strings = string.repeated().then_ignore(end());
+///
+/// let stream = Stream::from_iter(
+///     src.len()..src.len(),
+///     src.char_indices().map(|(i, c)| (c, i..i + c.len_utf8())),
+/// );
+/// let out = strings.parse(stream).unwrap();
+///
+/// // No escapes, so no allocation was necessary
+/// assert!(matches!(&out[0], Cow::Borrowed("hello")));
+/// // An escape was present, so the string was built
+/// assert!(matches!(&out[1], Cow::Owned(s) if s == "a\"b"));
+/// ```
+pub fn string_literal<'a, P, E>(source: &'a str, quote: char, escape: P) -> StringLiteral<'a, P, E>
+where
+    P: Parser<char, char, Error = E>,
+    E: Error<char, Span = Range<usize>>,
+{
+    StringLiteral(source, quote, escape, PhantomData)
+}
+
+/// A parser that accepts an escape sequence within a string or character literal, yielding the character it denotes.
+///
+/// The following escape sequences, which follow the rules of Rust, are supported:
+///
+/// - `\n`, `\t`, `\r`, `\\`, `\"`, `\'` and `\0`
+/// - `\xHH`: exactly two hexadecimal digits, giving an ASCII character (at most `\x7F`)
+/// - `\u{H...}`: between one and six hexadecimal digits, giving a Unicode code point
+///
+/// Malformed hexadecimal digits and unknown escape sequences produce a [`Error::custom`] error describing the problem.
+/// A hexadecimal escape that is well-formed but out of range (such as `\x80` or `\u{110000}`) is instead reported as a
+/// recoverable error, and parsing continues with [`char::REPLACEMENT_CHARACTER`] in its place.
+///
+/// This parser is intended to be combined with [`none_of`] to parse the contents of a quoted string, as shown below,
+/// or used as the `escape` parser of [`string_literal`].
+///
+/// The output type of this parser is [`char`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let string = none_of::<_, _, Simple<char>>(['"', '\\'])
+///     .or(text::escape())
+///     .repeated()
+///     .collect::<String>()
+///     .delimited_by(just('"'), just('"'));
+///
+/// assert_eq!(
+///     string.parse(r#""a\n\t\u{1F600}\x41""#),
+///     Ok("a\n\t\u{1F600}A".to_string()),
+/// );
+/// assert!(string.parse(r#""\q""#).is_err());
+/// assert!(string.parse(r#""\xZZ""#).is_err());
+///
+/// // An out-of-range code point produces an error, but parsing continues
+/// let (out, errors) = string.parse_recovery(r#""a\u{110000}b""#);
+/// assert_eq!(out, Some("a\u{FFFD}b".to_string()));
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].span(), 2..12);
+/// ```
+pub fn escape<E: Error<char>>() -> impl Parser<char, char, Error = E> + Clone {
+    let hex_digit = filter(|c: &char| c.is_ascii_hexdigit());
+
+    // Well-formed escapes that denote an invalid character yield an error message rather than failing to parse
+    let hex = just('x')
+        .ignore_then(
+            hex_digit
+                .repeated()
+                .exactly(2)
+                .collect::<String>()
+                .map_err_with_span(|_, span| {
+                    E::custom(span, "expected two hexadecimal digits after `\\x`")
+                }),
+        )
+        .map(|digits| match u8::from_str_radix(&digits, 16) {
+            Ok(n) if n <= 0x7F => Ok(n as char),
+            _ => Err(format!(
+                "`\\x{}` is out of range, it must be at most `\\x7F`",
+                digits
+            )),
+        });
+
+    let unicode = just('u')
+        .ignore_then(
+            hex_digit
+                .repeated()
+                .at_least(1)
+                .at_most(6)
+                .collect::<String>()
+                .delimited_by(just('{'), just('}'))
+                .map_err_with_span(|_, span| {
+                    E::custom(
+                        span,
+                        "expected between one and six hexadecimal digits in braces after `\\u`",
+                    )
+                }),
+        )
+        .map(|digits| {
+            u32::from_str_radix(&digits, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("`\\u{{{}}}` is not a valid Unicode code point", digits))
+        });
+
+    let unknown = any().try_map(|c, span| {
+        Err(E::custom(
+            span,
+            format!("unknown escape sequence `\\{}`", c),
+        ))
+    });
+
+    just('\\')
+        .ignore_then(choice((
+            just('n').to(Ok('\n')),
+            just('t').to(Ok('\t')),
+            just('r').to(Ok('\r')),
+            just('\\').map(Ok),
+            just('"').map(Ok),
+            just('\'').map(Ok),
+            just('0').to(Ok('\0')),
+            hex,
+            unicode,
+            unknown,
+        )))
+        .validate(|c, span, emit| {
+            c.unwrap_or_else(|msg| {
+                emit(E::custom(span, msg));
+                char::REPLACEMENT_CHARACTER
+            })
+        })
+}
+
 /// A parser that consumes text and generates tokens using semantic whitespace rules and the given token parser.
 ///
 /// Also required is a function that collects a [`Vec`] of tokens into a whitespace-indicated token tree.
+///
+/// This implements the 'offside rule' used by languages such as Python and Haskell. Each line is parsed as a sequence
+/// of tokens separated by inline whitespace. A line that is indented further than the line before it begins a new
+/// group, which ends when a line with less indentation is found. Each group (from its first line to its last) is
+/// passed to `make_group` along with the span of its first line, and the token that it produces is appended to the
+/// enclosing group, much like the INDENT and DEDENT tokens produced by Python's tokenizer.
+///
+/// Indentation is compared character-by-character, so a group's lines must begin with exactly the same whitespace
+/// (i.e: tabs and spaces are not interchangeable).
+///
+/// The output type of this parser is `Vec<Tok>`, the tokens of the top-level group.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// #[derive(Debug, PartialEq)]
+/// enum Tok {
+///     Word(String),
+///     Block(Vec<Tok>),
+/// }
+///
+/// let word = text::ident::<_, Simple<char>>().map(Tok::Word);
+/// let tokens = text::semantic_indentation(word, |toks, _| Tok::Block(toks)).then_ignore(end());
+///
+/// let src = "
+/// if a
+///     b c
+///     while d
+///         e
+///     f
+/// g
+/// ";
+///
+/// let word = |s: &str| Tok::Word(s.to_string());
+/// assert_eq!(tokens.parse(src), Ok(vec![
+///     word("if"), word("a"),
+///     Tok::Block(vec![
+///         word("b"), word("c"),
+///         word("while"), word("d"),
+///         Tok::Block(vec![word("e")]),
+///         word("f"),
+///     ]),
+///     word("g"),
+/// ]));
+/// ```
 #[must_use]
 pub fn semantic_indentation<'a, C, Tok, T, F, E: Error<C> + 'a>(
     token: T,
@@ -438,3 +1136,96 @@ where
         nesting.remove(0).1
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Simple;
+
+    #[test]
+    fn just_ignore_case_mixed_case_keywords() {
+        let kw = |s| just_ignore_case::<char, _, Simple<char>>(s).padded();
+        let query = kw("select")
+            .then(kw("from"))
+            .then(kw("where"))
+            .then_ignore(end());
+
+        assert_eq!(
+            query.parse("SELECT From wHeRe"),
+            Ok((
+                ("SELECT".to_string(), "From".to_string()),
+                "wHeRe".to_string()
+            )),
+        );
+        assert!(query.parse("SELECT Frm WHERE").is_err());
+
+        let bytes = just_ignore_case::<u8, _, Simple<u8>>(&b"select"[..]);
+        assert_eq!(bytes.parse(&b"SeLeCt"[..]), Ok(b"SeLeCt".to_vec()));
+    }
+
+    #[test]
+    fn escape_errors() {
+        let string = none_of::<_, _, Simple<char>>(['"', '\\'])
+            .or(escape())
+            .repeated()
+            .collect::<String>()
+            .delimited_by(just('"'), just('"'));
+
+        let reason = |src: &str| match string.parse(src).unwrap_err().remove(0).reason() {
+            crate::error::SimpleReason::Custom(msg) => msg.clone(),
+            reason => panic!("expected a custom error, found {:?}", reason),
+        };
+
+        assert_eq!(reason(r#""\q""#), "unknown escape sequence `\\q`");
+        assert_eq!(
+            reason(r#""\xZZ""#),
+            "expected two hexadecimal digits after `\\x`"
+        );
+        assert_eq!(
+            reason(r#""\u{12""#),
+            "expected between one and six hexadecimal digits in braces after `\\u`"
+        );
+
+        let (out, errors) = string.parse_recovery(r#""\x80""#);
+        assert_eq!(out, Some("\u{FFFD}".to_string()));
+        assert_eq!(errors[0].span(), 1..5);
+    }
+
+    #[test]
+    fn int_radix() {
+        let int = |radix| int::<char, Simple<char>>(radix).then_ignore(end());
+
+        assert_eq!(int(16).parse("ff"), Ok("ff".to_string()));
+        assert_eq!(int(2).parse("1010"), Ok("1010".to_string()));
+        assert!(int(2).parse("102").is_err());
+        assert!(int(8).parse("78").is_err());
+        assert!(int(16).parse("").is_err());
+
+        let signed = |radix| signed_int::<char, Simple<char>>(radix).then_ignore(end());
+
+        assert_eq!(signed(16).parse("-ff"), Ok("-ff".to_string()));
+        assert_eq!(signed(2).parse("+1010"), Ok("+1010".to_string()));
+        assert!(signed(8).parse("-8").is_err());
+        assert!(signed(10).parse("+").is_err());
+    }
+
+    #[test]
+    fn string_literal_char_index_spans() {
+        // Non-ASCII text before the literal means that the character indices of a `&str` stream aren't byte offsets
+        let cases = [
+            ("é \"ab\"", "ab"),
+            ("é \"\"", ""),
+            ("é \"a\\\"é\"", "a\"é"),
+            ("\"é\" é", "é"),
+        ];
+        for (src, expected) in cases {
+            let escape = just::<_, _, Simple<char>>('\\').ignore_then(just('"'));
+            let string = string_literal(src, '"', escape).padded();
+            let parser = none_of('"')
+                .repeated()
+                .ignore_then(string)
+                .then_ignore(any().repeated());
+            assert_eq!(parser.parse(src), Ok(Cow::Borrowed(expected)));
+        }
+    }
+}